@@ -0,0 +1,69 @@
+/* Generated by cbindgen from ffi/src/lib.rs, do not edit by hand. */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+// The puzzle was solved.
+#define AOC_OK 0
+
+// Solving failed in a way that has no category.
+#define AOC_ERROR 1
+
+// The day or part is invalid, or the input buffer is missing.
+#define AOC_ERROR_USAGE 2
+
+// A file could not be read or written, which only a registered solver would do.
+#define AOC_ERROR_IO 3
+
+// The input is not valid UTF-8 or could not be parsed.
+#define AOC_ERROR_PARSE 4
+
+// The input parsed but could not be simulated to an answer, or the solver panicked.
+#define AOC_ERROR_SIMULATION 5
+
+// The outcome of solving a puzzle.
+//
+// The codes are the same as the exit codes of `aoc`.
+typedef struct AocAnswer {
+  // [AOC_OK] if the puzzle was solved, otherwise one of the `AOC_ERROR` codes.
+  int code;
+  // The answer if the puzzle was solved, otherwise a message describing the error.
+  //
+  // Null only if the text could not be allocated. Owned by the caller, who must release it with
+  // [aoc_answer_free].
+  char *text;
+} AocAnswer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Solves a day and part of 2022 for an input of `input_len` bytes starting at `input`.
+//
+// The input must be UTF-8 and doesn't need to end in a nul. `input` may be null only if
+// `input_len` is 0. Panics in a solver are caught and reported as [AOC_ERROR_SIMULATION], so they
+// never unwind into the caller.
+//
+// # Safety
+//
+// Unless it's null, `input` must point to `input_len` bytes that are readable for the whole call.
+struct AocAnswer aoc_solve(uint8_t day, uint8_t part, const uint8_t *input, size_t input_len);
+
+// Releases the text of an answer returned by [aoc_solve].
+//
+// Does nothing if the text is null.
+//
+// # Safety
+//
+// The answer must have come from [aoc_solve], and its text must not be used or released again
+// afterwards.
+void aoc_answer_free(struct AocAnswer answer);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* AOC_H */
@@ -0,0 +1,140 @@
+//! C interface to the solvers, for calling them from other languages without running `aoc`.
+//!
+//! Built as a shared library with `cargo build --release -p aoc-ffi`, with its declarations in
+//! `include/aoc.h`. The header is generated from this file by cbindgen:
+//! ```shell
+//! cbindgen --config cbindgen.toml --output include/aoc.h
+//! ```
+//!
+//! [aoc_solve] solves an input already in memory and returns an [AocAnswer], whose text is owned by
+//! the caller and must be released with [aoc_answer_free]:
+//! ```c
+//! #include "aoc.h"
+//!
+//! AocAnswer answer = aoc_solve(6, 1, (const uint8_t *)"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 30);
+//! if (answer.code == AOC_OK) {
+//!     printf("%s\n", answer.text);
+//! } else {
+//!     fprintf(stderr, "error %d: %s\n", answer.code, answer.text);
+//! }
+//! aoc_answer_free(answer);
+//! ```
+
+use anyhow::anyhow;
+use aoc::error::{self, Categorize, ErrorKind};
+use std::ffi::{c_char, c_int, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+/// The puzzle was solved.
+pub const AOC_OK: c_int = 0;
+/// Solving failed in a way that has no category.
+pub const AOC_ERROR: c_int = 1;
+/// The day or part is invalid, or the input buffer is missing.
+pub const AOC_ERROR_USAGE: c_int = 2;
+/// A file could not be read or written, which only a registered solver would do.
+pub const AOC_ERROR_IO: c_int = 3;
+/// The input is not valid UTF-8 or could not be parsed.
+pub const AOC_ERROR_PARSE: c_int = 4;
+/// The input parsed but could not be simulated to an answer, or the solver panicked.
+pub const AOC_ERROR_SIMULATION: c_int = 5;
+
+/// The outcome of solving a puzzle.
+///
+/// The codes are the same as the exit codes of `aoc`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct AocAnswer {
+    /// [AOC_OK] if the puzzle was solved, otherwise one of the `AOC_ERROR` codes.
+    pub code: c_int,
+    /// The answer if the puzzle was solved, otherwise a message describing the error.
+    ///
+    /// Null only if the text could not be allocated. Owned by the caller, who must release it with
+    /// [aoc_answer_free].
+    pub text: *mut c_char,
+}
+
+impl AocAnswer {
+    fn new(result: Result<String, anyhow::Error>) -> Self {
+        let (code, text) = match result {
+            Ok(answer) => (AOC_OK, answer),
+            Err(e) => (
+                ErrorKind::of(&e).map_or(AOC_ERROR, |kind| kind.exit_code()),
+                format!("{:#}", e),
+            ),
+        };
+        // a C string ends at its first nul, so any in the text would cut it short
+        let text = CString::new(text.replace('\0', ""))
+            .map(CString::into_raw)
+            .unwrap_or(ptr::null_mut());
+        AocAnswer { code, text }
+    }
+}
+
+/// Solves a day and part of 2022 for an input of `input_len` bytes starting at `input`.
+///
+/// The input must be UTF-8 and doesn't need to end in a nul. `input` may be null only if
+/// `input_len` is 0. Panics in a solver are caught and reported as [AOC_ERROR_SIMULATION], so they
+/// never unwind into the caller.
+///
+/// # Safety
+///
+/// Unless it's null, `input` must point to `input_len` bytes that are readable for the whole call.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+) -> AocAnswer {
+    let input = match (input.is_null(), input_len) {
+        (true, 0) => &[][..],
+        (true, _) => {
+            return AocAnswer::new(
+                Err(anyhow!("input is null but its length is {}", input_len))
+                    .categorize(ErrorKind::Usage),
+            )
+        }
+        // SAFETY: the caller guarantees that input points to input_len readable bytes
+        (false, _) => unsafe { std::slice::from_raw_parts(input, input_len) },
+    };
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let input = std::str::from_utf8(input).map_err(|_| error::invalid_utf8())?;
+        aoc::solve_input(day, part, input)
+    }));
+    AocAnswer::new(result.unwrap_or_else(|_| Err(error::solver_panicked())))
+}
+
+/// Releases the text of an answer returned by [aoc_solve].
+///
+/// Does nothing if the text is null.
+///
+/// # Safety
+///
+/// The answer must have come from [aoc_solve], and its text must not be used or released again
+/// afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_answer_free(answer: AocAnswer) {
+    if !answer.text.is_null() {
+        // SAFETY: the text was allocated by CString::into_raw in AocAnswer::new
+        drop(unsafe { CString::from_raw(answer.text) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes() {
+        let kinds = [
+            (AOC_ERROR_USAGE, ErrorKind::Usage),
+            (AOC_ERROR_IO, ErrorKind::Io),
+            (AOC_ERROR_PARSE, ErrorKind::Parse),
+            (AOC_ERROR_SIMULATION, ErrorKind::Simulation),
+        ];
+        for (code, kind) in kinds {
+            assert_eq!(kind.exit_code(), code);
+        }
+    }
+}
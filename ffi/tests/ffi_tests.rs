@@ -0,0 +1,89 @@
+use aoc_ffi::{
+    aoc_answer_free, aoc_solve, AocAnswer, AOC_ERROR_PARSE, AOC_ERROR_SIMULATION, AOC_ERROR_USAGE,
+    AOC_OK,
+};
+use std::ffi::CStr;
+use std::path::Path;
+
+/// The functions as C callers see them, so each call goes through the C calling convention.
+const SOLVE: unsafe extern "C" fn(u8, u8, *const u8, usize) -> AocAnswer = aoc_solve;
+const FREE: unsafe extern "C" fn(AocAnswer) = aoc_answer_free;
+
+/// Solves an input through the C interface, returning the code and the text.
+fn solve(day: u8, part: u8, input: &[u8]) -> (i32, String) {
+    let answer = unsafe { SOLVE(day, part, input.as_ptr(), input.len()) };
+    assert!(!answer.text.is_null());
+    let text = unsafe { CStr::from_ptr(answer.text) }
+        .to_str()
+        .expect("should return UTF-8 text")
+        .to_string();
+    let code = answer.code;
+    unsafe { FREE(answer) };
+    (code, text)
+}
+
+#[test]
+fn test_solve() {
+    assert_eq!(
+        (AOC_OK, "7".to_string()),
+        solve(6, 1, b"mjqjpqmgbljsphdztnvjfqwrcgsmlb")
+    );
+    let input = "1000\n2000\n\n4000";
+    assert_eq!(
+        (
+            AOC_OK,
+            aoc::solve_input(1, 2, input).expect("should return result")
+        ),
+        solve(1, 2, input.as_bytes())
+    );
+}
+
+#[test]
+fn test_solve_errors() {
+    let (code, text) = solve(1, 1, b"1000\nabc");
+    assert_eq!(AOC_ERROR_PARSE, code);
+    assert!(text.contains("could not parse line to u32"));
+    assert_eq!(AOC_ERROR_USAGE, solve(26, 1, b"").0);
+    assert_eq!(AOC_ERROR_USAGE, solve(1, 3, b"").0);
+    assert_eq!(AOC_ERROR_PARSE, solve(1, 1, b"\xff").0);
+    let answer = unsafe { SOLVE(1, 1, std::ptr::null(), 1) };
+    assert_eq!(AOC_ERROR_USAGE, answer.code);
+    unsafe { FREE(answer) };
+    // an empty input may be passed as a null pointer
+    let answer = unsafe { SOLVE(6, 1, std::ptr::null(), 0) };
+    assert_eq!(AOC_OK, answer.code);
+    unsafe { FREE(answer) };
+}
+
+#[test]
+fn test_solve_panic() {
+    aoc::registry::register(2022, 200, 1, |_: &str| -> Result<String, anyhow::Error> {
+        panic!("solver bug")
+    })
+    .expect("should return result");
+    let result = solve(200, 1, b"");
+    aoc::registry::unregister(2022, 200, 1);
+    assert_eq!(
+        (AOC_ERROR_SIMULATION, "solver panicked".to_string()),
+        result
+    );
+}
+
+#[test]
+fn test_header() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let config = cbindgen::Config::from_file(dir.join("cbindgen.toml"))
+        .expect("should read cbindgen config");
+    let mut generated = Vec::new();
+    cbindgen::Builder::new()
+        .with_crate(dir)
+        .with_config(config)
+        .generate()
+        .expect("should generate header")
+        .write(&mut generated);
+    let header = std::fs::read(dir.join("include/aoc.h")).expect("should read header");
+    assert!(
+        generated == header,
+        "include/aoc.h is out of date, regenerate it with `cbindgen --config cbindgen.toml --output include/aoc.h`"
+    );
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    // parsing either succeeds or returns an error, but never panics
+    let _ = aoc::parse(3, input);
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // the first two bytes pick the day and part, and the rest is the input
+    let [day, part, input @ ..] = data else {
+        return;
+    };
+    let Ok(input) = std::str::from_utf8(input) else {
+        return;
+    };
+    // solving either succeeds or returns an error, but never panics
+    let _ = aoc::solve_input(day % 14 + 1, part % 2 + 1, input);
+});
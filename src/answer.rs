@@ -0,0 +1,80 @@
+//! Typed answers produced by the solvers.
+//!
+//! Every solver returns its answer as a string so that all days share one signature. Most answers
+//! are whole numbers though, and knowing that makes it possible to compare answers by value rather
+//! than by text. [Answer] recovers that type from a solver's output.
+
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// A solver's answer, either a whole number or free-form text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// An answer that is a whole number, such as a sum or a count.
+    Number(i64),
+    /// Any other answer, such as the letters on top of day 05's stacks or day 10's display.
+    Text(String),
+}
+
+impl Answer {
+    /// Compares two answers by value if both are numbers.
+    ///
+    /// Text answers have no ordering, so `None` is returned if either answer is text.
+    pub fn compare(&self, other: &Answer) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Number(a), Answer::Number(b)) => Some(a.cmp(b)),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Answer::from(s.to_string()))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        match s.trim().parse::<i64>() {
+            Ok(value) => Answer::Number(value),
+            Err(_) => Answer::Text(s),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_from_string() {
+        assert_eq!(Answer::Number(12345), Answer::from("12345".to_string()));
+        assert_eq!(Answer::Number(-3), Answer::from("-3".to_string()));
+        assert_eq!(
+            Answer::Text("CMZ".to_string()),
+            Answer::from("CMZ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_answer_compare() {
+        let low = Answer::Number(10);
+        let high = Answer::Number(20);
+        assert_eq!(Some(Ordering::Less), low.compare(&high));
+        assert_eq!(None, low.compare(&Answer::Text("10a".to_string())));
+    }
+}
@@ -0,0 +1,239 @@
+//! Solving the same day and part for many input files at once.
+//!
+//! Inputs collected from several people can be solved in one go by giving every path, a directory
+//! holding them, or a glob pattern such as `inputs/*.txt`. Each file is read and solved the same
+//! way as a single input, and a file that can't be read or solved doesn't stop the rest.
+
+use crate::error::{Categorize, ErrorKind};
+use anyhow::{anyhow, Context};
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::time::Duration;
+
+/// Checks whether the given paths need more than a single file to be solved.
+///
+/// That's the case for several paths, a directory or a glob pattern, but not for a single path
+/// to a file, even one that doesn't exist.
+pub fn is_batch(paths: &[String]) -> bool {
+    match paths {
+        [] => false,
+        [path] => Path::new(path).is_dir() || is_pattern(path),
+        _ => true,
+    }
+}
+
+/// The answer, or the error, from solving one input file.
+#[derive(Debug)]
+pub struct Outcome {
+    pub file: String,
+    pub answer: Result<String, anyhow::Error>,
+}
+
+/// Expands paths into the input files they refer to, in order.
+///
+/// A directory expands to every `.txt` file directly inside it, sorted by name, so the `.answers`
+/// files next to inputs are skipped. A glob pattern expands to every file it matches, sorted by
+/// name. Any other path is kept as it is, whether or not it exists, so that reading it reports
+/// the problem. An error categorized as [ErrorKind::Usage] is returned for an invalid pattern, or
+/// a directory or pattern without any files.
+///
+/// # Examples
+///
+/// ```
+/// let files = aoc::batch::expand(&["input/day_01/input.txt".to_string()]).unwrap();
+/// assert_eq!(vec!["input/day_01/input.txt"], files);
+/// let files = aoc::batch::expand(&["input/day_0[12]/*.txt".to_string()]).unwrap();
+/// assert!(files.contains(&"input/day_02/input.txt".to_string()));
+/// ```
+pub fn expand(paths: &[String]) -> Result<Vec<String>, anyhow::Error> {
+    let mut files = Vec::new();
+    for path in paths {
+        let expanded = if Path::new(path).is_dir() {
+            directory_files(path)?
+        } else if is_pattern(path) {
+            matching_files(path)?
+        } else {
+            files.push(path.clone());
+            continue;
+        };
+        if expanded.is_empty() {
+            return Err(anyhow!("no input files found: {}", path)).categorize(ErrorKind::Usage);
+        }
+        files.extend(expanded);
+    }
+    Ok(files)
+}
+
+/// Solves the selected year, day and part for each file, keeping every file's result.
+///
+/// Each solver gives up after `timeout` if one is given. The day and part are checked first, so an
+/// invalid day is reported once rather than for every file. A solver that panics on a file gives
+/// that file an error categorized as [ErrorKind::Simulation].
+pub fn solve(
+    year: u16,
+    day: u8,
+    part: u8,
+    files: &[String],
+    timeout: Option<Duration>,
+) -> Result<Vec<Outcome>, anyhow::Error> {
+    crate::solution::validate(year, day, part)?;
+    Ok(files
+        .iter()
+        .map(|file| {
+            let answer =
+                crate::read_input_year(year, day, part, Some(file.clone())).and_then(|input| {
+                    // a solver that panics on one file mustn't stop the rest from being solved
+                    panic::catch_unwind(AssertUnwindSafe(|| match timeout {
+                        Some(timeout) => {
+                            crate::solve_input_with_timeout_year(year, day, part, &input, timeout)
+                        }
+                        None => crate::solve_input_year(year, day, part, &input),
+                    }))
+                    .unwrap_or_else(|_| Err(crate::error::solver_panicked()))
+                });
+            Outcome {
+                file: file.clone(),
+                answer,
+            }
+        })
+        .collect())
+}
+
+fn is_pattern(path: &str) -> bool {
+    path.contains(['*', '?', '['])
+}
+
+fn directory_files(dir: &str) -> Result<Vec<String>, anyhow::Error> {
+    let entries = fs::read_dir(dir)
+        .with_context(|| format!("could not read input directory: {}", dir))
+        .categorize(ErrorKind::Io)?;
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry
+            .with_context(|| format!("could not read input directory: {}", dir))
+            .categorize(ErrorKind::Io)?
+            .path();
+        if path.is_file() && path.extension().is_some_and(|e| e == "txt") {
+            files.push(path.to_string_lossy().to_string());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn matching_files(pattern: &str) -> Result<Vec<String>, anyhow::Error> {
+    let paths = glob::glob(pattern)
+        .with_context(|| format!("invalid file pattern: {}", pattern))
+        .categorize(ErrorKind::Usage)?;
+    let mut files = Vec::new();
+    for path in paths {
+        let path = path
+            .context("could not read a matching path")
+            .categorize(ErrorKind::Io)?;
+        if path.is_file() {
+            files.push(path.to_string_lossy().to_string());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_batch() {
+        assert!(!is_batch(&[]));
+        assert!(!is_batch(&["input/day_01/input.txt".to_string()]));
+        assert!(!is_batch(&["does/not/exist.txt".to_string()]));
+        assert!(is_batch(&["input/day_01".to_string()]));
+        assert!(is_batch(&["input/*/input.txt".to_string()]));
+        assert!(is_batch(&["a.txt".to_string(), "b.txt".to_string()]));
+    }
+
+    #[test]
+    fn test_expand() {
+        let dir = tempfile::tempdir().expect("should create temp dir");
+        for name in ["b.txt", "a.txt", "a.answers"] {
+            fs::write(dir.path().join(name), "").expect("should write file");
+        }
+        let dir_path = dir.path().to_string_lossy().to_string();
+        let files =
+            expand(&[dir_path.clone(), "missing.txt".to_string()]).expect("should return result");
+        assert_eq!(
+            vec![
+                format!("{}/a.txt", dir_path),
+                format!("{}/b.txt", dir_path),
+                "missing.txt".to_string()
+            ],
+            files
+        );
+        let files = expand(&[format!("{}/*.answers", dir_path)]).expect("should return result");
+        assert_eq!(vec![format!("{}/a.answers", dir_path)], files);
+        let error = expand(&[format!("{}/*.json", dir_path)]).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+        let error = expand(&["input/[".to_string()]).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+    }
+
+    #[test]
+    fn test_solve() {
+        let dir = tempfile::tempdir().expect("should create temp dir");
+        let good = dir.path().join("good.txt");
+        let bad = dir.path().join("bad.txt");
+        fs::write(&good, "1000\n\n2000").expect("should write file");
+        fs::write(&bad, "abc").expect("should write file");
+        let files = [
+            good.to_string_lossy().to_string(),
+            bad.to_string_lossy().to_string(),
+            "missing.txt".to_string(),
+        ];
+        let outcomes = solve(2022, 1, 1, &files, None).expect("should return result");
+        assert_eq!(files[0], outcomes[0].file);
+        let answer = outcomes[0].answer.as_ref().expect("should return result");
+        assert_eq!("2000", answer);
+        let error = outcomes[1]
+            .answer
+            .as_ref()
+            .expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Parse), ErrorKind::of(error));
+        let error = outcomes[2]
+            .answer
+            .as_ref()
+            .expect_err("should return error");
+        assert_eq!("could not read input file: missing.txt", error.to_string());
+        let error = solve(2022, 0, 1, &files, None).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+    }
+
+    #[test]
+    fn test_solve_panic() {
+        let dir = tempfile::tempdir().expect("should create temp dir");
+        let bad = dir.path().join("bad.txt");
+        let good = dir.path().join("good.txt");
+        fs::write(&bad, "panic").expect("should write file");
+        fs::write(&good, "abc").expect("should write file");
+        let files = [
+            bad.to_string_lossy().to_string(),
+            good.to_string_lossy().to_string(),
+        ];
+        crate::registry::register(2022, 203, 1, |input: &str| match input {
+            "panic" => panic!("solver bug"),
+            _ => Ok(input.len().to_string()),
+        })
+        .expect("should return result");
+        let outcomes = solve(2022, 203, 1, &files, None).expect("should return result");
+        crate::registry::unregister(2022, 203, 1);
+        let error = outcomes[0]
+            .answer
+            .as_ref()
+            .expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Simulation), ErrorKind::of(error));
+        assert_eq!("solver panicked", error.to_string());
+        // the panic doesn't stop the files after it from being solved
+        let answer = outcomes[1].answer.as_ref().expect("should return result");
+        assert_eq!("3", answer);
+    }
+}
@@ -0,0 +1,170 @@
+//! Structural checks of puzzle inputs.
+//!
+//! Parsing stops at the first thing that's wrong, and some mistakes in an input still parse but make
+//! a solver give the wrong number. Checking an input instead looks through all of it and lists
+//! every problem it finds, such as grid rows of different lengths on days 8 and 12, a missing `S`
+//! or `E` on day 12, or a monkey on day 11 throwing to a monkey that doesn't exist.
+//!
+//! Checks only look at the input, so they are quick even for inputs that would take a solver a
+//! long time.
+
+use crate::error::{Categorize, ErrorKind};
+use anyhow::anyhow;
+use std::fmt::{Display, Formatter};
+
+/// Something wrong with an input, and where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// The line of the input the problem is on, counting from 1, if it's on a single line.
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl Problem {
+    /// Creates a problem found on a line, counting from 1.
+    pub(crate) fn at(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line: Some(line),
+            message: message.into(),
+        }
+    }
+
+    /// Creates a problem with the input as a whole.
+    pub(crate) fn general(message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Returns every day that has a checker, in order.
+pub fn days() -> Vec<u8> {
+    (1..=crate::solution::checkers().len() as u8).collect()
+}
+
+/// Checks a day's input and returns every problem found, which is empty if the input looks right.
+///
+/// An error is returned only if the day has no solver.
+///
+/// # Examples
+///
+/// ```
+/// let problems = aoc::check::check(8, "303\n25\n653").unwrap();
+/// assert_eq!(
+///     "line 2: row has 2 trees, but the first row has 3",
+///     problems[0].to_string()
+/// );
+/// assert!(aoc::check::check(8, "303\n255\n653").unwrap().is_empty());
+/// ```
+pub fn check(day: u8, input: &str) -> Result<Vec<Problem>, anyhow::Error> {
+    match crate::solution::checkers().get((day as usize).wrapping_sub(1)) {
+        Some(checker) => Ok(checker(input)),
+        None => Err(anyhow!("invalid day: {}", day)).categorize(ErrorKind::Usage),
+    }
+}
+
+/// Parses each line on its own and reports the lines that fail, numbering lines from 1.
+pub(crate) fn check_lines<T>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<T, anyhow::Error>,
+) -> Vec<Problem> {
+    input
+        .split('\n')
+        .enumerate()
+        .filter_map(|(i, line)| match parse(line) {
+            Ok(_) => None,
+            Err(e) => Some(Problem::at(i + 1, format!("{:#}", e))),
+        })
+        .collect()
+}
+
+/// Reports rows of a grid whose length doesn't match the first row.
+///
+/// `cells` names what the grid holds, such as `trees`.
+pub(crate) fn check_grid(input: &str, cells: &str) -> Vec<Problem> {
+    let mut rows = input.split('\n');
+    let width = rows.next().map_or(0, |row| row.chars().count());
+    rows.enumerate()
+        .filter_map(|(i, row)| {
+            let length = row.chars().count();
+            (length != width).then(|| {
+                Problem::at(
+                    i + 2,
+                    format!(
+                        "row has {} {}, but the first row has {}",
+                        length, cells, width
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_check_lines() {
+        let problems = check_lines("1\nx\n3\n-4", |line| {
+            line.parse::<u32>()
+                .with_context(|| format!("not a number: {}", line))
+        });
+        assert_eq!(
+            vec![
+                "line 2: not a number: x: invalid digit found in string",
+                "line 4: not a number: -4: invalid digit found in string"
+            ],
+            problems
+                .iter()
+                .map(Problem::to_string)
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn test_check_grid() {
+        let problems = check_grid("abc\nab\nabc\nabcd", "squares");
+        assert_eq!(
+            vec![
+                Problem::at(2, "row has 2 squares, but the first row has 3"),
+                Problem::at(4, "row has 4 squares, but the first row has 3"),
+            ],
+            problems
+        );
+    }
+
+    #[test]
+    fn test_check_generated() {
+        for day in crate::generate::days() {
+            for seed in 0..5 {
+                let input =
+                    crate::generate::generate(day, seed, None).expect("should return result");
+                assert_eq!(
+                    Vec::<Problem>::new(),
+                    check(day, &input).expect("should return result"),
+                    "day {} seed {}",
+                    day,
+                    seed
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_check_invalid_day() {
+        assert!(check(0, "").is_err());
+        assert!(check(15, "").is_err());
+    }
+}
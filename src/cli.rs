@@ -0,0 +1,882 @@
+//! The command-line interface of the `aoc` binary.
+//!
+//! The CLI lives in the library so that a crate which registers its own solvers through
+//! [crate::registry] can run them with the same commands, by calling [main] from its own binary.
+
+use crate::answer::Answer;
+use crate::batch;
+use crate::check;
+use crate::describe;
+use crate::differential;
+use crate::error::{Categorize, ErrorKind};
+use crate::fetch::{self, Fetched};
+use crate::generate;
+use crate::history::{self, History, Verdict};
+use crate::metrics::{self, Metrics};
+use crate::report;
+use crate::serve::Server;
+use crate::stats;
+use crate::stream;
+use crate::verify::{self, Verification};
+use crate::visualize;
+use anyhow::{anyhow, Context};
+use clap::builder::PossibleValuesParser;
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use terminal_size::{Height, Width};
+
+/// Exit codes for each category of error, shown at the end of `--help`.
+const EXIT_CODES: &str = "Exit codes:
+  0  Success
+  1  Uncategorized error
+  2  Usage error, such as a day or part without a solver
+  3  I/O error, such as a missing input file or failed download
+  4  Parse error in the puzzle input
+  5  Simulation error while solving the puzzle
+  6  Timeout
+  7  Verification mismatch";
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None, after_long_help = EXIT_CODES)]
+struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Which day's puzzle to solve
+    #[arg(short, long, default_value = "1")]
+    pub day: u8,
+
+    /// Which part of the day's puzzle to solve
+    #[arg(short, long, default_value = "1")]
+    pub part: u8,
+
+    /// Path to input file, or several paths, directories or glob patterns to solve each file
+    #[arg(short, long, num_args = 1..)]
+    pub file: Vec<String>,
+
+    /// Give up if the solver takes longer than this many seconds
+    #[arg(short, long, value_parser = parse_seconds)]
+    pub timeout: Option<Duration>,
+
+    /// Print how long reading, parsing and solving each took to stderr
+    #[arg(long, conflicts_with = "timeout")]
+    pub metrics: bool,
+
+    /// Read the input a piece at a time instead of all at once, for the days that support it
+    #[arg(long, conflicts_with_all = ["timeout", "metrics"])]
+    pub stream: bool,
+
+    /// Which year's puzzles to use
+    #[arg(short, long, global = true, default_value_t = crate::DEFAULT_YEAR)]
+    pub year: u16,
+
+    /// Path to the log of submitted answers
+    #[arg(long, global = true, default_value = history::DEFAULT_HISTORY_PATH)]
+    pub history: String,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Download a day's puzzle input into the input directory
+    Fetch {
+        /// Which day's input to download
+        #[arg(short, long, default_value = "1")]
+        day: u8,
+
+        /// Base URL of the Advent of Code website
+        #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Print a day's puzzle description, formatted for the terminal
+    Describe {
+        /// Which day's puzzle to describe
+        #[arg(short, long, default_value = "1")]
+        day: u8,
+
+        /// Only describe this part [default: both parts]
+        #[arg(short, long)]
+        part: Option<u8>,
+
+        /// Wrap lines at this many columns [default: the terminal's width]
+        #[arg(long)]
+        width: Option<usize>,
+    },
+    /// Record the website's verdict for a submitted answer
+    Record {
+        /// The verdict: wrong, too-high, too-low or correct
+        verdict: Verdict,
+
+        /// Which day's puzzle the answer was for
+        #[arg(short, long, default_value = "1")]
+        day: u8,
+
+        /// Which part of the day's puzzle the answer was for
+        #[arg(short, long, default_value = "1")]
+        part: u8,
+
+        /// Path to input file
+        #[arg(short, long)]
+        file: Option<String>,
+
+        /// The submitted answer [default: the solver's answer]
+        #[arg(short, long)]
+        answer: Option<String>,
+    },
+    /// Check solver answers against the stored answer hashes
+    Verify {
+        /// Only verify this day [default: every day]
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Only verify this part [default: both parts]
+        #[arg(short, long)]
+        part: Option<u8>,
+
+        /// Path to input file
+        #[arg(short, long, requires = "day")]
+        file: Option<String>,
+
+        /// Store hashes of the current answers instead of checking them
+        #[arg(long)]
+        update: bool,
+    },
+    /// Print a Markdown table of each day's puzzle, answer status and runtime
+    Report {
+        /// Only report this day [default: every day]
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Replace the report section of this Markdown file instead of printing the table
+        #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = "README.md")]
+        update: Option<String>,
+    },
+    /// List structural problems in puzzle inputs without solving them
+    Check {
+        /// Only check this day [default: every day]
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Path to input file
+        #[arg(short, long, requires = "day")]
+        file: Option<String>,
+    },
+    /// Print measurements of the shape of puzzle inputs
+    Stats {
+        /// Only measure this day [default: every day]
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Path to input file
+        #[arg(short, long, requires = "day")]
+        file: Option<String>,
+    },
+    /// Explore a day's parsed input with commands read from stdin
+    Repl {
+        /// Which day's input to explore
+        #[arg(short, long, default_value = "7")]
+        day: u8,
+
+        /// Path to input file
+        #[arg(short, long)]
+        file: Option<String>,
+    },
+    /// Animate a simulation day's puzzle in the terminal
+    Visualize {
+        /// Which day's puzzle to animate
+        #[arg(short, long, default_value = "14")]
+        day: u8,
+
+        /// Which part of the day's puzzle to animate
+        #[arg(short, long, default_value = "1")]
+        part: u8,
+
+        /// Path to input file
+        #[arg(short, long)]
+        file: Option<String>,
+
+        /// Frames to draw per second, or 0 for as fast as possible
+        #[arg(long, default_value = "30")]
+        fps: f64,
+
+        /// Simulation steps to take between frames
+        #[arg(long, default_value = "1")]
+        steps: usize,
+
+        /// Stop after drawing this many frames
+        #[arg(long)]
+        frames: Option<usize>,
+    },
+    /// Print a random puzzle input for a day
+    #[command(after_long_help = size_help())]
+    Generate {
+        /// Which day's input to generate
+        #[arg(short, long, default_value = "1")]
+        day: u8,
+
+        /// Seed for the random input, which is always the same for the same seed
+        #[arg(long, default_value = "0")]
+        seed: u64,
+
+        /// Size of the input, counted in a unit that depends on the day
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// Write the input to this file instead of printing it
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Compare solvers with slow reference implementations on generated inputs
+    Compare {
+        /// Only compare this day [default: every day with a reference implementation]
+        #[arg(short, long)]
+        day: Option<u8>,
+
+        /// Only compare this part [default: both parts]
+        #[arg(short, long)]
+        part: Option<u8>,
+
+        /// How many inputs to generate, from consecutive seeds
+        #[arg(long, default_value = "100")]
+        inputs: u64,
+
+        /// Seed for the first input
+        #[arg(long, default_value = "0")]
+        seed: u64,
+
+        /// Size of the inputs, counted in a unit that depends on the day
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Serve solvers over HTTP on localhost
+    Serve {
+        /// Port to listen on, or 0 to pick a free port
+        #[arg(long, default_value = "8022")]
+        port: u16,
+
+        /// Give up on solvers that take longer than this many seconds
+        #[arg(short, long, value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+    },
+    /// Print a shell completion script
+    Completions {
+        /// The shell to generate completions for
+        shell: Shell,
+    },
+    /// Print a man page in roff format
+    Manpage,
+}
+
+/// Parses the command-line arguments and runs the selected command, exiting on error.
+pub fn main() {
+    let cli = Cli::parse();
+    match cli.command {
+        Some(Command::Fetch { day, base_url }) => match fetch::fetch(cli.year, day, &base_url) {
+            Ok(Fetched::Cached(path)) => println!("Day {} input already cached: {}", day, path),
+            Ok(Fetched::Downloaded(path)) => println!("Day {} input saved to: {}", day, path),
+            Err(e) => exit_with_error(e),
+        },
+        Some(Command::Describe { day, part, width }) => {
+            if let Err(e) = describe(cli.year, day, part, width) {
+                exit_with_error(e);
+            }
+        }
+        Some(Command::Record {
+            verdict,
+            day,
+            part,
+            file,
+            answer,
+        }) => {
+            if let Err(e) = record(&cli.history, cli.year, day, part, file, answer, verdict) {
+                exit_with_error(e);
+            }
+        }
+        Some(Command::Verify {
+            day,
+            part,
+            file,
+            update,
+        }) => {
+            if let Err(e) = verify_answers(cli.year, day, part, file, update) {
+                exit_with_error(e);
+            }
+        }
+        Some(Command::Report { day, update }) => {
+            if let Err(e) = print_report(cli.year, day, update) {
+                exit_with_error(e);
+            }
+        }
+        Some(Command::Check { day, file }) => {
+            if let Err(e) = builtin_only(cli.year, "check").and_then(|_| check_inputs(day, file)) {
+                exit_with_error(e);
+            }
+        }
+        Some(Command::Stats { day, file }) => {
+            if let Err(e) = builtin_only(cli.year, "stats").and_then(|_| print_stats(day, file)) {
+                exit_with_error(e);
+            }
+        }
+        Some(Command::Repl { day, file }) => {
+            if let Err(e) = builtin_only(cli.year, "repl").and_then(|_| repl(day, file)) {
+                exit_with_error(e);
+            }
+        }
+        Some(Command::Visualize {
+            day,
+            part,
+            file,
+            fps,
+            steps,
+            frames,
+        }) => {
+            let options = visualize::Options { fps, steps, frames };
+            let result = builtin_only(cli.year, "visualize")
+                .and_then(|_| animate(day, part, file, &options));
+            if let Err(e) = result {
+                exit_with_error(e);
+            }
+        }
+        Some(Command::Generate {
+            day,
+            seed,
+            size,
+            output,
+        }) => {
+            let result = builtin_only(cli.year, "generate")
+                .and_then(|_| generate_input(day, seed, size, output));
+            if let Err(e) = result {
+                exit_with_error(e);
+            }
+        }
+        Some(Command::Compare {
+            day,
+            part,
+            inputs,
+            seed,
+            size,
+        }) => {
+            let result = builtin_only(cli.year, "compare")
+                .and_then(|_| compare(day, part, seed, inputs, size));
+            if let Err(e) = result {
+                exit_with_error(e);
+            }
+        }
+        Some(Command::Serve { port, timeout }) => {
+            if let Err(e) = serve(port, timeout) {
+                exit_with_error(e);
+            }
+        }
+        Some(Command::Completions { shell }) => {
+            let mut cmd = command_with_days();
+            let name = cmd.get_name().to_string();
+            clap_complete::generate(shell, &mut cmd, name, &mut io::stdout());
+        }
+        Some(Command::Manpage) => {
+            if let Err(e) = clap_mangen::Man::new(command_with_days()).render(&mut io::stdout()) {
+                exit_with_error(ErrorKind::Io.wrap(e.into()));
+            }
+        }
+        None if batch::is_batch(&cli.file) => {
+            let result = solve_batch(
+                cli.year,
+                cli.day,
+                cli.part,
+                &cli.file,
+                cli.timeout,
+                cli.metrics,
+                cli.stream,
+            );
+            if let Err(e) = result {
+                exit_with_error(e);
+            }
+        }
+        None if cli.stream => {
+            let result = builtin_only(cli.year, "--stream")
+                .and_then(|_| stream::solve(cli.day, cli.part, cli.file.into_iter().next()));
+            match result {
+                Ok(res) => println!("Day {} part {} solution: {}", cli.day, cli.part, res),
+                Err(e) => exit_with_error(e),
+            }
+        }
+        None => match solve(
+            &cli.history,
+            cli.year,
+            cli.day,
+            cli.part,
+            cli.file.into_iter().next(),
+            cli.timeout,
+            cli.metrics,
+        ) {
+            Ok(res) => println!("Day {} part {} solution: {}", cli.day, cli.part, res),
+            Err(e) => exit_with_error(e),
+        },
+    }
+}
+
+/// Solves the puzzle and warns if the answer conflicts with the answer log.
+///
+/// With `metrics`, how long each phase took is printed to stderr, so the answer can still be piped.
+fn solve(
+    history_path: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+    file: Option<String>,
+    timeout: Option<Duration>,
+    metrics: bool,
+) -> Result<String, anyhow::Error> {
+    let (input, read) = metrics::read_input(year, day, part, file)?;
+    let res = match timeout {
+        Some(timeout) => crate::solve_input_with_timeout_year(year, day, part, &input, timeout)?,
+        None if metrics => {
+            let (res, parse, solve) = metrics::solve_input(year, day, part, &input)?;
+            eprintln!("{}", Metrics { read, parse, solve });
+            res
+        }
+        None => crate::solve_input_year(year, day, part, &input)?,
+    };
+    // a broken answer log should not stop the puzzle from being solved
+    match History::load(history_path) {
+        Ok(history) => {
+            let answer = Answer::from(res.clone());
+            for warning in history.warnings(day, part, &input, &answer) {
+                eprintln!("WARNING: {}", warning);
+            }
+        }
+        Err(e) => eprintln!("WARNING: {:#}", e),
+    }
+    Ok(res)
+}
+
+/// Solves each input file, printing a line per file and failing if any of them failed.
+///
+/// Every file is solved even if an earlier one failed, and the error has the category of the
+/// first failure.
+fn solve_batch(
+    year: u16,
+    day: u8,
+    part: u8,
+    paths: &[String],
+    timeout: Option<Duration>,
+    metrics: bool,
+    stream: bool,
+) -> Result<(), anyhow::Error> {
+    if metrics {
+        return Err(anyhow!("--metrics only works with a single input file"))
+            .categorize(ErrorKind::Usage);
+    }
+    if stream {
+        return Err(anyhow!("--stream only works with a single input file"))
+            .categorize(ErrorKind::Usage);
+    }
+    let files = batch::expand(paths)?;
+    let outcomes = batch::solve(year, day, part, &files, timeout)?;
+    let mut failures = 0;
+    let mut kind = None;
+    for outcome in &outcomes {
+        match &outcome.answer {
+            Ok(answer) => println!("{}: {}", outcome.file, answer),
+            Err(e) => {
+                println!("{}: ERROR: {:#}", outcome.file, e);
+                failures += 1;
+                kind = kind.or(ErrorKind::of(e));
+            }
+        }
+    }
+    if failures > 0 {
+        let e = anyhow!("{} of {} input file(s) failed", failures, outcomes.len());
+        return Err(match kind {
+            Some(kind) => kind.wrap(e),
+            None => e,
+        });
+    }
+    Ok(())
+}
+
+/// Prints a day's puzzle description, styled and wrapped to fit the terminal.
+///
+/// When the output isn't a terminal, the description is wrapped at 80 columns without any styling.
+fn describe(
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    width: Option<usize>,
+) -> Result<(), anyhow::Error> {
+    let description = describe::description(year, day, part)?;
+    let styled = io::stdout().is_terminal();
+    let width = width.unwrap_or_else(|| match (styled, terminal_size::terminal_size()) {
+        (true, Some((Width(width), _))) => width as usize,
+        _ => 80,
+    });
+    writeln!(
+        io::stdout(),
+        "{}",
+        describe::render(&description, width, styled)
+    )
+    .context("could not write description")
+    .categorize(ErrorKind::Io)
+}
+
+/// Records a verdict in the answer log, solving the puzzle if no answer was given.
+fn record(
+    history_path: &str,
+    year: u16,
+    day: u8,
+    part: u8,
+    file: Option<String>,
+    answer: Option<String>,
+    verdict: Verdict,
+) -> Result<(), anyhow::Error> {
+    let input = crate::read_input_year(year, day, part, file)?;
+    let answer = match answer {
+        Some(answer) => Answer::from(answer),
+        None => Answer::from(crate::solve_input_year(year, day, part, &input)?),
+    };
+    let mut history = History::load(history_path)?;
+    println!(
+        "Day {} part {} answer {} marked {}",
+        day, part, answer, verdict
+    );
+    history.record(day, part, &input, answer, verdict);
+    history.save(history_path)
+}
+
+/// Verifies or stores the answers for the selected days and parts.
+fn verify_answers(
+    year: u16,
+    day: Option<u8>,
+    part: Option<u8>,
+    file: Option<String>,
+    update: bool,
+) -> Result<(), anyhow::Error> {
+    let days = match day {
+        Some(day) => vec![day],
+        None => crate::days_year(year),
+    };
+    let mut mismatches = 0;
+    for day in days {
+        // a registered day may only have one of its parts
+        let parts = match part {
+            Some(part) => vec![part],
+            None => crate::parts_year(year, day),
+        };
+        for part in parts {
+            if update {
+                verify::store(year, day, part, file.clone())?;
+                println!("Day {} part {}: stored", day, part);
+                continue;
+            }
+            let result = verify::verify(year, day, part, file.clone())?;
+            if result == Verification::Mismatch {
+                mismatches += 1;
+            }
+            println!("Day {} part {}: {}", day, part, result);
+        }
+    }
+    if mismatches > 0 {
+        return Err(anyhow!("{} answer(s) did not match", mismatches))
+            .categorize(ErrorKind::Mismatch);
+    }
+    Ok(())
+}
+
+/// Reports on the selected days, printing the table or writing it into a Markdown file.
+fn print_report(year: u16, day: Option<u8>, update: Option<String>) -> Result<(), anyhow::Error> {
+    let days = match day {
+        Some(day) if crate::parts_year(year, day).is_empty() => {
+            return Err(anyhow!("invalid day: {}", day)).categorize(ErrorKind::Usage);
+        }
+        Some(day) => vec![day],
+        None => crate::days_year(year),
+    };
+    let reports = report::report(year, &days);
+    for report in &reports {
+        for part in &report.parts {
+            // the table only has room for the status, so say what went wrong here
+            if let report::Status::Failed(e) = &part.status {
+                eprintln!("WARNING: day {} part {}: {}", report.day, part.part, e);
+            }
+        }
+    }
+    let table = report::to_markdown(&reports);
+    match update {
+        Some(path) => {
+            report::update_file(&path, &table)?;
+            println!("Report written to: {}", path);
+            Ok(())
+        }
+        None => {
+            println!("{}", table);
+            Ok(())
+        }
+    }
+}
+
+/// Checks the inputs for the selected days and lists every problem found.
+fn check_inputs(day: Option<u8>, file: Option<String>) -> Result<(), anyhow::Error> {
+    let days = match day {
+        Some(day) => vec![day],
+        None => check::days(),
+    };
+    let mut total = 0;
+    for day in days {
+        let input = crate::read_input(day, 1, file.clone())?;
+        let problems = check::check(day, &input)?;
+        match problems.len() {
+            0 => println!("Day {}: ok", day),
+            1 => println!("Day {}: 1 problem", day),
+            count => println!("Day {}: {} problems", day, count),
+        }
+        for problem in &problems {
+            println!("  {}", problem);
+        }
+        total += problems.len();
+    }
+    if total > 0 {
+        return Err(anyhow!("{} problem(s) found", total)).categorize(ErrorKind::Parse);
+    }
+    Ok(())
+}
+
+/// Prints the statistics of the inputs for the selected days, with their values lined up.
+fn print_stats(day: Option<u8>, file: Option<String>) -> Result<(), anyhow::Error> {
+    let days = match day {
+        Some(day) => vec![day],
+        None => stats::days(),
+    };
+    for day in days {
+        let input = crate::read_input(day, 1, file.clone())?;
+        let stats = stats::stats(day, &input)?;
+        let width = stats.iter().map(|stat| stat.name.len()).max().unwrap_or(0);
+        println!("Day {}:", day);
+        for stat in &stats {
+            println!(
+                "  {:<width$}  {}",
+                format!("{}:", stat.name),
+                stat.value,
+                width = width + 1
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Loads a day's input and runs explorer commands from stdin until it's closed.
+fn repl(day: u8, file: Option<String>) -> Result<(), anyhow::Error> {
+    let input = crate::read_input(day, 1, file)?;
+    let mut explorer = crate::repl::explorer(day, &input)?;
+    // only prompt when someone is typing, so scripted output stays clean
+    let interactive = io::stdin().is_terminal();
+    if interactive {
+        println!("Exploring day {} input, type help for commands", day);
+    }
+    crate::repl::run(
+        day,
+        &input,
+        explorer.as_mut(),
+        io::stdin().lock(),
+        io::stdout(),
+        interactive,
+    )
+}
+
+/// Plays a day's animation in the terminal until it finishes or Ctrl-C is pressed.
+fn animate(
+    day: u8,
+    part: u8,
+    file: Option<String>,
+    options: &visualize::Options,
+) -> Result<(), anyhow::Error> {
+    let input = crate::read_input(day, part, file)?;
+    let mut animation = visualize::animation(day, part, &input)?;
+    // stop between frames instead of dying, so the terminal can be restored
+    let stop = Arc::new(AtomicBool::new(false));
+    let handler_stop = stop.clone();
+    ctrlc::set_handler(move || handler_stop.store(true, Ordering::Relaxed))
+        .context("could not handle Ctrl-C")?;
+    let size = || match terminal_size::terminal_size() {
+        Some((Width(width), Height(height))) => (width as usize, height as usize),
+        None => (80, 24),
+    };
+    let mut stdout = io::stdout();
+    let interactive = stdout.is_terminal();
+    if interactive {
+        write!(stdout, "{}", visualize::ENTER_SCREEN)?;
+    }
+    let result = visualize::play(animation.as_mut(), options, &mut stdout, size, &stop);
+    if interactive {
+        write!(stdout, "{}", visualize::LEAVE_SCREEN)?;
+    } else {
+        writeln!(stdout)?;
+    }
+    result?;
+    println!("Day {} part {}: {}", day, part, animation.status());
+    Ok(())
+}
+
+/// Generates a day's input and writes it to a file or stdout.
+fn generate_input(
+    day: u8,
+    seed: u64,
+    size: Option<usize>,
+    output: Option<String>,
+) -> Result<(), anyhow::Error> {
+    let input = generate::generate(day, seed, size)?;
+    match output {
+        Some(path) => fs::write(&path, input)
+            .with_context(|| format!("could not write input file: {}", path))
+            .categorize(ErrorKind::Io),
+        None => {
+            let mut stdout = io::stdout();
+            // inputs don't end with a newline, but a prompt shouldn't end up after the last line
+            match stdout.is_terminal() {
+                true => writeln!(stdout, "{}", input),
+                false => write!(stdout, "{}", input),
+            }
+            .context("could not write input")
+            .categorize(ErrorKind::Io)
+        }
+    }
+}
+
+/// Compares the selected days and parts with their reference implementations on inputs generated
+/// from consecutive seeds, printing the first disagreement for each.
+fn compare(
+    day: Option<u8>,
+    part: Option<u8>,
+    seed: u64,
+    inputs: u64,
+    size: Option<usize>,
+) -> Result<(), anyhow::Error> {
+    let seeds = seed..seed.saturating_add(inputs);
+    let days = match day {
+        Some(day) => vec![day],
+        None => differential::days(),
+    };
+    let mut disagreements = 0;
+    for day in days {
+        let parts = match part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        };
+        for part in parts {
+            match differential::compare(day, part, seeds.clone(), size)? {
+                None => println!("Day {} part {}: agree on {} input(s)", day, part, inputs),
+                Some(disagreement) => {
+                    disagreements += 1;
+                    println!("Day {} part {}: disagree", day, part);
+                    for line in disagreement.to_string().lines() {
+                        println!("  {}", line);
+                    }
+                    let size = size.map(|s| format!(" --size {}", s)).unwrap_or_default();
+                    println!(
+                        "  input:     aoc generate -d {} --seed {}{}",
+                        day, disagreement.seed, size
+                    );
+                }
+            }
+        }
+    }
+    if disagreements > 0 {
+        return Err(anyhow!(
+            "{} solver(s) disagreed with the reference implementation",
+            disagreements
+        ))
+        .categorize(ErrorKind::Mismatch);
+    }
+    Ok(())
+}
+
+/// Lists what the generator's size means for each day, shown at the end of `generate --help`.
+fn size_help() -> String {
+    let mut help = String::from("Sizes by day:");
+    for day in generate::days() {
+        if let Ok(description) = generate::size_description(day) {
+            help.push_str(&format!("\n  {:>2}  {}", day, description));
+        }
+    }
+    help
+}
+
+/// Runs the HTTP service until the process is stopped.
+fn serve(port: u16, timeout: Option<Duration>) -> Result<(), anyhow::Error> {
+    let server = Server::bind(port, timeout)?;
+    println!("Listening on http://{}", server.local_addr()?);
+    // make sure whoever started the service sees the address before any requests arrive
+    io::stdout().flush()?;
+    server.run()
+}
+
+/// Builds the CLI definition with the valid values for each `--day` argument filled in.
+///
+/// Days are parsed as plain numbers so that an invalid day is reported by the solver, but the
+/// completion scripts and man page should still offer the days that can actually be used.
+fn command_with_days() -> clap::Command {
+    let mut solved_days = crate::years()
+        .into_iter()
+        .flat_map(crate::days_year)
+        .collect::<Vec<_>>();
+    solved_days.sort();
+    solved_days.dedup();
+    let solved_days = solved_days.iter().map(u8::to_string).collect::<Vec<_>>();
+    // any puzzle day can be fetched, even if it hasn't been solved yet
+    let puzzle_days = (1..=25).map(|d: u8| d.to_string()).collect::<Vec<_>>();
+    let mut cmd = Cli::command().mut_arg("day", |arg| {
+        arg.value_parser(PossibleValuesParser::new(solved_days.clone()))
+    });
+    let subcommands = cmd
+        .get_subcommands()
+        .filter(|sub| sub.get_arguments().any(|arg| arg.get_id() == "day"))
+        .map(|sub| sub.get_name().to_string())
+        .collect::<Vec<_>>();
+    for name in subcommands {
+        let days = match name.as_str() {
+            "fetch" => puzzle_days.clone(),
+            "check" => check::days().iter().map(u8::to_string).collect(),
+            "compare" => differential::days().iter().map(u8::to_string).collect(),
+            "generate" => generate::days().iter().map(u8::to_string).collect(),
+            "repl" => crate::repl::days().iter().map(u8::to_string).collect(),
+            "stats" => stats::days().iter().map(u8::to_string).collect(),
+            "visualize" => visualize::days().iter().map(u8::to_string).collect(),
+            _ => solved_days.clone(),
+        };
+        cmd = cmd.mut_subcommand(name, |sub| {
+            sub.mut_arg("day", |arg| {
+                arg.value_parser(PossibleValuesParser::new(days))
+            })
+        });
+    }
+    cmd
+}
+
+/// Checks that a command which only works with the built-in solvers was given the built-in year.
+fn builtin_only(year: u16, command: &str) -> Result<(), anyhow::Error> {
+    if year != crate::DEFAULT_YEAR {
+        return Err(anyhow!(
+            "{} is only available for {}, not {}",
+            command,
+            crate::DEFAULT_YEAR,
+            year
+        ))
+        .categorize(ErrorKind::Usage);
+    }
+    Ok(())
+}
+
+/// Parses a number of seconds, which may be fractional, into a duration.
+fn parse_seconds(s: &str) -> Result<Duration, anyhow::Error> {
+    let seconds = s.parse::<f64>()?;
+    Ok(Duration::try_from_secs_f64(seconds)?)
+}
+
+/// Prints the error and exits with the code for its category.
+fn exit_with_error(e: anyhow::Error) -> ! {
+    eprintln!("ERROR: {:#}", e);
+    process::exit(ErrorKind::of(&e).map_or(1, |kind| kind.exit_code()));
+}
@@ -0,0 +1,311 @@
+//! Puzzle descriptions formatted for the terminal.
+//!
+//! Each day's puzzle text is kept as Markdown in `docs/day_XX.md`, using only a little of it:
+//! headings such as `**--- Part Two ---**`, paragraphs on a single line, lists, inline code and
+//! fenced code blocks. [render] turns that into text that fits the terminal, with bold headings,
+//! highlighted inline code and indented code blocks.
+
+use crate::error::{Categorize, ErrorKind};
+use anyhow::{anyhow, Context};
+use std::fs;
+
+/// Bold text, used for headings and other strong text.
+const BOLD: &str = "\x1b[1m";
+/// Cyan text, used for inline code.
+const CODE: &str = "\x1b[36m";
+/// Resets all text attributes.
+const RESET: &str = "\x1b[0m";
+
+/// The heading that starts the description of part 2.
+const PART_TWO: &str = "**--- Part Two ---**";
+
+/// How the paragraphs giving a solved puzzle's answers start.
+const ANSWER: &str = "Your puzzle answer was";
+
+/// How many columns code blocks are indented by.
+const INDENT: usize = 4;
+
+/// Reads the Markdown description of a day's puzzle, or of only one of its parts.
+///
+/// The description of part 2 starts with the day's heading, so it's clear which puzzle it's for.
+/// An error categorized as [ErrorKind::Usage] is returned if the day or part is invalid, and one
+/// categorized as [ErrorKind::Io] if the day has no description.
+pub fn description(year: u16, day: u8, part: Option<u8>) -> Result<String, anyhow::Error> {
+    crate::solution::validate(year, day, part.unwrap_or(1))?;
+    let path = crate::solution::get_docs_path(year, day);
+    let description = fs::read_to_string(&path)
+        .with_context(|| format!("could not read puzzle description: {}", path))
+        .categorize(ErrorKind::Io)?;
+    match part {
+        Some(part) => select_part(&description, part)
+            .with_context(|| format!("invalid puzzle description: {}", path)),
+        None => Ok(description),
+    }
+}
+
+/// Picks out the description of one part from the description of the whole day.
+fn select_part(description: &str, part: u8) -> Result<String, anyhow::Error> {
+    let lines = description.lines().collect::<Vec<&str>>();
+    let part_two = lines
+        .iter()
+        .position(|line| line.trim() == PART_TWO)
+        .ok_or_else(|| anyhow!("could not find the description of part 2"))
+        .categorize(ErrorKind::Parse)?;
+    let selected = match part {
+        1 => lines[..part_two].to_vec(),
+        _ => [&lines[..1], &[""], &lines[part_two..]].concat(),
+    };
+    Ok(selected.join("\n").trim_end().to_string())
+}
+
+/// A run of text in a single style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Plain,
+    Bold,
+    Code,
+}
+
+/// Splits a line into runs of plain text, `**bold**` text and `` `inline code` ``.
+///
+/// A marker without a matching one after it is kept as plain text.
+fn spans(line: &str) -> Vec<(&str, Style)> {
+    let mut spans = Vec::new();
+    let mut rest = line;
+    while !rest.is_empty() {
+        let next = [("`", Style::Code), ("**", Style::Bold)]
+            .into_iter()
+            .filter_map(|(marker, style)| {
+                let start = rest.find(marker)?;
+                let end = rest[start + marker.len()..].find(marker)? + start + marker.len();
+                Some((start, end, marker.len(), style))
+            })
+            .min_by_key(|(start, ..)| *start);
+        match next {
+            Some((start, end, marker, style)) => {
+                if start > 0 {
+                    spans.push((&rest[..start], Style::Plain));
+                }
+                spans.push((&rest[start + marker..end], style));
+                rest = &rest[end + marker..];
+            }
+            None => {
+                spans.push((rest, Style::Plain));
+                rest = "";
+            }
+        }
+    }
+    spans
+}
+
+/// Wraps styled text into lines no wider than `width` columns, not counting the indent.
+///
+/// Words are only broken at spaces, so a word longer than the width gets a line to itself.
+fn wrap(spans: &[(&str, Style)], width: usize) -> Vec<Vec<(String, Style)>> {
+    // each word is made of pieces in different styles, such as `X` followed by a full stop
+    let mut words: Vec<Vec<(String, Style)>> = vec![Vec::new()];
+    for (text, style) in spans {
+        for (i, piece) in text.split(' ').enumerate() {
+            if i > 0 {
+                words.push(Vec::new());
+            }
+            if let Some(word) = words.last_mut().filter(|_| !piece.is_empty()) {
+                word.push((piece.to_string(), *style));
+            }
+        }
+    }
+    let mut lines: Vec<Vec<(String, Style)>> = Vec::new();
+    let mut line_width = 0;
+    for word in words.into_iter().filter(|word| !word.is_empty()) {
+        let word_width = word
+            .iter()
+            .map(|(text, _)| text.chars().count())
+            .sum::<usize>();
+        match lines.last_mut() {
+            Some(line) if line_width + 1 + word_width <= width => {
+                // a space between two words in the same style keeps that style
+                let space = match (line.last(), word.first()) {
+                    (Some((_, before)), Some((_, after))) if before == after => *before,
+                    _ => Style::Plain,
+                };
+                line.push((" ".to_string(), space));
+                line.extend(word);
+                line_width += 1 + word_width;
+            }
+            _ => {
+                lines.push(word);
+                line_width = word_width;
+            }
+        }
+    }
+    lines
+}
+
+/// Formats the pieces of a line, using escape codes for their styles only if `styled` is set.
+fn format_line(pieces: &[(String, Style)], styled: bool) -> String {
+    // runs of pieces in the same style only need their escape codes once
+    let mut runs: Vec<(String, Style)> = Vec::new();
+    for (text, style) in pieces {
+        match runs.last_mut() {
+            Some((run, run_style)) if run_style == style => run.push_str(text),
+            _ => runs.push((text.clone(), *style)),
+        }
+    }
+    let mut line = String::new();
+    for (text, style) in &runs {
+        match (styled, style) {
+            (true, Style::Bold) => line.push_str(&format!("{}{}{}", BOLD, text, RESET)),
+            (true, Style::Code) => line.push_str(&format!("{}{}{}", CODE, text, RESET)),
+            _ => line.push_str(text),
+        }
+    }
+    line
+}
+
+/// Renders Markdown as text wrapped to `width` columns.
+///
+/// Headings and `**bold**` text are made bold and inline code is highlighted, using ANSI escape
+/// codes if `styled` is set. Without it the markup is only removed, for output that isn't going to
+/// a terminal. Code blocks are indented and never wrapped, since their lines often line up.
+/// Paragraphs giving the puzzle's answers are left out, so they aren't printed in plain text.
+///
+/// # Examples
+///
+/// ```
+/// let markdown = "**--- Day 1 ---**\nThe Elves carry `1000` Calories.\n```\n1000\n```";
+/// let text = aoc::describe::render(markdown, 20, false);
+/// assert_eq!("--- Day 1 ---\nThe Elves carry 1000\nCalories.\n    1000", text);
+/// ```
+pub fn render(markdown: &str, width: usize, styled: bool) -> String {
+    let mut output = Vec::new();
+    let mut in_code = false;
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            output.push(
+                format!("{}{}", " ".repeat(INDENT), line)
+                    .trim_end()
+                    .to_string(),
+            );
+            continue;
+        }
+        if line.starts_with(ANSWER) {
+            if output.last().is_some_and(String::is_empty) {
+                output.pop();
+            }
+            continue;
+        }
+        let (first_prefix, prefix, text, heading) = if let Some(item) = line.strip_prefix("- ") {
+            ("- ", "  ", item, false)
+        } else if line.starts_with('#') {
+            ("", "", line.trim_start_matches('#').trim_start(), true)
+        } else {
+            ("", "", line, false)
+        };
+        let mut spans = spans(text);
+        if heading {
+            for (_, style) in spans.iter_mut() {
+                *style = Style::Bold;
+            }
+        }
+        let lines = wrap(&spans, width.saturating_sub(prefix.len()).max(1));
+        if lines.is_empty() {
+            output.push(String::new());
+        }
+        for (i, pieces) in lines.iter().enumerate() {
+            let prefix = if i == 0 { first_prefix } else { prefix };
+            output.push(format!("{}{}", prefix, format_line(pieces, styled)));
+        }
+    }
+    output.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_description() {
+        let description = description(crate::DEFAULT_YEAR, 1, None).expect("should return result");
+        assert!(description.starts_with("**--- Day 1: Calorie Counting ---**"));
+        let part_1 =
+            super::description(crate::DEFAULT_YEAR, 1, Some(1)).expect("should return result");
+        assert!(!part_1.contains(PART_TWO));
+        let part_2 =
+            super::description(crate::DEFAULT_YEAR, 1, Some(2)).expect("should return result");
+        assert!(part_2.starts_with("**--- Day 1: Calorie Counting ---**\n\n**--- Part Two ---**"));
+        let error =
+            super::description(crate::DEFAULT_YEAR, 1, Some(3)).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+        let error =
+            super::description(crate::DEFAULT_YEAR, 15, None).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+    }
+
+    #[test]
+    fn test_select_part() {
+        let description = "**--- Day 1 ---**\none\n\n**--- Part Two ---**\ntwo\n";
+        assert_eq!(
+            "**--- Day 1 ---**\none",
+            select_part(description, 1).expect("should return result")
+        );
+        assert_eq!(
+            "**--- Day 1 ---**\n\n**--- Part Two ---**\ntwo",
+            select_part(description, 2).expect("should return result")
+        );
+        let error = select_part("**--- Day 1 ---**", 2).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Parse), ErrorKind::of(&error));
+    }
+
+    #[test]
+    fn test_spans() {
+        assert_eq!(
+            vec![
+                ("a ", Style::Plain),
+                ("b", Style::Code),
+                (" ", Style::Plain),
+                ("c", Style::Bold),
+                (" `d", Style::Plain)
+            ],
+            spans("a `b` **c** `d")
+        );
+        // markers inside code are part of the code
+        assert_eq!(vec![("1 ** 2", Style::Code)], spans("`1 ** 2`"));
+    }
+
+    #[test]
+    fn test_render() {
+        let markdown = "# Title
+- a list item that `wraps`.
+
+```
+ [Z]   long code lines are never wrapped
+```";
+        assert_eq!(
+            "Title
+- a list item
+  that wraps.
+
+     [Z]   long code lines are never wrapped",
+            render(markdown, 15, false)
+        );
+        assert_eq!(
+            "\x1b[1mA title\x1b[0m\n- a \x1b[36mx\x1b[0m.",
+            render("# A title\n- a `x`.", 15, true)
+        );
+    }
+
+    #[test]
+    fn test_render_answers() {
+        let markdown =
+            "one\n\nYour puzzle answer was `69626`.\n\n# Two\n\nYour puzzle answer was `1`.";
+        assert_eq!("one\n\nTwo", render(markdown, 80, false));
+        let description = description(crate::DEFAULT_YEAR, 1, None).expect("should return result");
+        let text = render(&description, 80, false);
+        assert!(!text.contains(ANSWER));
+        assert!(!text.contains("206780"));
+    }
+}
@@ -0,0 +1,137 @@
+//! Differential testing of solvers against slow reference implementations.
+//!
+//! Some solvers rely on shortcuts that are easy to get subtly wrong: day 4 decides whether two
+//! ranges overlap from their ends, day 11 keeps worry levels below the product of the monkeys'
+//! divisors, and day 13 compares packets with a hand-written [Ord]. Each of those days also has a
+//! reference implementation that does the obvious thing instead, however slowly. [compare] solves
+//! generated inputs with both and reports the first input where they disagree.
+//!
+//! ```
+//! let disagreement = aoc::differential::compare(4, 2, 0..10, Some(20)).unwrap();
+//! assert!(disagreement.is_none());
+//! ```
+
+use crate::generate;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+/// Returns every day that has a reference implementation, in order.
+pub fn days() -> Vec<u8> {
+    crate::solution::reference_days()
+}
+
+/// Solves an input for a day and part with the day's reference implementation.
+///
+/// An error is returned if the day has no reference implementation, the part is invalid, or the
+/// input cannot be solved.
+///
+/// # Examples
+///
+/// ```
+/// let answer = aoc::differential::reference(4, 1, "2-8,3-7\n6-6,4-6\n2-4,6-8").unwrap();
+/// assert_eq!("2", answer);
+/// ```
+pub fn reference(day: u8, part: u8, input: &str) -> Result<String, anyhow::Error> {
+    crate::solution::reference(day, part, input)
+}
+
+/// A generated input that the solver and the reference implementation disagree on.
+///
+/// Each answer is the error message instead if solving failed. Both failing counts as agreeing,
+/// since the input is then rejected either way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    /// The seed the input was generated from.
+    pub seed: u64,
+    pub input: String,
+    /// The answer from the day's solver.
+    pub answer: Result<String, String>,
+    /// The answer from the day's reference implementation.
+    pub expected: Result<String, String>,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let show = |answer: &Result<String, String>| match answer {
+            Ok(answer) => answer.clone(),
+            Err(e) => format!("error: {}", e),
+        };
+        writeln!(f, "seed:      {}", self.seed)?;
+        writeln!(f, "solver:    {}", show(&self.answer))?;
+        write!(f, "reference: {}", show(&self.expected))
+    }
+}
+
+/// Solves the inputs generated from each seed with both the solver and the reference
+/// implementation, stopping at the first input where their answers differ.
+///
+/// Returns `None` if they agree on every input. An error is returned if the day has no reference
+/// implementation or no generator, or the size is invalid.
+pub fn compare(
+    day: u8,
+    part: u8,
+    seeds: Range<u64>,
+    size: Option<usize>,
+) -> Result<Option<Disagreement>, anyhow::Error> {
+    // an unusable day or part is reported even if there are no seeds
+    crate::solution::reference_solver(day, part)?;
+    for seed in seeds {
+        let input = generate::generate(day, seed, size)?;
+        let answer = crate::solve_input(day, part, &input);
+        let expected = reference(day, part, &input);
+        let agree = match (&answer, &expected) {
+            (Ok(answer), Ok(expected)) => answer == expected,
+            (Err(_), Err(_)) => true,
+            _ => false,
+        };
+        if !agree {
+            return Ok(Some(Disagreement {
+                seed,
+                input,
+                answer: answer.map_err(|e| format!("{:#}", e)),
+                expected: expected.map_err(|e| format!("{:#}", e)),
+            }));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn test_compare() {
+        for day in days() {
+            for part in 1..=2 {
+                let disagreement = compare(day, part, 0..10, None).expect("should return result");
+                assert_eq!(None, disagreement, "day {} part {}", day, part);
+            }
+        }
+    }
+
+    #[test]
+    fn test_compare_invalid() {
+        let error = compare(1, 1, 0..1, None).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+        let error = compare(4, 3, 0..0, None).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+        let error = compare(4, 1, 0..1, Some(0)).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+    }
+
+    #[test]
+    fn test_display() {
+        let disagreement = Disagreement {
+            seed: 3,
+            input: String::new(),
+            answer: Ok("12".to_string()),
+            expected: Err("invalid packet".to_string()),
+        };
+        assert_eq!(
+            "seed:      3\nsolver:    12\nreference: error: invalid packet",
+            disagreement.to_string()
+        );
+    }
+}
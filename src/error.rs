@@ -0,0 +1,190 @@
+//! Categories that tell different kinds of failure apart.
+//!
+//! Errors throughout the crate are [anyhow::Error]s, which carry a readable message but no type.
+//! An [ErrorKind] can be attached to an error without changing its message, so callers such as the
+//! CLI can react to the category of a failure rather than its wording. The CLI uses the category to
+//! pick its exit code:
+//!
+//! | Code | Category                 |
+//! |------|--------------------------|
+//! | 0    | success                  |
+//! | 1    | uncategorized error      |
+//! | 2    | [ErrorKind::Usage]       |
+//! | 3    | [ErrorKind::Io]          |
+//! | 4    | [ErrorKind::Parse]       |
+//! | 5    | [ErrorKind::Simulation]  |
+//! | 6    | [ErrorKind::Timeout]     |
+//! | 7    | [ErrorKind::Mismatch]    |
+
+use anyhow::anyhow;
+use std::error::Error;
+use std::fmt;
+
+/// The category of a failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The request itself was invalid, such as a day or part without a solver.
+    Usage,
+    /// A file could not be read or written, or a download failed.
+    Io,
+    /// The puzzle input was not in the expected format.
+    Parse,
+    /// The input parsed, but solving it reached an impossible state.
+    Simulation,
+    /// The solver did not finish within the time allowed.
+    Timeout,
+    /// An answer did not match the expected answer.
+    Mismatch,
+}
+
+impl ErrorKind {
+    /// Returns the category attached to an error, if any.
+    ///
+    /// If several categories were attached, the innermost one is returned since it was attached
+    /// closest to where the failure happened.
+    pub fn of(error: &anyhow::Error) -> Option<ErrorKind> {
+        // a categorized error's source skips the error it wraps, so that error is looked through
+        // separately in case it was categorized too
+        error
+            .chain()
+            .filter_map(|e| e.downcast_ref::<Categorized>())
+            .last()
+            .map(|c| ErrorKind::of(&c.error).unwrap_or(c.kind))
+    }
+
+    /// Attaches this category to an error without changing how the error is displayed.
+    pub fn wrap(self, error: anyhow::Error) -> anyhow::Error {
+        anyhow::Error::new(Categorized { kind: self, error })
+    }
+
+    /// Returns the process exit code used by the CLI for this category.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ErrorKind::Usage => 2,
+            ErrorKind::Io => 3,
+            ErrorKind::Parse => 4,
+            ErrorKind::Simulation => 5,
+            ErrorKind::Timeout => 6,
+            ErrorKind::Mismatch => 7,
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ErrorKind::Usage => "usage",
+            ErrorKind::Io => "io",
+            ErrorKind::Parse => "parse",
+            ErrorKind::Simulation => "simulation",
+            ErrorKind::Timeout => "timeout",
+            ErrorKind::Mismatch => "mismatch",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Extension trait for attaching an [ErrorKind] to the error of a [Result].
+pub trait Categorize<T> {
+    /// Attaches the category to the error, if there is one.
+    fn categorize(self, kind: ErrorKind) -> Result<T, anyhow::Error>;
+}
+
+impl<T> Categorize<T> for Result<T, anyhow::Error> {
+    fn categorize(self, kind: ErrorKind) -> Result<T, anyhow::Error> {
+        self.map_err(|e| kind.wrap(e))
+    }
+}
+
+/// Returns the error for puzzle input that isn't valid UTF-8.
+///
+/// Solvers only take text, so bytes that don't decode are input in the wrong format, categorized
+/// as [ErrorKind::Parse] whether they came from a file, a stream, a request or a C caller.
+pub fn invalid_utf8() -> anyhow::Error {
+    ErrorKind::Parse.wrap(anyhow!("input is not valid UTF-8"))
+}
+
+/// Returns the error for a solver that panicked.
+///
+/// A panic means the solver reached a state it doesn't handle, so it's categorized as
+/// [ErrorKind::Simulation] by everything that catches one: [crate::batch], [crate::serve] and the C
+/// interface.
+pub fn solver_panicked() -> anyhow::Error {
+    ErrorKind::Simulation.wrap(anyhow!("solver panicked"))
+}
+
+/// An error with a category attached, displayed exactly like the error it wraps.
+#[derive(Debug)]
+struct Categorized {
+    kind: ErrorKind,
+    error: anyhow::Error,
+}
+
+impl fmt::Display for Categorized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // only the outermost message is shown here, the sources are part of the error chain
+        write!(f, "{}", self.error)
+    }
+}
+
+impl Error for Categorized {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        // the wrapped error's own message is already the one displayed, so it isn't repeated here
+        self.error.source()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_categorize() {
+        let result: Result<(), anyhow::Error> = Err(anyhow!("bad token"));
+        let error = result.categorize(ErrorKind::Parse).unwrap_err();
+        assert_eq!(Some(ErrorKind::Parse), ErrorKind::of(&error));
+        assert_eq!("bad token", error.to_string());
+        assert_eq!(None, ErrorKind::of(&anyhow!("bad token")));
+    }
+
+    #[test]
+    fn test_categorize_keeps_chain() {
+        let result: Result<(), anyhow::Error> =
+            Err(anyhow!("inner")).context("outer").context("outermost");
+        let error = result.categorize(ErrorKind::Io).unwrap_err();
+        assert_eq!("outermost: outer: inner", format!("{:#}", error));
+    }
+
+    #[test]
+    fn test_innermost_category_wins() {
+        let error = ErrorKind::Simulation.wrap(anyhow!("no such monkey"));
+        let error = ErrorKind::Parse.wrap(error.context("while solving"));
+        assert_eq!(Some(ErrorKind::Simulation), ErrorKind::of(&error));
+    }
+
+    #[test]
+    fn test_innermost_category_wins_directly_nested() {
+        let error = ErrorKind::Simulation.wrap(anyhow!("no such monkey"));
+        let error = ErrorKind::Parse.wrap(error);
+        assert_eq!(Some(ErrorKind::Simulation), ErrorKind::of(&error));
+        assert_eq!("no such monkey", format!("{:#}", error));
+        let result: Result<(), anyhow::Error> = Err(error);
+        let error = result.categorize(ErrorKind::Io).unwrap_err();
+        assert_eq!(Some(ErrorKind::Simulation), ErrorKind::of(&error));
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let error = invalid_utf8();
+        assert_eq!(Some(ErrorKind::Parse), ErrorKind::of(&error));
+        assert_eq!("input is not valid UTF-8", error.to_string());
+    }
+
+    #[test]
+    fn test_solver_panicked() {
+        let error = solver_panicked();
+        assert_eq!(Some(ErrorKind::Simulation), ErrorKind::of(&error));
+        assert_eq!("solver panicked", error.to_string());
+    }
+}
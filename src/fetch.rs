@@ -0,0 +1,145 @@
+//! Downloads puzzle inputs from the Advent of Code website.
+//!
+//! Inputs are saved into the same layout that [solve](crate::solve) reads from by default
+//! (`input/day_XX/input.txt`, or `input/YYYY/day_XX/input.txt` for years other than
+//! [DEFAULT_YEAR](crate::DEFAULT_YEAR)), so a fetched input can be solved immediately. A file that already
+//! exists is treated as a cache hit and is never downloaded again.
+//!
+//! Each user's input is tied to their account, so requests are authenticated with the `session`
+//! cookie from a logged-in browser. The token is read from the `AOC_SESSION` environment variable
+//! or, if that is not set, from the `aoc/session` file in the user's config directory.
+
+use crate::error::{Categorize, ErrorKind};
+use crate::solution::{get_file_path, validate_year};
+use anyhow::{anyhow, Context};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The base URL of the Advent of Code website.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session token.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Outcome of a call to [fetch], holding the path of the input file.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input file already existed and was left untouched.
+    Cached(String),
+    /// The input file was downloaded and written to disk.
+    Downloaded(String),
+}
+
+/// Downloads the selected year and day's input unless it has already been saved.
+///
+/// The input is requested from `{base_url}/{year}/day/{day}/input`. Overriding the base URL makes it
+/// possible to point the fetcher at a mirror or a local stand-in server.
+///
+/// The website serves inputs with a trailing newline, but the solvers expect the input exactly as
+/// stored in the repository, so the trailing newline is removed before the file is written.
+///
+/// An error is returned if the year or day is not a puzzle day, no session token can be found, the request
+/// fails, or the file cannot be written. A session token is only required if the input is not
+/// already cached.
+pub fn fetch(year: u16, day: u8, base_url: &str) -> Result<Fetched, anyhow::Error> {
+    validate_year(year)?;
+    if !(1..=25).contains(&day) {
+        return Err(anyhow!("invalid day: {}", day)).categorize(ErrorKind::Usage);
+    }
+    let file_path = get_file_path(year, day, 1, None)?;
+    // never download an input twice
+    if Path::new(&file_path).exists() {
+        return Ok(Fetched::Cached(file_path));
+    }
+    let session = session_token().categorize(ErrorKind::Usage)?;
+    download(&input_url(base_url, year, day), &session, &file_path).categorize(ErrorKind::Io)?;
+    Ok(Fetched::Downloaded(file_path))
+}
+
+/// Downloads an input and writes it to a file without its trailing newline.
+fn download(url: &str, session: &str, file_path: &str) -> Result<(), anyhow::Error> {
+    let body = ureq::get(url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", concat!("aoc/", env!("CARGO_PKG_VERSION")))
+        .call()
+        .with_context(|| format!("could not download input: {}", url))?
+        .into_string()
+        .with_context(|| format!("could not read response body: {}", url))?;
+    // create the day's directory if this is the first time it is used
+    if let Some(parent) = Path::new(file_path).parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("could not create input directory: {}", parent.display()))?;
+    }
+    fs::write(file_path, body.trim_end_matches('\n'))
+        .with_context(|| format!("could not write input file: {}", file_path))
+}
+
+/// Builds the URL of a day's input from the base URL.
+fn input_url(base_url: &str, year: u16, day: u8) -> String {
+    format!(
+        "{}/{}/day/{}/input",
+        base_url.trim_end_matches('/'),
+        year,
+        day
+    )
+}
+
+/// Finds the session token, preferring the environment over the config file.
+fn session_token() -> Result<String, anyhow::Error> {
+    if let Ok(token) = env::var(SESSION_ENV) {
+        if !token.trim().is_empty() {
+            return Ok(token.trim().to_string());
+        }
+    }
+    let path = config_path().ok_or_else(|| {
+        anyhow!(
+            "no session token: set {} or create the aoc/session config file",
+            SESSION_ENV
+        )
+    })?;
+    let token = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "no session token: set {} or create {}",
+            SESSION_ENV,
+            path.display()
+        )
+    })?;
+    if token.trim().is_empty() {
+        return Err(anyhow!("session file is empty: {}", path.display()));
+    }
+    Ok(token.trim().to_string())
+}
+
+/// Resolves the session config file, following the XDG base directory convention.
+fn config_path() -> Option<PathBuf> {
+    let config_dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+    Some(config_dir.join("aoc").join("session"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_url() {
+        assert_eq!(
+            "https://adventofcode.com/2022/day/3/input",
+            input_url(DEFAULT_BASE_URL, 2022, 3)
+        );
+        assert_eq!(
+            "http://127.0.0.1:8080/2021/day/12/input",
+            input_url("http://127.0.0.1:8080/", 2021, 12)
+        );
+    }
+
+    #[test]
+    fn test_fetch_invalid_day() {
+        assert!(fetch(2022, 0, DEFAULT_BASE_URL).is_err());
+        assert!(fetch(2022, 26, DEFAULT_BASE_URL).is_err());
+        assert!(fetch(2014, 1, DEFAULT_BASE_URL).is_err());
+    }
+}
@@ -0,0 +1,217 @@
+//! Random puzzle inputs for stress testing and benchmarking.
+//!
+//! Only one real input exists per day, so this module builds as many more as needed. Each day's
+//! generator produces input in exactly the format the puzzle uses, and keeps to whatever the puzzle
+//! promises about its input so the solvers can finish: a path up the hill on day 12, enough used
+//! space to need a deletion on day 7, a CRT program that fits the screen on day 10, and so on.
+//!
+//! Generation is deterministic: the same day, seed and size always give the same input, on every
+//! platform and in every release, so a slow or failing input can be shared as just its seed.
+
+use crate::error::{Categorize, ErrorKind};
+use anyhow::anyhow;
+use std::ops::RangeInclusive;
+
+/// Type alias for the function signature that each day's generator uses.
+pub(crate) type GenerateFn = fn(&mut Rng, usize) -> String;
+
+/// A day's generator along with what its size means and the sizes it supports.
+pub(crate) struct Generator {
+    pub(crate) generate: GenerateFn,
+    /// What the size counts, such as `elves` or `monkeys`.
+    pub(crate) unit: &'static str,
+    /// Size used when none is given, roughly that of a real input.
+    pub(crate) default_size: usize,
+    pub(crate) sizes: RangeInclusive<usize>,
+}
+
+/// Returns every day that has a generator, in order.
+pub fn days() -> Vec<u8> {
+    (1..=crate::solution::generators().len() as u8).collect()
+}
+
+/// Describes what the size controls for a day, such as `number of monkeys (2 to 9, default 8)`.
+pub fn size_description(day: u8) -> Result<String, anyhow::Error> {
+    let generator = generator(day)?;
+    Ok(format!(
+        "number of {} ({} to {}, default {})",
+        generator.unit,
+        generator.sizes.start(),
+        generator.sizes.end(),
+        generator.default_size
+    ))
+}
+
+/// Generates a random input for a day from a seed, using the day's default size if none is given.
+///
+/// An error is returned if the day has no generator or the size is outside the range it supports.
+///
+/// # Examples
+///
+/// ```
+/// let input = aoc::generate::generate(2, 7, Some(3)).unwrap();
+/// assert_eq!(3, input.lines().count());
+/// // the same seed always gives the same input
+/// assert_eq!(input, aoc::generate::generate(2, 7, Some(3)).unwrap());
+/// ```
+pub fn generate(day: u8, seed: u64, size: Option<usize>) -> Result<String, anyhow::Error> {
+    let generator = generator(day)?;
+    let size = size.unwrap_or(generator.default_size);
+    if !generator.sizes.contains(&size) {
+        return Err(anyhow!(
+            "invalid size for day {}: {} (must be {} to {} {})",
+            day,
+            size,
+            generator.sizes.start(),
+            generator.sizes.end(),
+            generator.unit
+        ))
+        .categorize(ErrorKind::Usage);
+    }
+    Ok((generator.generate)(&mut Rng::new(seed), size))
+}
+
+/// Looks up the generator for a day.
+fn generator(day: u8) -> Result<Generator, anyhow::Error> {
+    let mut generators = crate::solution::generators();
+    if day == 0 || day as usize > generators.len() {
+        return Err(anyhow!("invalid day: {}", day)).categorize(ErrorKind::Usage);
+    }
+    Ok(generators.swap_remove(day as usize - 1))
+}
+
+/// A small pseudo-random number generator (SplitMix64).
+///
+/// It's implemented here rather than taken from a crate so that the numbers for a seed can never
+/// change underneath saved seeds.
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in the range, with every value equally likely.
+    pub(crate) fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        let span = end - start;
+        if span == u64::MAX {
+            return self.next_u64();
+        }
+        // reject the uneven remainder at the top so that no value is favoured
+        let limit = u64::MAX - (u64::MAX - span) % (span + 1);
+        loop {
+            let value = self.next_u64();
+            if value <= limit {
+                return start + value % (span + 1);
+            }
+        }
+    }
+
+    /// Returns an index below `len`, which must not be zero.
+    pub(crate) fn index(&mut self, len: usize) -> usize {
+        self.range(0..=len as u64 - 1) as usize
+    }
+
+    /// Returns true with the given probability.
+    pub(crate) fn chance(&mut self, probability: f64) -> bool {
+        // the top 53 bits fill a double exactly
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub(crate) fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub(crate) fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    /// Returns a lowercase name with a length in the range.
+    pub(crate) fn name(&mut self, lengths: RangeInclusive<u64>) -> String {
+        (0..self.range(lengths))
+            .map(|_| (b'a' + self.range(0..=25) as u8) as char)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(0);
+        // reference values for SplitMix64 seeded with 0
+        assert_eq!(0xe220_a839_7b1d_cdaf, rng.next_u64());
+        assert_eq!(0x6e78_9e6a_a1b9_65f4, rng.next_u64());
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((3..=5).contains(&rng.range(3..=5)));
+        }
+        assert_eq!(7, rng.range(7..=7));
+        let mut items = (0..10).collect::<Vec<u32>>();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!((0..10).collect::<Vec<u32>>(), items);
+    }
+
+    #[test]
+    fn test_generate() {
+        for day in days() {
+            let generator = generator(day).expect("should return result");
+            let smallest = *generator.sizes.start();
+            for (seed, size) in [
+                (0, smallest),
+                (1, generator.default_size),
+                (2, smallest + 1),
+            ] {
+                let input = generate(day, seed, Some(size)).expect("should return result");
+                assert_eq!(
+                    input,
+                    generate(day, seed, Some(size)).expect("should return result")
+                );
+                assert!(!input.ends_with('\n'), "day {} seed {}", day, seed);
+                for part in 1..=2 {
+                    if let Err(e) = crate::solve_input(day, part, &input) {
+                        panic!(
+                            "day {} part {} seed {}: {:#}\n{}",
+                            day, part, seed, e, input
+                        );
+                    }
+                }
+            }
+            assert_ne!(
+                generate(day, 3, None).expect("should return result"),
+                generate(day, 4, None).expect("should return result")
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate_errors() {
+        let error = generate(15, 0, None).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+        let error = generate(11, 0, Some(10)).expect_err("should return error");
+        assert_eq!(
+            "invalid size for day 11: 10 (must be 2 to 9 monkeys)",
+            error.to_string()
+        );
+        assert_eq!(
+            "number of monkeys (2 to 9, default 8)",
+            size_description(11).expect("should return result")
+        );
+    }
+}
@@ -0,0 +1,353 @@
+//! Local log of answers that have been submitted to the Advent of Code website.
+//!
+//! The website only says whether an answer is right, wrong, too high or too low, and it is easy to
+//! lose track of which candidates were already rejected. [History] keeps those verdicts on disk so
+//! that a new answer can be checked against them before it is submitted.
+//!
+//! Entries are kept per day, part and input. Inputs are identified by a hash of their contents, so
+//! the same input is recognized no matter which path it was read from.
+//!
+//! The log is a plain text file with one tab-separated entry per line:
+//! `<day> <part> <input hash> <verdict> <answer>`. Newlines in answers are escaped as `\n`.
+
+use crate::answer::Answer;
+use crate::error::{Categorize, ErrorKind};
+use anyhow::{anyhow, Context};
+use std::cmp::Ordering;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Default location of the answer log.
+pub const DEFAULT_HISTORY_PATH: &str = "input/history.txt";
+
+/// The website's response to a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer was rejected without a hint.
+    Wrong,
+    /// The answer was rejected for being too high.
+    TooHigh,
+    /// The answer was rejected for being too low.
+    TooLow,
+    /// The answer was accepted.
+    Correct,
+}
+
+impl FromStr for Verdict {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "wrong" => Ok(Verdict::Wrong),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "correct" => Ok(Verdict::Correct),
+            _ => Err(anyhow!(
+                "invalid verdict (expected wrong, too-high, too-low or correct): {}",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Correct => "correct",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A single recorded answer and its verdict.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub verdict: Verdict,
+    pub answer: Answer,
+}
+
+/// The answer log, loaded from and saved to a file.
+#[derive(Debug, Default)]
+pub struct History {
+    entries: Vec<Entry>,
+}
+
+impl History {
+    /// Loads the answer log from a file.
+    ///
+    /// A log that does not exist yet is treated as empty. An error is returned if the file cannot be
+    /// read or any line is malformed.
+    pub fn load(path: &str) -> Result<Self, anyhow::Error> {
+        if !Path::new(path).exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("could not read history file: {}", path))
+            .categorize(ErrorKind::Io)?;
+        let mut entries = Vec::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            entries.push(
+                parse_entry(line)
+                    .with_context(|| format!("invalid history entry on line {}", i + 1))
+                    .categorize(ErrorKind::Parse)?,
+            );
+        }
+        Ok(Self { entries })
+    }
+
+    /// Writes the answer log to a file, creating its directory if needed.
+    pub fn save(&self, path: &str) -> Result<(), anyhow::Error> {
+        let mut contents = String::from("# day\tpart\tinput\tverdict\tanswer\n");
+        for entry in &self.entries {
+            contents.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\n",
+                entry.day,
+                entry.part,
+                entry.input_hash,
+                entry.verdict,
+                escape(&entry.answer.to_string())
+            ));
+        }
+        if let Some(parent) = Path::new(path).parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent)
+                    .with_context(|| {
+                        format!("could not create history directory: {}", parent.display())
+                    })
+                    .categorize(ErrorKind::Io)?;
+            }
+        }
+        fs::write(path, contents)
+            .with_context(|| format!("could not write history file: {}", path))
+            .categorize(ErrorKind::Io)
+    }
+
+    /// Records the verdict for an answer, replacing any earlier verdict for the same answer.
+    pub fn record(&mut self, day: u8, part: u8, input: &str, answer: Answer, verdict: Verdict) {
+        let input_hash = hash_input(input);
+        self.entries.retain(|e| {
+            !(e.day == day && e.part == part && e.input_hash == input_hash && e.answer == answer)
+        });
+        self.entries.push(Entry {
+            day,
+            part,
+            input_hash,
+            verdict,
+            answer,
+        });
+    }
+
+    /// Returns the recorded entries for a day, part and input.
+    pub fn entries(&self, day: u8, part: u8, input: &str) -> Vec<&Entry> {
+        let input_hash = hash_input(input);
+        self.entries
+            .iter()
+            .filter(|e| e.day == day && e.part == part && e.input_hash == input_hash)
+            .collect()
+    }
+
+    /// Checks an answer against the recorded verdicts and returns a warning for each conflict.
+    ///
+    /// An answer conflicts with the log if it was already rejected, if it differs from an answer
+    /// that was accepted, or if it is a number at or above the lowest answer rejected as too high
+    /// (or at or below the highest answer rejected as too low).
+    pub fn warnings(&self, day: u8, part: u8, input: &str, answer: &Answer) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let entries = self.entries(day, part, input);
+        // exact matches with a recorded answer
+        for entry in entries.iter().filter(|e| &e.answer == answer) {
+            if entry.verdict != Verdict::Correct {
+                warnings.push(format!(
+                    "answer {} was already submitted and marked {}",
+                    answer, entry.verdict
+                ));
+            }
+        }
+        // a different answer was already accepted
+        for entry in entries
+            .iter()
+            .filter(|e| e.verdict == Verdict::Correct && &e.answer != answer)
+        {
+            warnings.push(format!(
+                "answer {} differs from the accepted answer {}",
+                answer, entry.answer
+            ));
+        }
+        // bounds from numeric answers that were too high or too low
+        let upper = entries
+            .iter()
+            .filter(|e| e.verdict == Verdict::TooHigh && &e.answer != answer)
+            .map(|e| &e.answer)
+            .filter(|a| answer.compare(a) == Some(Ordering::Greater))
+            .min_by(|a, b| a.compare(b).unwrap_or(Ordering::Equal));
+        if let Some(bound) = upper {
+            warnings.push(format!(
+                "answer {} is above {}, which was already too high",
+                answer, bound
+            ));
+        }
+        let lower = entries
+            .iter()
+            .filter(|e| e.verdict == Verdict::TooLow && &e.answer != answer)
+            .map(|e| &e.answer)
+            .filter(|a| answer.compare(a) == Some(Ordering::Less))
+            .max_by(|a, b| a.compare(b).unwrap_or(Ordering::Equal));
+        if let Some(bound) = lower {
+            warnings.push(format!(
+                "answer {} is below {}, which was already too low",
+                answer, bound
+            ));
+        }
+        warnings
+    }
+}
+
+/// Parses a single tab-separated line of the answer log.
+fn parse_entry(line: &str) -> Result<Entry, anyhow::Error> {
+    let parts = line.splitn(5, '\t').collect::<Vec<&str>>();
+    if parts.len() != 5 {
+        return Err(anyhow!("expected 5 fields: {}", line));
+    }
+    Ok(Entry {
+        day: parts[0]
+            .parse::<u8>()
+            .with_context(|| format!("could not parse token to u8: {}", parts[0]))?,
+        part: parts[1]
+            .parse::<u8>()
+            .with_context(|| format!("could not parse token to u8: {}", parts[1]))?,
+        input_hash: parts[2].to_string(),
+        verdict: parts[3].parse::<Verdict>()?,
+        answer: Answer::from(unescape(parts[4])),
+    })
+}
+
+/// Hashes an input's contents with 64-bit FNV-1a, which is stable across platforms and releases.
+fn hash_input(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Escapes backslashes, tabs and newlines so an answer fits on one line.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+/// Reverses [escape].
+fn unescape(s: &str) -> String {
+    let mut output = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => output.push('\n'),
+            Some('t') => output.push('\t'),
+            Some(other) => output.push(other),
+            None => output.push('\\'),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warnings_known_wrong() {
+        let mut history = History::default();
+        history.record(1, 1, "input", Answer::Number(5), Verdict::Wrong);
+        let warnings = history.warnings(1, 1, "input", &Answer::Number(5));
+        assert_eq!(1, warnings.len());
+        assert!(warnings[0].contains("marked wrong"));
+        // other parts and inputs are unaffected
+        assert!(history
+            .warnings(1, 2, "input", &Answer::Number(5))
+            .is_empty());
+        assert!(history
+            .warnings(1, 1, "other", &Answer::Number(5))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_warnings_bounds() {
+        let mut history = History::default();
+        history.record(3, 2, "input", Answer::Number(100), Verdict::TooHigh);
+        history.record(3, 2, "input", Answer::Number(80), Verdict::TooHigh);
+        history.record(3, 2, "input", Answer::Number(20), Verdict::TooLow);
+        assert!(history
+            .warnings(3, 2, "input", &Answer::Number(50))
+            .is_empty());
+        let warnings = history.warnings(3, 2, "input", &Answer::Number(90));
+        assert_eq!(
+            vec!["answer 90 is above 80, which was already too high"],
+            warnings
+        );
+        let warnings = history.warnings(3, 2, "input", &Answer::Number(10));
+        assert_eq!(
+            vec!["answer 10 is below 20, which was already too low"],
+            warnings
+        );
+    }
+
+    #[test]
+    fn test_warnings_correct() {
+        let mut history = History::default();
+        history.record(
+            5,
+            1,
+            "input",
+            Answer::from("CMZ".to_string()),
+            Verdict::Correct,
+        );
+        let answer = Answer::from("CMZ".to_string());
+        assert!(history.warnings(5, 1, "input", &answer).is_empty());
+        let answer = Answer::from("MCD".to_string());
+        assert_eq!(1, history.warnings(5, 1, "input", &answer).len());
+    }
+
+    #[test]
+    fn test_record_replaces_verdict() {
+        let mut history = History::default();
+        history.record(1, 1, "input", Answer::Number(5), Verdict::Wrong);
+        history.record(1, 1, "input", Answer::Number(5), Verdict::TooLow);
+        let entries = history.entries(1, 1, "input");
+        assert_eq!(1, entries.len());
+        assert_eq!(Verdict::TooLow, entries[0].verdict);
+    }
+
+    #[test]
+    fn test_parse_entry() {
+        let entry = parse_entry("10\t2\tabc\twrong\t\\n##..\\n..##").expect("should return result");
+        assert_eq!(10, entry.day);
+        assert_eq!(2, entry.part);
+        assert_eq!(Verdict::Wrong, entry.verdict);
+        assert_eq!(Answer::Text("\n##..\n..##".to_string()), entry.answer);
+        assert!(parse_entry("1\t1\tabc\tmaybe\t5").is_err());
+    }
+
+    #[test]
+    fn test_escape_round_trip() {
+        let s = "a\tb\\c\nd";
+        assert_eq!(s, unescape(&escape(s)));
+    }
+}
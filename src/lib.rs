@@ -1,5 +1,34 @@
 #![doc = include_str!("../README.md")]
 
+pub mod answer;
+pub mod batch;
+pub mod check;
+pub mod cli;
+pub mod describe;
+pub mod differential;
+pub mod error;
+pub mod fetch;
+pub mod generate;
+pub mod history;
+pub mod metrics;
+pub mod registry;
+pub mod repl;
+pub mod report;
+pub mod search;
+pub mod serve;
+pub mod simulation;
 mod solution;
+pub mod stats;
+pub mod stream;
+pub mod verify;
+pub mod visualize;
 
-pub use solution::solve;
+pub use solution::{
+    day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09, day_10, day_11, day_12,
+    day_13, day_14,
+};
+pub use solution::{
+    days, days_year, parse, parts, parts_year, read_input, read_input_year, solve, solve_input,
+    solve_input_with_timeout, solve_input_with_timeout_year, solve_input_year, solve_year, years,
+    DEFAULT_YEAR,
+};
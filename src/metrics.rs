@@ -0,0 +1,339 @@
+//! How long each phase of a solver run takes, and how much it allocates.
+//!
+//! A run is split into three phases: reading the input file, parsing it into the day's model, and
+//! solving the puzzle from the model. Solvers mark where their parsing ends, so a slow day shows
+//! whether the time goes on building the model, such as the height map on day 12 with an entry
+//! per square, or on searching it. Solvers that don't separate the two, such as registered solvers
+//! or days that solve while reading the input, only report a solve phase.
+//!
+//! Allocations are only counted when the `alloc-metrics` feature is enabled, which installs a
+//! `CountingAllocator` as the global allocator. Without it, each phase only has its duration.
+//!
+//! ```
+//! let (answer, metrics) = aoc::metrics::solve(2022, 12, 1, None).unwrap();
+//! assert_eq!(answer, aoc::solve(12, 1, None).unwrap());
+//! assert!(metrics.parse.is_some());
+//! println!("{}", metrics);
+//! ```
+
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+/// The measurements of a single phase of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Phase {
+    pub duration: Duration,
+    /// What the phase allocated, or `None` without the `alloc-metrics` feature.
+    pub allocations: Option<Allocations>,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.1?}", self.duration)?;
+        if let Some(allocations) = &self.allocations {
+            write!(f, " ({})", allocations)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Phase {
+    fn default() -> Self {
+        Self {
+            duration: Duration::ZERO,
+            allocations: None,
+        }
+    }
+}
+
+/// The heap allocations made during a phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Allocations {
+    /// How many allocations and reallocations were made.
+    pub count: u64,
+    /// The most heap memory in use at once during the phase, beyond what was already in use when
+    /// it started.
+    pub peak_bytes: u64,
+}
+
+impl Display for Allocations {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocations, peak {} bytes",
+            self.count, self.peak_bytes
+        )
+    }
+}
+
+/// The measurements of each phase of a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metrics {
+    pub read: Phase,
+    /// Parsing the input, or `None` if the solver doesn't mark where its parsing ends.
+    pub parse: Option<Phase>,
+    /// Solving the puzzle, including parsing if the solver doesn't separate it.
+    pub solve: Phase,
+}
+
+impl Display for Metrics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "read:  {}", self.read)?;
+        match &self.parse {
+            Some(parse) => writeln!(f, "parse: {}", parse)?,
+            None => writeln!(f, "parse: not separate from solving")?,
+        }
+        write!(f, "solve: {}", self.solve)
+    }
+}
+
+/// Reads the input for a year, day and part, and solves it while measuring each phase.
+///
+/// The input is read the same way as [crate::solve], so the default input path is used if no
+/// file is provided.
+pub fn solve(
+    year: u16,
+    day: u8,
+    part: u8,
+    file: Option<String>,
+) -> Result<(String, Metrics), anyhow::Error> {
+    let (input, read) = read_input(year, day, part, file)?;
+    let (answer, parse, solve) = solve_input(year, day, part, &input)?;
+    Ok((answer, Metrics { read, parse, solve }))
+}
+
+/// Reads the input for a year, day and part while measuring it.
+pub fn read_input(
+    year: u16,
+    day: u8,
+    part: u8,
+    file: Option<String>,
+) -> Result<(String, Phase), anyhow::Error> {
+    let tracker = Tracker::start();
+    let input = crate::read_input_year(year, day, part, file)?;
+    Ok((input, tracker.finish()))
+}
+
+/// Solves an input that has already been read while measuring the parse and solve phases.
+///
+/// The parse phase is `None` if the solver doesn't mark where its parsing ends.
+pub fn solve_input(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+) -> Result<(String, Option<Phase>, Phase), anyhow::Error> {
+    RUN.with(|run| {
+        *run.borrow_mut() = Some(Run {
+            current: Tracker::start(),
+            parse: None,
+        })
+    });
+    let answer = crate::solve_input_year(year, day, part, input);
+    // the run is taken even if the solver failed, so it can't leak into the next one
+    let run = RUN.with(|run| run.borrow_mut().take());
+    let answer = answer?;
+    let (parse, solve) = match run {
+        Some(run) => (run.parse, run.current.finish()),
+        None => (None, Phase::default()),
+    };
+    Ok((answer, parse, solve))
+}
+
+/// Marks the end of the solver's parsing, starting its solve phase.
+///
+/// Only the first mark of a run counts, and marks made outside of a measured run do nothing.
+pub(crate) fn parsed() {
+    RUN.with(|run| {
+        if let Some(run) = run.borrow_mut().as_mut() {
+            if run.parse.is_none() {
+                run.parse = Some(run.current.finish());
+                run.current = Tracker::start();
+            }
+        }
+    });
+}
+
+thread_local! {
+    /// The run being measured on this thread, if there is one.
+    static RUN: RefCell<Option<Run>> = const { RefCell::new(None) };
+}
+
+struct Run {
+    /// Measures the phase in progress.
+    current: Tracker,
+    parse: Option<Phase>,
+}
+
+/// Measures a phase from when it was started.
+struct Tracker {
+    started: Instant,
+    /// The allocation count and bytes in use when the phase started.
+    allocations: Option<(u64, u64)>,
+}
+
+impl Tracker {
+    fn start() -> Self {
+        Self {
+            started: Instant::now(),
+            allocations: counting::start(),
+        }
+    }
+
+    fn finish(&self) -> Phase {
+        let duration = self.started.elapsed();
+        let allocations = self.allocations.and_then(|(count, bytes)| {
+            let (count_now, peak) = counting::finish()?;
+            Some(Allocations {
+                count: count_now - count,
+                peak_bytes: peak.saturating_sub(bytes),
+            })
+        });
+        Phase {
+            duration,
+            allocations,
+        }
+    }
+}
+
+#[cfg(feature = "alloc-metrics")]
+pub use counting::CountingAllocator;
+
+#[cfg(feature = "alloc-metrics")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static COUNT: AtomicU64 = AtomicU64::new(0);
+    static IN_USE: AtomicU64 = AtomicU64::new(0);
+    static PEAK: AtomicU64 = AtomicU64::new(0);
+
+    /// Counts allocations and the bytes in use while passing them on to the system allocator.
+    ///
+    /// The counts are shared by every thread, so runs measured at the same time on different
+    /// threads include each other's allocations.
+    pub struct CountingAllocator;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn allocated(size: usize) {
+        COUNT.fetch_add(1, Ordering::Relaxed);
+        let in_use = IN_USE.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+        PEAK.fetch_max(in_use, Ordering::Relaxed);
+    }
+
+    // SAFETY: every call is passed on unchanged to the system allocator, only counting around it
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            IN_USE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                IN_USE.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+                allocated(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    /// Starts counting a phase, returning the allocation count and bytes in use so far.
+    pub(super) fn start() -> Option<(u64, u64)> {
+        let in_use = IN_USE.load(Ordering::Relaxed);
+        PEAK.store(in_use, Ordering::Relaxed);
+        Some((COUNT.load(Ordering::Relaxed), in_use))
+    }
+
+    /// Returns the allocation count and the peak bytes in use since the phase started.
+    pub(super) fn finish() -> Option<(u64, u64)> {
+        Some((COUNT.load(Ordering::Relaxed), PEAK.load(Ordering::Relaxed)))
+    }
+}
+
+#[cfg(not(feature = "alloc-metrics"))]
+mod counting {
+    pub(super) fn start() -> Option<(u64, u64)> {
+        None
+    }
+
+    pub(super) fn finish() -> Option<(u64, u64)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let (answer, metrics) = solve(2022, 8, 1, None).expect("should return result");
+        assert_eq!(
+            crate::solve_year(2022, 8, 1, None).expect("should return result"),
+            answer
+        );
+        assert!(metrics.parse.is_some());
+        assert_eq!(
+            cfg!(feature = "alloc-metrics"),
+            metrics.solve.allocations.is_some()
+        );
+        // day 6 finds the marker while reading the datastream, so there is nothing to separate
+        let (_, metrics) = solve(2022, 6, 1, None).expect("should return result");
+        assert_eq!(None, metrics.parse);
+        let error = solve(2022, 8, 1, Some("does/not/exist.txt".to_string()))
+            .expect_err("should return error");
+        assert!(error.to_string().contains("could not read input file"));
+    }
+
+    #[test]
+    fn test_solve_error() {
+        let error = solve_input(2022, 8, 1, "3x3").expect_err("should return error");
+        assert_eq!(
+            Some(crate::error::ErrorKind::Parse),
+            crate::error::ErrorKind::of(&error)
+        );
+        // marks outside of a measured run are ignored
+        parsed();
+        RUN.with(|run| assert!(run.borrow().is_none()));
+    }
+
+    #[test]
+    fn test_display() {
+        let phase = Phase {
+            duration: Duration::from_micros(1500),
+            allocations: Some(Allocations {
+                count: 3,
+                peak_bytes: 1024,
+            }),
+        };
+        let metrics = Metrics {
+            read: Phase::default(),
+            parse: None,
+            solve: phase,
+        };
+        assert_eq!(
+            "read:  0.0ns\nparse: not separate from solving\nsolve: 1.5ms (3 allocations, peak 1024 bytes)",
+            metrics.to_string()
+        );
+    }
+}
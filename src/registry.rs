@@ -0,0 +1,169 @@
+//! Solvers registered at runtime by other crates.
+//!
+//! A crate that depends on this one can add solvers for new days or other years, or replace the
+//! solver for one of the built-in days, by registering anything that implements [Solver]. Registered solvers are used
+//! by [crate::solve] and everything built on it, including the CLI in [crate::cli], so a custom
+//! binary only has to register its solvers before handing over to [crate::cli::main]:
+//!
+//! ```no_run
+//! // a variant of day 1 that counts the elves instead of their calories
+//! aoc::registry::register(2022, 15, 1, |input: &str| {
+//!     Ok(input.split("\n\n").count().to_string())
+//! })
+//! .unwrap();
+//! aoc::cli::main();
+//! ```
+//!
+//! Registered solvers are kept for the life of the process.
+
+use crate::error::{Categorize, ErrorKind};
+use anyhow::anyhow;
+use std::collections::BTreeMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Solves one part of a day's puzzle.
+///
+/// Any function or closure that takes the input and returns the answer implements this trait, so
+/// most solvers never need to implement it directly.
+pub trait Solver: Send + Sync {
+    /// Solves the puzzle for the given input.
+    ///
+    /// Errors that aren't categorized are reported as [ErrorKind::Parse], the same as errors from
+    /// the built-in solvers.
+    fn solve(&self, input: &str) -> Result<String, anyhow::Error>;
+}
+
+impl<F> Solver for F
+where
+    F: Fn(&str) -> Result<String, anyhow::Error> + Send + Sync,
+{
+    fn solve(&self, input: &str) -> Result<String, anyhow::Error> {
+        self(input)
+    }
+}
+
+type Registry = RwLock<BTreeMap<(u16, u8, u8), Arc<dyn Solver>>>;
+
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(|| RwLock::new(BTreeMap::new()))
+}
+
+/// Registers the solver for a year, day and part, replacing any solver the day already has.
+///
+/// Any day other than 0 of any year since Advent of Code began can be registered, but each day only
+/// has part 1 and part 2. An error categorized as [ErrorKind::Usage] is returned for any other
+/// year, day or part.
+///
+/// # Examples
+///
+/// ```
+/// aoc::registry::register(2022, 6, 2, |input: &str| Ok(input.len().to_string())).unwrap();
+/// assert_eq!("4", aoc::solve_input(6, 2, "abcd").unwrap());
+/// ```
+pub fn register(
+    year: u16,
+    day: u8,
+    part: u8,
+    solver: impl Solver + 'static,
+) -> Result<(), anyhow::Error> {
+    crate::solution::validate_year(year)?;
+    if day == 0 {
+        return Err(anyhow!("invalid day: {}", day)).categorize(ErrorKind::Usage);
+    }
+    if !(1..=2).contains(&part) {
+        return Err(anyhow!("invalid part: {}", part)).categorize(ErrorKind::Usage);
+    }
+    registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert((year, day, part), Arc::new(solver));
+    Ok(())
+}
+
+/// Removes the registered solver for a year, day and part, returning whether there was one.
+///
+/// A built-in day goes back to its built-in solver.
+pub fn unregister(year: u16, day: u8, part: u8) -> bool {
+    registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(&(year, day, part))
+        .is_some()
+}
+
+/// Returns the year, day and part of every registered solver, in order.
+pub fn registered() -> Vec<(u16, u8, u8)> {
+    registry()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .keys()
+        .copied()
+        .collect()
+}
+
+/// Returns the registered solver for a year, day and part, if there is one.
+pub(crate) fn solver(year: u16, day: u8, part: u8) -> Option<Arc<dyn Solver>> {
+    registry()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&(year, day, part))
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the registry is shared by every test, so these only use days that aren't built in
+
+    #[test]
+    fn test_register() {
+        register(2022, 200, 1, |input: &str| Ok(input.to_uppercase()))
+            .expect("should return result");
+        assert!(registered().contains(&(2022, 200, 1)));
+        assert_eq!(
+            "ABC",
+            crate::solve_input_year(2022, 200, 1, "abc").expect("should return result")
+        );
+        assert!(crate::days_year(2022).contains(&200));
+        assert_eq!(vec![1], crate::parts_year(2022, 200));
+        let error = crate::solve_input_year(2022, 200, 2, "abc").expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+        assert!(unregister(2022, 200, 1));
+        assert!(!unregister(2022, 200, 1));
+        let error = crate::solve_input_year(2022, 200, 1, "abc").expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+    }
+
+    #[test]
+    fn test_register_other_year() {
+        register(2016, 1, 2, |input: &str| Ok(input.len().to_string()))
+            .expect("should return result");
+        assert!(crate::years().contains(&2016));
+        assert_eq!(vec![1], crate::days_year(2016));
+        assert_eq!(
+            "3",
+            crate::solve_input_year(2016, 1, 2, "abc").expect("should return result")
+        );
+        // other years don't have the built-in solvers
+        let error = crate::solve_input_year(2016, 6, 1, "abc").expect_err("should return error");
+        assert_eq!("invalid day: 6", error.to_string());
+        unregister(2016, 1, 2);
+    }
+
+    #[test]
+    fn test_register_errors() {
+        let solver = |_: &str| Ok(String::new());
+        let error = register(2014, 1, 1, solver).expect_err("should return error");
+        assert_eq!("invalid year: 2014", error.to_string());
+        let error = register(2022, 0, 1, solver).expect_err("should return error");
+        assert_eq!("invalid day: 0", error.to_string());
+        let error = register(2022, 201, 3, solver).expect_err("should return error");
+        assert_eq!("invalid part: 3", error.to_string());
+        register(2022, 201, 2, |_: &str| Err(anyhow!("bad input"))).expect("should return result");
+        let error = crate::solve_input_year(2022, 201, 2, "").expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Parse), ErrorKind::of(&error));
+        unregister(2022, 201, 2);
+    }
+}
@@ -0,0 +1,229 @@
+//! Interactive exploration of a day's parsed puzzle input.
+//!
+//! Some puzzles build an interesting model before producing their answer, such as the directory
+//! tree of day 7, the monkeys of day 11 and the height map of day 12. An [Explorer] loads a day's
+//! input once and then answers commands about that model, one command per line.
+//!
+//! Every explorer understands these commands, in addition to its own:
+//!
+//! ```text
+//! help                     list the available commands
+//! part <part>              run a part's solver on the loaded input
+//! quit                     stop exploring
+//! ```
+//!
+//! Commands are read line by line and all output is plain text, so a session can be scripted by
+//! piping commands into it. Blank lines and lines starting with `#` are ignored.
+
+use crate::error::{Categorize, ErrorKind};
+use anyhow::{anyhow, Context};
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+use std::time::Instant;
+
+/// Commands understood by every explorer, shown by `help` before the day's own commands.
+const COMMON_HELP: &str = "help                     list the available commands
+part <part>              run a part's solver on the loaded input
+quit                     stop exploring";
+
+/// The parsed model of a day's input, queried with text commands.
+pub trait Explorer {
+    /// Runs a command with its whitespace-separated arguments and returns the text to print.
+    ///
+    /// An error categorized as [ErrorKind::Usage] is returned for unknown commands or invalid
+    /// arguments.
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String, anyhow::Error>;
+
+    /// Describes the day's own commands, one per line.
+    fn help(&self) -> &'static str;
+}
+
+/// Returns every day that has an explorer, in order.
+pub fn days() -> Vec<u8> {
+    crate::solution::explorer_days()
+}
+
+/// Parses a day's input and returns an explorer for it.
+///
+/// An error is returned if the day has no explorer or if the input cannot be parsed.
+pub fn explorer(day: u8, input: &str) -> Result<Box<dyn Explorer>, anyhow::Error> {
+    crate::solution::explorer(day, input)
+}
+
+/// Reads commands from the reader and writes their output to the writer until `quit` or the end of
+/// the input.
+///
+/// A command that fails does not end the session: its error is written in place of its output. If
+/// `prompt` is true a prompt is written before each command, which is only useful when a person is
+/// typing the commands.
+pub fn run<R: BufRead, W: Write>(
+    day: u8,
+    input: &str,
+    explorer: &mut dyn Explorer,
+    reader: R,
+    mut writer: W,
+    prompt: bool,
+) -> Result<(), anyhow::Error> {
+    if prompt {
+        write_prompt(&mut writer, day)?;
+    }
+    for line in reader.lines() {
+        let line = line
+            .context("could not read command")
+            .categorize(ErrorKind::Io)?;
+        let words = line.split_whitespace().collect::<Vec<&str>>();
+        match words.first() {
+            Some(&"quit") | Some(&"exit") => break,
+            Some(command) if !command.starts_with('#') => {
+                let output = match execute(day, input, explorer, command, &words[1..]) {
+                    Ok(output) => output,
+                    Err(e) => format!("ERROR: {:#}", e),
+                };
+                if !output.is_empty() {
+                    writeln!(writer, "{}", output.trim_end())
+                        .context("could not write output")
+                        .categorize(ErrorKind::Io)?;
+                }
+            }
+            // blank lines and comments
+            _ => {}
+        }
+        if prompt {
+            write_prompt(&mut writer, day)?;
+        }
+    }
+    Ok(())
+}
+
+/// Runs one of the common commands, or passes the command on to the explorer.
+fn execute(
+    day: u8,
+    input: &str,
+    explorer: &mut dyn Explorer,
+    command: &str,
+    args: &[&str],
+) -> Result<String, anyhow::Error> {
+    match command {
+        "help" => Ok(format!("{}\n{}", COMMON_HELP, explorer.help())),
+        "part" => {
+            let part = arg::<u8>(args, 0, "part")?;
+            let start = Instant::now();
+            let answer = crate::solve_input(day, part, input)?;
+            Ok(format!(
+                "Day {} part {} solution: {} ({:.3}ms)",
+                day,
+                part,
+                answer,
+                start.elapsed().as_secs_f64() * 1000.0
+            ))
+        }
+        _ => explorer.execute(command, args),
+    }
+}
+
+/// Writes the prompt and flushes it, since it doesn't end with a newline.
+fn write_prompt<W: Write>(writer: &mut W, day: u8) -> Result<(), anyhow::Error> {
+    write!(writer, "day {}> ", day)
+        .and_then(|_| writer.flush())
+        .context("could not write prompt")
+        .categorize(ErrorKind::Io)
+}
+
+/// Parses a required argument of a command.
+pub(crate) fn arg<T: FromStr>(args: &[&str], index: usize, name: &str) -> Result<T, anyhow::Error> {
+    match args.get(index) {
+        Some(value) => value
+            .parse::<T>()
+            .map_err(|_| anyhow!("invalid {}: {}", name, value))
+            .categorize(ErrorKind::Usage),
+        None => Err(anyhow!("missing argument: <{}>", name)).categorize(ErrorKind::Usage),
+    }
+}
+
+/// Parses an optional argument of a command, falling back to a default when it's missing.
+pub(crate) fn arg_or<T: FromStr>(
+    args: &[&str],
+    index: usize,
+    name: &str,
+    default: T,
+) -> Result<T, anyhow::Error> {
+    match args.get(index) {
+        Some(_) => arg(args, index, name),
+        None => Ok(default),
+    }
+}
+
+/// Returns the error for a command that the explorer doesn't understand.
+pub(crate) fn unknown_command(command: &str) -> anyhow::Error {
+    ErrorKind::Usage.wrap(anyhow!("unknown command: {} (try help)", command))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// An explorer that only counts.
+    struct Counter(u32);
+
+    impl Explorer for Counter {
+        fn execute(&mut self, command: &str, args: &[&str]) -> Result<String, anyhow::Error> {
+            match command {
+                "add" => {
+                    self.0 += arg_or::<u32>(args, 0, "amount", 1)?;
+                    Ok(self.0.to_string())
+                }
+                _ => Err(unknown_command(command)),
+            }
+        }
+
+        fn help(&self) -> &'static str {
+            "add [amount]  add to the count"
+        }
+    }
+
+    fn run_script(script: &str) -> String {
+        let mut output = Vec::new();
+        let mut counter = Counter(0);
+        run(
+            6,
+            "abcd",
+            &mut counter,
+            Cursor::new(script),
+            &mut output,
+            false,
+        )
+        .expect("should return result");
+        String::from_utf8(output).expect("should be UTF-8")
+    }
+
+    #[test]
+    fn test_run() {
+        let output = run_script("add\n\n# a comment\nadd 5\nquit\nadd");
+        assert_eq!("1\n6\n", output);
+    }
+
+    #[test]
+    fn test_run_errors() {
+        let output = run_script("add x\nsubtract\nadd");
+        assert_eq!(
+            "ERROR: invalid amount: x\nERROR: unknown command: subtract (try help)\n1\n",
+            output
+        );
+    }
+
+    #[test]
+    fn test_run_common_commands() {
+        let output = run_script("help\npart 1");
+        assert!(output.contains("add [amount]"));
+        assert!(output.contains("Day 6 part 1 solution: 4 ("));
+    }
+
+    #[test]
+    fn test_arg() {
+        assert_eq!(3, arg::<u32>(&["3"], 0, "count").expect("should parse"));
+        let error = arg::<u32>(&[], 0, "count").unwrap_err();
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+        assert_eq!(7, arg_or::<u32>(&[], 0, "count", 7).expect("should parse"));
+    }
+}
@@ -0,0 +1,292 @@
+//! A Markdown table of which puzzles are solved and how long their solvers take.
+//!
+//! Every part of every day with a solver, including registered ones, is solved from its default
+//! input and timed, and the answer is checked against the stored answer hashes described in
+//! [crate::verify]. Each day's row is titled with the puzzle's name, taken from the first line of
+//! its description in `docs/day_XX.md`.
+//!
+//! The table can be written into a section of a Markdown file, such as the README, between a
+//! [SECTION_START] and a [SECTION_END] marker, replacing whatever was there before.
+
+use crate::error::{Categorize, ErrorKind};
+use crate::verify::{self, Verification};
+use anyhow::{anyhow, Context};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::time::{Duration, Instant};
+
+/// Marks the start of the section replaced by [update_section].
+pub const SECTION_START: &str = "<!-- report start -->";
+
+/// Marks the end of the section replaced by [update_section].
+pub const SECTION_END: &str = "<!-- report end -->";
+
+/// How a part's answer compares to its stored answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The answer matches the stored answer.
+    Solved,
+    /// The answer doesn't match the stored answer.
+    Wrong,
+    /// There is no stored answer to check the answer against.
+    Unverified,
+    /// The input couldn't be read or the solver returned an error.
+    Failed(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::Wrong => write!(f, "wrong"),
+            Status::Unverified => write!(f, "unverified"),
+            Status::Failed(_) => write!(f, "error"),
+        }
+    }
+}
+
+/// The outcome of solving one part of a day.
+#[derive(Debug, Clone)]
+pub struct PartReport {
+    pub part: u8,
+    pub status: Status,
+    /// How long the solver took, not counting reading the input.
+    pub runtime: Duration,
+}
+
+/// The outcome of solving each part of a day.
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    /// The puzzle's name, or `None` if the day has no description.
+    pub title: Option<String>,
+    pub parts: Vec<PartReport>,
+}
+
+/// Solves and times every part of the selected days of a year.
+///
+/// A part that can't be solved is reported as [Status::Failed] rather than stopping the report.
+pub fn report(year: u16, days: &[u8]) -> Vec<DayReport> {
+    days.iter()
+        .map(|&day| DayReport {
+            day,
+            title: title(year, day),
+            parts: crate::parts_year(year, day)
+                .into_iter()
+                .map(|part| report_part(year, day, part))
+                .collect(),
+        })
+        .collect()
+}
+
+fn report_part(year: u16, day: u8, part: u8) -> PartReport {
+    let failed = |e: anyhow::Error| PartReport {
+        part,
+        status: Status::Failed(format!("{:#}", e)),
+        runtime: Duration::ZERO,
+    };
+    let input = match crate::read_input_year(year, day, part, None) {
+        Ok(input) => input,
+        Err(e) => return failed(e),
+    };
+    let started = Instant::now();
+    let answer = match crate::solve_input_year(year, day, part, &input) {
+        Ok(answer) => answer,
+        Err(e) => return failed(e),
+    };
+    let runtime = started.elapsed();
+    let status = match verify::check(year, day, part, None, &answer) {
+        Ok(Verification::Match) => Status::Solved,
+        Ok(Verification::Mismatch) => Status::Wrong,
+        Err(_) => Status::Unverified,
+    };
+    PartReport {
+        part,
+        status,
+        runtime,
+    }
+}
+
+/// Reads the puzzle's name from the first line of its description, such as
+/// `**--- Day 1: Calorie Counting ---**`.
+fn title(year: u16, day: u8) -> Option<String> {
+    let description = fs::read_to_string(crate::solution::get_docs_path(year, day)).ok()?;
+    parse_title(description.lines().next()?)
+}
+
+fn parse_title(line: &str) -> Option<String> {
+    let line = line
+        .trim()
+        .trim_matches('*')
+        .trim()
+        .trim_matches('-')
+        .trim();
+    let (_, title) = line.split_once(": ")?;
+    Some(title.trim().to_string())
+}
+
+/// Formats the reports as a Markdown table with a row per day.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::report::{DayReport, PartReport, Status};
+/// use std::time::Duration;
+///
+/// let report = DayReport {
+///     day: 1,
+///     title: Some("Calorie Counting".to_string()),
+///     parts: vec![PartReport {
+///         part: 1,
+///         status: Status::Solved,
+///         runtime: Duration::from_micros(250),
+///     }],
+/// };
+/// let table = aoc::report::to_markdown(&[report]);
+/// assert!(table.ends_with("| 1 | Calorie Counting | solved | 250.0µs | | |"));
+/// ```
+pub fn to_markdown(reports: &[DayReport]) -> String {
+    let mut lines = vec![
+        "| Day | Puzzle | Part 1 | Time | Part 2 | Time |".to_string(),
+        "| --: | ------ | ------ | ---: | ------ | ---: |".to_string(),
+    ];
+    for report in reports {
+        let mut line = format!(
+            "| {} | {} |",
+            report.day,
+            report.title.as_deref().unwrap_or("")
+        );
+        for part in 1..=2 {
+            match report.parts.iter().find(|p| p.part == part) {
+                Some(PartReport {
+                    status: status @ Status::Failed(_),
+                    ..
+                }) => line.push_str(&format!(" {} | |", status)),
+                Some(p) => line.push_str(&format!(" {} | {:.1?} |", p.status, p.runtime)),
+                None => line.push_str(" | |"),
+            }
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+/// Replaces everything between the [SECTION_START] and [SECTION_END] markers of a Markdown
+/// document with a table, keeping the markers so the section can be replaced again.
+///
+/// An error categorized as [ErrorKind::Usage] is returned if the document doesn't have both
+/// markers in order.
+pub fn update_section(document: &str, table: &str) -> Result<String, anyhow::Error> {
+    let missing = || {
+        anyhow!(
+            "could not find the report section, marked by {} and {}",
+            SECTION_START,
+            SECTION_END
+        )
+    };
+    let start = document
+        .find(SECTION_START)
+        .ok_or_else(missing)
+        .categorize(ErrorKind::Usage)?
+        + SECTION_START.len();
+    let end = document[start..]
+        .find(SECTION_END)
+        .ok_or_else(missing)
+        .categorize(ErrorKind::Usage)?
+        + start;
+    Ok(format!(
+        "{}\n{}\n{}",
+        &document[..start],
+        table,
+        &document[end..]
+    ))
+}
+
+/// Replaces the report section of a Markdown file with a table.
+pub fn update_file(path: &str, table: &str) -> Result<(), anyhow::Error> {
+    let document = fs::read_to_string(path)
+        .with_context(|| format!("could not read file: {}", path))
+        .categorize(ErrorKind::Io)?;
+    let updated = update_section(&document, table).with_context(|| path.to_string())?;
+    fs::write(path, updated)
+        .with_context(|| format!("could not write file: {}", path))
+        .categorize(ErrorKind::Io)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_title() {
+        assert_eq!(
+            Some("Calorie Counting".to_string()),
+            parse_title("**--- Day 1: Calorie Counting ---**")
+        );
+        assert_eq!(
+            Some("Hill Climbing Algorithm".to_string()),
+            parse_title("--- Day 12: Hill Climbing Algorithm ---")
+        );
+        assert_eq!(None, parse_title("Santa's reindeer typically eat"));
+        assert_eq!(
+            Some("Calorie Counting".to_string()),
+            title(crate::DEFAULT_YEAR, 1)
+        );
+    }
+
+    #[test]
+    fn test_report() {
+        let reports = report(crate::DEFAULT_YEAR, &[1, 15]);
+        assert_eq!(2, reports[0].parts.len());
+        assert!(reports[0].parts.iter().all(|p| p.status == Status::Solved));
+        // a day without a solver has no parts to report
+        assert!(reports[1].parts.is_empty());
+        assert_eq!(None, reports[1].title);
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let reports = [DayReport {
+            day: 2,
+            title: None,
+            parts: vec![
+                PartReport {
+                    part: 1,
+                    status: Status::Wrong,
+                    runtime: Duration::from_millis(3),
+                },
+                PartReport {
+                    part: 2,
+                    status: Status::Failed("invalid input".to_string()),
+                    runtime: Duration::ZERO,
+                },
+            ],
+        }];
+        assert_eq!(
+            "| Day | Puzzle | Part 1 | Time | Part 2 | Time |
+| --: | ------ | ------ | ---: | ------ | ---: |
+| 2 |  | wrong | 3.0ms | error | |",
+            to_markdown(&reports)
+        );
+    }
+
+    #[test]
+    fn test_update_section() {
+        let document = "# Title\n<!-- report start -->\nold\n<!-- report end -->\nrest";
+        let updated = update_section(document, "new").expect("should return result");
+        assert_eq!(
+            "# Title\n<!-- report start -->\nnew\n<!-- report end -->\nrest",
+            updated
+        );
+        // replacing it again gives the same document
+        assert_eq!(
+            updated,
+            update_section(&updated, "new").expect("should return result")
+        );
+        let error = update_section("# Title", "new").expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+        let error = update_section("<!-- report end --><!-- report start -->", "new")
+            .expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+    }
+}
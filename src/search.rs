@@ -0,0 +1,361 @@
+//! Graph searches shared by the puzzle days.
+//!
+//! Many puzzles come down to finding the shortest way through a graph, such as the climb up the
+//! height map on day 12. Rather than each day building its graph up front, the searches here are
+//! given the nodes to start from and a function returning the neighbours of a node, so the graph
+//! is only explored as far as it needs to be:
+//!
+//! - [bfs] for graphs where every step costs the same,
+//! - [dijkstra] for graphs where steps have different costs,
+//! - [astar] for graphs where a heuristic can steer the search towards the goal.
+//!
+//! Each of them can start from several nodes at once, and stops as soon as it reaches a node that
+//! `goal` accepts, or explores everything reachable if nothing is accepted. The [Search] they
+//! return has the distance to every node that was reached, and the path that led to it:
+//!
+//! ```
+//! use aoc::search;
+//!
+//! // the number line, where each step adds 1 or doubles the number
+//! let search = search::bfs([1u32], |n| [n + 1, n * 2], |n| *n == 10);
+//! assert_eq!(Some(&10), search.goal());
+//! assert_eq!(Some(4), search.distance(&10));
+//! assert_eq!(Some(vec![1, 2, 4, 5, 10]), search.path_to(&10));
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// The nodes reached by a search, with their distances from the nearest start and how they were
+/// reached.
+///
+/// When a search explores everything reachable, every distance and path is the shortest. When it
+/// stops early at a goal, the goal's are, but nodes the search hadn't finished with by then may
+/// only have been reached by a longer path.
+#[derive(Debug, Clone)]
+pub struct Search<N> {
+    distances: HashMap<N, u64>,
+    previous: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N> Search<N>
+where
+    N: Eq + Hash + Clone,
+{
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            previous: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// Returns the first node accepted by `goal`, or `None` if the search never reached one.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// Returns the cost of the path from the nearest start to a node, or `None` if the node wasn't
+    /// reached.
+    pub fn distance(&self, node: &N) -> Option<u64> {
+        self.distances.get(node).copied()
+    }
+
+    /// Returns the path to a node, from the start it was reached from up to and including the node
+    /// itself, or `None` if the node wasn't reached.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        let mut current = node;
+        while let Some(previous) = self.previous.get(current) {
+            path.push(previous.clone());
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Returns every node that was reached along with its distance, in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (&N, u64)> {
+        self.distances
+            .iter()
+            .map(|(node, distance)| (node, *distance))
+    }
+
+    /// Records a start, returning whether it hadn't been seen before.
+    fn start(&mut self, node: N) -> bool {
+        if self.distances.contains_key(&node) {
+            return false;
+        }
+        self.distances.insert(node, 0);
+        true
+    }
+}
+
+/// Searches a graph where every step costs 1, visiting nodes in order of their distance.
+///
+/// `neighbours` returns the nodes that can be reached in a single step from a node, and the
+/// search stops at the first node accepted by `goal`, which is also the closest.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::search;
+///
+/// // a 3x3 grid with a wall in the middle of the top two rows
+/// let open = |x: i32, y: i32| (0..3).contains(&x) && (0..3).contains(&y) && !(x == 1 && y < 2);
+/// let search = search::bfs(
+///     [(0, 0)],
+///     |&(x, y)| {
+///         [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+///             .into_iter()
+///             .filter(|&(x, y)| open(x, y))
+///     },
+///     |_| false,
+/// );
+/// assert_eq!(Some(6), search.distance(&(2, 0)));
+/// assert_eq!(None, search.distance(&(1, 0)));
+/// ```
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.start(start.clone()) {
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((current, distance)) = queue.pop_front() {
+        if goal(&current) {
+            search.goal = Some(current);
+            break;
+        }
+        for next in neighbours(&current) {
+            // the first time a node is seen is always by a shortest path
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance + 1);
+                search.previous.insert(next.clone(), current.clone());
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    search
+}
+
+/// Searches a graph where steps have different costs, visiting nodes in order of their distance.
+///
+/// `neighbours` returns the nodes that can be reached in a single step from a node along with the
+/// cost of that step, and the search stops at the first node accepted by `goal`, which is also the
+/// closest.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::search;
+///
+/// // the direct road from a to c is longer than going through b
+/// let roads = |town: &char| match town {
+///     'a' => vec![('b', 2), ('c', 5)],
+///     'b' => vec![('c', 1)],
+///     _ => vec![],
+/// };
+/// let search = search::dijkstra(['a'], roads, |town| *town == 'c');
+/// assert_eq!(Some(3), search.distance(&'c'));
+/// assert_eq!(Some(vec!['a', 'b', 'c']), search.path_to(&'c'));
+/// ```
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    astar(starts, neighbours, |_| 0, goal)
+}
+
+/// Searches a graph where steps have different costs, visiting first the nodes that `heuristic`
+/// estimates are on the cheapest path to the goal.
+///
+/// `neighbours` returns the nodes that can be reached in a single step from a node along with the
+/// cost of that step, and `heuristic` estimates the cost of the rest of the path from a node to
+/// the goal. As long as the estimate is never more than the real cost, the node accepted by `goal`
+/// is reached by a shortest path.
+///
+/// # Examples
+///
+/// ```
+/// use aoc::search;
+///
+/// // walking along a row, guided by the distance left to walk
+/// let search = search::astar(
+///     [0i32],
+///     |&x| [(x - 1, 1), (x + 1, 1)],
+///     |&x| (10 - x).unsigned_abs() as u64,
+///     |&x| x == 10,
+/// );
+/// assert_eq!(Some(10), search.distance(&10));
+/// // nodes behind the start were never worth exploring
+/// assert_eq!(None, search.distance(&-2));
+/// ```
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut search = Search::new();
+    // nodes don't have to be ordered, so the heap orders their index in `nodes` instead
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if search.start(start.clone()) {
+            heap.push(Reverse((heuristic(&start), 0, nodes.len())));
+            nodes.push(start);
+        }
+    }
+    while let Some(Reverse((_, distance, index))) = heap.pop() {
+        let current = nodes[index].clone();
+        if search.distances.get(&current) != Some(&distance) {
+            // a shorter path to this node was found after it was queued
+            continue;
+        }
+        if goal(&current) {
+            search.goal = Some(current);
+            break;
+        }
+        for (next, cost) in neighbours(&current) {
+            let next_distance = distance.saturating_add(cost);
+            if search
+                .distances
+                .get(&next)
+                .is_some_and(|d| *d <= next_distance)
+            {
+                continue;
+            }
+            search.distances.insert(next.clone(), next_distance);
+            search.previous.insert(next.clone(), current.clone());
+            let estimate = next_distance.saturating_add(heuristic(&next));
+            heap.push(Reverse((estimate, next_distance, nodes.len())));
+            nodes.push(next);
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small weighted graph, where `a` and `e` are both starts of the paths to `d`.
+    fn roads(town: &char) -> Vec<(char, u64)> {
+        match town {
+            'a' => vec![('b', 7), ('c', 2)],
+            'b' => vec![('d', 1)],
+            'c' => vec![('b', 3), ('d', 8)],
+            'e' => vec![('d', 9)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        // without a goal every reachable node is explored
+        let search = bfs(
+            [0u32],
+            |n| [n + 1].into_iter().filter(|n| *n < 5),
+            |_| false,
+        );
+        assert_eq!(5, search.distances().count());
+        assert_eq!(Some(4), search.distance(&4));
+        assert_eq!(None, search.distance(&5));
+        assert_eq!(None, search.goal());
+    }
+
+    #[test]
+    fn test_bfs_goal() {
+        let search = bfs([1u32], |n| [n + 1, n * 3], |n| *n == 18);
+        assert_eq!(Some(&18), search.goal());
+        assert_eq!(Some(3), search.distance(&18));
+        assert_eq!(Some(vec![1, 2, 6, 18]), search.path_to(&18));
+        let search = bfs(
+            [1u32],
+            |n| [n + 1].into_iter().filter(|n| *n < 4),
+            |n| *n == 9,
+        );
+        assert_eq!(None, search.goal());
+        assert_eq!(None, search.path_to(&9));
+    }
+
+    #[test]
+    fn test_bfs_multiple_starts() {
+        let search = bfs([0i32, 10], |n| [n - 1, n + 1], |n| *n == 7);
+        assert_eq!(Some(3), search.distance(&7));
+        assert_eq!(Some(vec![10, 9, 8, 7]), search.path_to(&7));
+        assert_eq!(Some(0), search.distance(&0));
+        assert_eq!(Some(vec![0]), search.path_to(&0));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra(['a'], roads, |_| false);
+        assert_eq!(Some(0), search.distance(&'a'));
+        assert_eq!(Some(5), search.distance(&'b'));
+        assert_eq!(Some(2), search.distance(&'c'));
+        assert_eq!(Some(6), search.distance(&'d'));
+        assert_eq!(None, search.distance(&'e'));
+        assert_eq!(Some(vec!['a', 'c', 'b', 'd']), search.path_to(&'d'));
+        assert_eq!(None, search.goal());
+    }
+
+    #[test]
+    fn test_dijkstra_multiple_starts() {
+        let search = dijkstra(['e', 'a'], roads, |town| *town == 'd');
+        assert_eq!(Some(&'d'), search.goal());
+        assert_eq!(Some(6), search.distance(&'d'));
+        assert_eq!(Some(vec!['a', 'c', 'b', 'd']), search.path_to(&'d'));
+        let search = dijkstra(['e'], roads, |town| *town == 'd');
+        assert_eq!(Some(vec!['e', 'd']), search.path_to(&'d'));
+    }
+
+    #[test]
+    fn test_astar() {
+        // a grid with a wall from 5,0 to 5,8 between the start and the goal
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| {
+                    (0..10).contains(&x) && (0..10).contains(&y) && !(x == 5 && y < 9)
+                })
+                .map(|node| (node, 1))
+        };
+        let goal = (9, 0);
+        let heuristic = |&(x, y): &(i32, i32)| (x.abs_diff(goal.0) + y.abs_diff(goal.1)) as u64;
+        let search = astar([(0, 0)], neighbours, heuristic, |node| *node == goal);
+        assert_eq!(Some(&goal), search.goal());
+        assert_eq!(Some(27), search.distance(&goal));
+        let path = search.path_to(&goal).expect("should return result");
+        assert_eq!(28, path.len());
+        assert_eq!((0, 0), path[0]);
+        assert!(path.contains(&(5, 9)));
+        // the same as searching without a heuristic
+        let search = dijkstra([(0, 0)], neighbours, |node| *node == goal);
+        assert_eq!(Some(27), search.distance(&goal));
+    }
+}
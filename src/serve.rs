@@ -0,0 +1,382 @@
+//! A small HTTP service that exposes the solvers to tools written in other languages.
+//!
+//! The service listens on localhost only and understands a single route, with an optional year
+//! that defaults to [crate::DEFAULT_YEAR]:
+//!
+//! ```text
+//! POST /solve/{day}/{part}
+//! POST /solve/{year}/{day}/{part}
+//! ```
+//!
+//! The request body is the puzzle input. The response is JSON holding either the answer and how
+//! long the solver took, or a structured error:
+//!
+//! ```text
+//! {"day":1,"part":1,"answer":"24000","time_ms":0.042}
+//! {"day":1,"part":1,"error":{"kind":"parse","message":"could not parse line to u32"}}
+//! ```
+//!
+//! Error kinds are the names of the [ErrorKind] categories, or `other` for uncategorized errors. A
+//! solver that panics is reported as a `simulation` error. Only the standard library is used, so the HTTP support is
+//! deliberately minimal: one request per connection, and the body must be sent with a
+//! `Content-Length` header. A client that sends nothing for [READ_TIMEOUT] is disconnected, and a
+//! request whose line and headers add up to more than [MAX_HEADER_SIZE] bytes is refused.
+
+use crate::error::{Categorize, ErrorKind};
+use anyhow::{anyhow, Context};
+use std::io::{BufRead, BufReader, Read, Take, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Largest request body that will be accepted, which is far larger than any puzzle input.
+const MAX_BODY_SIZE: usize = 64 * 1024 * 1024;
+
+/// Largest request line and headers that will be accepted, which is far more than any client of
+/// the service needs.
+pub const MAX_HEADER_SIZE: usize = 64 * 1024;
+
+/// How long to wait for more of a request before giving up on the client.
+pub const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A solver service bound to a localhost port.
+pub struct Server {
+    listener: TcpListener,
+    timeout: Option<Duration>,
+}
+
+impl Server {
+    /// Binds the service to a port on localhost.
+    ///
+    /// Port `0` lets the operating system pick a free port, which can then be found with
+    /// [Server::local_addr]. If a timeout is given, solvers that run for longer are abandoned and
+    /// reported as an [ErrorKind::Timeout] error.
+    pub fn bind(port: u16, timeout: Option<Duration>) -> Result<Self, anyhow::Error> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+            .with_context(|| format!("could not listen on port {}", port))
+            .categorize(ErrorKind::Io)?;
+        Ok(Self { listener, timeout })
+    }
+
+    /// Returns the address the service is listening on.
+    pub fn local_addr(&self) -> Result<SocketAddr, anyhow::Error> {
+        self.listener
+            .local_addr()
+            .context("could not get listening address")
+    }
+
+    /// Serves requests forever, handling each connection on its own thread.
+    pub fn run(&self) -> Result<(), anyhow::Error> {
+        for stream in self.listener.incoming() {
+            // a failed connection only affects that client
+            let Ok(stream) = stream else { continue };
+            let timeout = self.timeout;
+            thread::spawn(move || handle_connection(stream, timeout));
+        }
+        Ok(())
+    }
+}
+
+/// A response status and JSON body.
+struct Response {
+    status: u16,
+    body: String,
+}
+
+/// Reads a single request from the connection and writes the response.
+fn handle_connection(mut stream: TcpStream, timeout: Option<Duration>) {
+    // without a timeout a client that never finishes its request would hold on to the connection
+    // forever, and if setting it fails the request is still worth serving
+    let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+    let response = match read_request(&stream) {
+        Ok((method, path, body)) => route(&method, &path, body, timeout),
+        Err(e) => error_response(None, &ErrorKind::Usage.wrap(e)),
+    };
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        422 => "Unprocessable Entity",
+        504 => "Gateway Timeout",
+        _ => "Internal Server Error",
+    };
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        reason,
+        response.body.len()
+    );
+    // the client may already have gone away, in which case there's nobody to report to
+    let _ = stream
+        .write_all(head.as_bytes())
+        .and_then(|_| stream.write_all(response.body.as_bytes()));
+}
+
+/// Reads the method, path and body of a request.
+fn read_request(stream: impl Read) -> Result<(String, String, Vec<u8>), anyhow::Error> {
+    let mut reader = BufReader::new(stream);
+    // the request line and headers are read a line at a time, so without a limit a client could
+    // make the server buffer a line that never ends
+    let mut head = (&mut reader).take(MAX_HEADER_SIZE as u64);
+    let request_line = read_head_line(&mut head).context("could not read request")?;
+    let parts = request_line.split_whitespace().collect::<Vec<&str>>();
+    if parts.len() != 3 {
+        return Err(anyhow!("invalid request line: {}", request_line.trim_end()));
+    }
+    let (method, path) = (parts[0].to_string(), parts[1].to_string());
+    // read headers until the blank line, keeping only the body length
+    let mut content_length = 0;
+    loop {
+        let line = read_head_line(&mut head).context("could not read header")?;
+        if line.is_empty() {
+            break;
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value
+                    .trim()
+                    .parse::<usize>()
+                    .with_context(|| format!("invalid content length: {}", value.trim()))?;
+            }
+        }
+    }
+    if content_length > MAX_BODY_SIZE {
+        return Err(anyhow!(
+            "request body is too large: {} bytes",
+            content_length
+        ));
+    }
+    // the body is read as it arrives, so a client can't make the server set aside memory for a
+    // body it never sends
+    let mut body = Vec::new();
+    reader
+        .take(content_length as u64)
+        .read_to_end(&mut body)
+        .context("could not read request body")?;
+    if body.len() < content_length {
+        return Err(anyhow!(
+            "request body ended after {} of {} bytes",
+            body.len(),
+            content_length
+        ));
+    }
+    Ok((method, path, body))
+}
+
+/// Reads a line of the request line and headers, which is empty at the end of the stream.
+///
+/// An error is returned if the line doesn't end before [MAX_HEADER_SIZE] bytes of them.
+fn read_head_line(head: &mut Take<impl BufRead>) -> Result<String, anyhow::Error> {
+    let mut line = String::new();
+    head.read_line(&mut line)?;
+    if !line.ends_with('\n') && head.limit() == 0 {
+        return Err(anyhow!(
+            "request headers are larger than {} bytes",
+            MAX_HEADER_SIZE
+        ));
+    }
+    Ok(line)
+}
+
+/// Dispatches a request to the solver selected by its path.
+fn route(method: &str, path: &str, body: Vec<u8>, timeout: Option<Duration>) -> Response {
+    let segments = path.trim_matches('/').split('/').collect::<Vec<&str>>();
+    if !(3..=4).contains(&segments.len()) || segments[0] != "solve" {
+        return Response {
+            status: 404,
+            body: error_json("usage", &format!("no such route: {}", path)),
+        };
+    }
+    if method != "POST" {
+        return Response {
+            status: 405,
+            body: error_json("usage", &format!("method not allowed: {}", method)),
+        };
+    }
+    let (year, day, part) = match parse_puzzle(&segments[1..]) {
+        Some(puzzle) => puzzle,
+        None => {
+            return Response {
+                status: 400,
+                body: error_json("usage", &format!("invalid year, day or part: {}", path)),
+            }
+        }
+    };
+    let input = match String::from_utf8(body) {
+        Ok(input) => input,
+        Err(_) => return error_response(Some((day, part)), &crate::error::invalid_utf8()),
+    };
+    let start = Instant::now();
+    // a panicking solver still gets a response rather than a dropped connection
+    let result = panic::catch_unwind(AssertUnwindSafe(|| match timeout {
+        Some(timeout) => crate::solve_input_with_timeout_year(year, day, part, &input, timeout),
+        None => crate::solve_input_year(year, day, part, &input),
+    }))
+    .unwrap_or_else(|_| Err(crate::error::solver_panicked()));
+    let elapsed = start.elapsed();
+    match result {
+        Ok(answer) => Response {
+            status: 200,
+            body: format!(
+                "{{\"day\":{},\"part\":{},\"answer\":{},\"time_ms\":{:.3}}}",
+                day,
+                part,
+                json_string(&answer),
+                elapsed.as_secs_f64() * 1000.0
+            ),
+        },
+        Err(e) => error_response(Some((day, part)), &e),
+    }
+}
+
+/// Parses the year, day and part from the segments of a path after `solve`.
+fn parse_puzzle(segments: &[&str]) -> Option<(u16, u8, u8)> {
+    let (year, rest) = match segments.len() {
+        3 => (segments[0].parse::<u16>().ok()?, &segments[1..]),
+        _ => (crate::DEFAULT_YEAR, segments),
+    };
+    Some((
+        year,
+        rest[0].parse::<u8>().ok()?,
+        rest[1].parse::<u8>().ok()?,
+    ))
+}
+
+/// Builds the response for an error, choosing the status from its category.
+fn error_response(day_part: Option<(u8, u8)>, error: &anyhow::Error) -> Response {
+    let kind = ErrorKind::of(error);
+    let status = match kind {
+        Some(ErrorKind::Usage) => 400,
+        Some(ErrorKind::Parse) | Some(ErrorKind::Simulation) => 422,
+        Some(ErrorKind::Timeout) => 504,
+        _ => 500,
+    };
+    let kind = kind.map_or("other".to_string(), |k| k.to_string());
+    let error = error_json(&kind, &format!("{:#}", error));
+    let body = match day_part {
+        Some((day, part)) => format!("{{\"day\":{},\"part\":{},{}}}", day, part, &error[1..]),
+        None => error,
+    };
+    Response { status, body }
+}
+
+/// Builds a JSON object holding a structured error.
+fn error_json(kind: &str, message: &str) -> String {
+    format!(
+        "{{\"error\":{{\"kind\":{},\"message\":{}}}}}",
+        json_string(kind),
+        json_string(message)
+    )
+}
+
+/// Quotes and escapes a string for use in JSON.
+fn json_string(s: &str) -> String {
+    let mut output = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!("\"plain\"", json_string("plain"));
+        assert_eq!("\"a\\\"b\\\\c\\nd\"", json_string("a\"b\\c\nd"));
+        assert_eq!("\"\\u0001\"", json_string("\u{1}"));
+    }
+
+    #[test]
+    fn test_route_solve() {
+        let response = route(
+            "POST",
+            "/solve/6/1",
+            b"mjqjpqmgbljsphdztnvjfqwrcgsmlb".to_vec(),
+            None,
+        );
+        assert_eq!(200, response.status);
+        assert!(response
+            .body
+            .starts_with("{\"day\":6,\"part\":1,\"answer\":\"7\",\"time_ms\":"));
+        let response = route("POST", "/solve/2022/6/1", b"abcd".to_vec(), None);
+        assert_eq!(200, response.status);
+        assert!(response.body.contains("\"answer\":\"4\""));
+    }
+
+    #[test]
+    fn test_route_errors() {
+        assert_eq!(404, route("POST", "/answer/1/1", Vec::new(), None).status);
+        assert_eq!(405, route("GET", "/solve/1/1", Vec::new(), None).status);
+        assert_eq!(400, route("POST", "/solve/one/1", Vec::new(), None).status);
+        assert_eq!(400, route("POST", "/solve/y/1/1", Vec::new(), None).status);
+        assert_eq!(
+            404,
+            route("POST", "/solve/2022/1/1/1", Vec::new(), None).status
+        );
+        // no solvers are built in for other years
+        assert_eq!(
+            400,
+            route("POST", "/solve/2021/1/1", Vec::new(), None).status
+        );
+        let response = route("POST", "/solve/1/1", b"abc".to_vec(), None);
+        assert_eq!(422, response.status);
+        assert!(response.body.contains("\"kind\":\"parse\""));
+        let response = route("POST", "/solve/1/1", b"\xff".to_vec(), None);
+        assert_eq!(422, response.status);
+        assert!(response.body.contains("\"kind\":\"parse\""));
+    }
+
+    #[test]
+    fn test_route_panic() {
+        crate::registry::register(2022, 202, 1, |_: &str| -> Result<String, anyhow::Error> {
+            panic!("solver bug")
+        })
+        .expect("should return result");
+        let response = route("POST", "/solve/202/1", Vec::new(), None);
+        crate::registry::unregister(2022, 202, 1);
+        assert_eq!(422, response.status);
+        assert!(response.body.contains("\"kind\":\"simulation\""));
+        assert!(response.body.contains("solver panicked"));
+    }
+
+    #[test]
+    fn test_read_request() {
+        let request = b"POST /solve/1/1 HTTP/1.1\r\nContent-Length: 4\r\n\r\nabcdef";
+        let (method, path, body) = read_request(&request[..]).expect("should return result");
+        assert_eq!(("POST", "/solve/1/1"), (method.as_str(), path.as_str()));
+        assert_eq!(b"abcd".to_vec(), body);
+        // a client that claims a large body but sends little of it is turned away
+        let request = b"POST /solve/1/1 HTTP/1.1\r\nContent-Length: 1000000\r\n\r\nab";
+        read_request(&request[..]).expect_err("should return error");
+        let request = b"POST /solve/1/1 HTTP/1.1\r\nContent-Length: 99999999999\r\n\r\n";
+        read_request(&request[..]).expect_err("should return error");
+        // as is one whose request line or headers never end
+        let request = io::repeat(b'a');
+        read_request(request).expect_err("should return error");
+        let request = b"POST /solve/1/1 HTTP/1.1\r\nX: ".chain(io::repeat(b'a'));
+        let error = read_request(request).expect_err("should return error");
+        assert!(format!("{:#}", error).contains("larger than"));
+        let mut request = b"POST /solve/1/1 HTTP/1.1\r\n".to_vec();
+        request.extend(b"X: a\r\n".repeat(MAX_HEADER_SIZE / 6));
+        read_request(&request[..]).expect_err("should return error");
+    }
+}
@@ -0,0 +1,137 @@
+//! Step-based simulations shared by the simulation days.
+//!
+//! The rope on day 9, the device on day 10, the monkeys on day 11 and the sand on day 14 are all
+//! simulations that are advanced until they finish. Each of them implements [Simulation], so they
+//! can be paused, stepped, inspected and run until some condition holds in the same way, whether
+//! that's by a solver, an animation, or a caller with a time budget:
+//!
+//! ```
+//! use aoc::day_14::{Cave, SandSimulation};
+//! use aoc::simulation::Simulation;
+//! use std::time::{Duration, Instant};
+//!
+//! let cave = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9"
+//!     .parse::<Cave>()
+//!     .unwrap();
+//! let mut simulation = SandSimulation::new(cave, false);
+//! let started = Instant::now();
+//! simulation
+//!     .run_until(|_| started.elapsed() > Duration::from_secs(1))
+//!     .unwrap();
+//! assert!(simulation.is_done());
+//! assert_eq!(24, simulation.state());
+//! ```
+
+/// A simulation that is advanced one step at a time until it finishes.
+pub trait Simulation {
+    /// A snapshot of the simulation, such as the positions of the knots of a rope.
+    type State;
+
+    /// Advances the simulation by a single step.
+    ///
+    /// Stepping a simulation that has already finished does nothing. An error is returned if the
+    /// step cannot be taken, such as when a monkey throws to a monkey that doesn't exist.
+    fn step(&mut self) -> Result<(), anyhow::Error>;
+
+    /// Checks whether the simulation has finished.
+    fn is_done(&self) -> bool;
+
+    /// Returns a snapshot of the current state.
+    fn state(&self) -> Self::State;
+
+    /// Steps the simulation until `stop` returns true or it finishes, and returns how many steps
+    /// were taken.
+    ///
+    /// `stop` is checked before each step, so no steps are taken if it's already true.
+    fn run_until(&mut self, mut stop: impl FnMut(&Self) -> bool) -> Result<u64, anyhow::Error>
+    where
+        Self: Sized,
+    {
+        let mut steps = 0;
+        while !self.is_done() && !stop(self) {
+            self.step()?;
+            steps += 1;
+        }
+        Ok(steps)
+    }
+
+    /// Steps the simulation until it finishes, and returns how many steps were taken.
+    fn run(&mut self) -> Result<u64, anyhow::Error>
+    where
+        Self: Sized,
+    {
+        self.run_until(|_| false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    /// Counts up to a limit, failing on a chosen value.
+    struct Counter {
+        value: u32,
+        limit: u32,
+        fail_at: Option<u32>,
+    }
+
+    impl Simulation for Counter {
+        type State = u32;
+
+        fn step(&mut self) -> Result<(), anyhow::Error> {
+            if self.fail_at == Some(self.value) {
+                return Err(anyhow!("failed at {}", self.value));
+            }
+            if !self.is_done() {
+                self.value += 1;
+            }
+            Ok(())
+        }
+
+        fn is_done(&self) -> bool {
+            self.value == self.limit
+        }
+
+        fn state(&self) -> u32 {
+            self.value
+        }
+    }
+
+    #[test]
+    fn test_run_until() {
+        let mut counter = Counter {
+            value: 0,
+            limit: 10,
+            fail_at: None,
+        };
+        assert_eq!(
+            4,
+            counter
+                .run_until(|c| c.value == 4)
+                .expect("should return result")
+        );
+        assert_eq!(
+            0,
+            counter
+                .run_until(|c| c.value == 4)
+                .expect("should return result")
+        );
+        assert_eq!(6, counter.run().expect("should return result"));
+        assert!(counter.is_done());
+        counter.step().expect("should return result");
+        assert_eq!(10, counter.state());
+    }
+
+    #[test]
+    fn test_run_error() {
+        let mut counter = Counter {
+            value: 0,
+            limit: 10,
+            fail_at: Some(3),
+        };
+        let error = counter.run().expect_err("should return error");
+        assert_eq!("failed at 3", error.to_string());
+        assert_eq!(3, counter.state());
+    }
+}
@@ -0,0 +1,53 @@
+//! Structural checks of calorie lists.
+
+use crate::check::Problem;
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut total: Option<u32> = Some(0);
+    for (i, line) in input.split('\n').enumerate() {
+        if line.is_empty() {
+            total = Some(0);
+            continue;
+        }
+        match line.parse::<u32>() {
+            // only report the first line that overflows a group
+            Ok(calories) => match total.map(|t| t.checked_add(calories)) {
+                Some(Some(sum)) => total = Some(sum),
+                Some(None) => {
+                    problems.push(Problem::at(
+                        i + 1,
+                        "group total is too large to fit in a u32",
+                    ));
+                    total = None;
+                }
+                None => (),
+            },
+            Err(e) => problems.push(Problem::at(
+                i + 1,
+                format!("could not parse line to u32: {}", e),
+            )),
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("1000\n2000\n\n3000").is_empty());
+        assert_eq!(
+            vec![
+                Problem::at(
+                    2,
+                    "could not parse line to u32: invalid digit found in string"
+                ),
+                Problem::at(5, "group total is too large to fit in a u32"),
+            ],
+            check("1000\n2000x\n\n4294967295\n1\n1")
+        );
+    }
+}
@@ -0,0 +1,16 @@
+//! Random calorie lists, one group of items per elf.
+
+use crate::generate::Rng;
+
+/// Generates the items carried by `elves` elves.
+pub(crate) fn generate(rng: &mut Rng, elves: usize) -> String {
+    (0..elves)
+        .map(|_| {
+            (0..rng.range(1..=15))
+                .map(|_| rng.range(1000..=60000).to_string())
+                .collect::<Vec<String>>()
+                .join("\n")
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
@@ -2,19 +2,30 @@
 //!
 //! The parts for day 01 focus on groups of numbers that represent items belonging to individuals.
 //! Each part requires summing each individual group and then doing something with the sums. The
-//! [parse_input] function is used by both parts to turn the input into the required list of sums.
+//! [calorie_totals] function is used by both parts to turn the input into the required list of sums.
+//!
+//! # Examples
+//!
+//! ```
+//! let totals = aoc::day_01::calorie_totals("1000\n2000\n\n3000").unwrap();
+//! assert_eq!(vec![3000, 3000], totals);
+//! ```
 
-use anyhow::Context;
+use anyhow::{anyhow, Context};
+use std::io::BufRead;
 
+pub(crate) mod check;
+pub(crate) mod generate;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod stats;
 
 /// Sum number groups separated by newlines.
 ///
 /// The input should be a string containing groups of whole numbers separated by newlines.
 ///
 /// Returns an error if any tokens from the input cannot be parsed into a u32.
-fn parse_input(input: &str) -> Result<Vec<u32>, anyhow::Error> {
+pub fn calorie_totals(input: &str) -> Result<Vec<u32>, anyhow::Error> {
     let mut output = Vec::<u32>::new();
     output.push(0);
     let mut idx = 0;
@@ -25,18 +36,54 @@ fn parse_input(input: &str) -> Result<Vec<u32>, anyhow::Error> {
             idx += 1;
         } else {
             // parse line to u32 and add to group total
-            output[idx] += line.parse::<u32>().context("could not parse line to u32")?;
+            output[idx] = add_calories(output[idx], line)?;
         }
     }
     Ok(output)
 }
 
+/// Adds the number on a line to a group's total.
+fn add_calories(total: u32, line: &str) -> Result<u32, anyhow::Error> {
+    let value = line.parse::<u32>().context("could not parse line to u32")?;
+    total
+        .checked_add(value)
+        .ok_or_else(|| anyhow!("group total is too large to fit in a u32"))
+}
+
+/// Sums number groups while reading the input a line at a time, calling `f` with each group's
+/// total in turn.
+///
+/// The totals are the same as those from [calorie_totals], without keeping them all.
+fn stream_calorie_totals(
+    reader: &mut dyn BufRead,
+    mut f: impl FnMut(u32),
+) -> Result<(), anyhow::Error> {
+    let mut total = 0;
+    crate::stream::for_each_line(reader, |line| {
+        if line.is_empty() {
+            f(total);
+            total = 0;
+        } else {
+            total = add_calories(total, line)?;
+        }
+        Ok(())
+    })?;
+    f(total);
+    Ok(())
+}
+
+/// Parses the input for both parts without solving them.
+pub(crate) fn parse(input: &str) -> Result<(), anyhow::Error> {
+    calorie_totals(input)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_input() {
+    fn test_calorie_totals() {
         let input = "1000
 2000
 3000
@@ -53,7 +100,16 @@ mod tests {
 10000";
         assert_eq!(
             vec!(6000, 4000, 11000, 24000, 10000),
-            parse_input(input).expect("should return vec")
+            calorie_totals(input).expect("should return vec")
+        );
+    }
+
+    #[test]
+    fn test_calorie_totals_overflow() {
+        let error = calorie_totals("4294967295\n1").expect_err("should return error");
+        assert_eq!(
+            "group total is too large to fit in a u32",
+            error.to_string()
         );
     }
 }
@@ -1,18 +1,27 @@
 //! Solver for part 1.
 
-use crate::solution::day_01::parse_input;
+use crate::solution::day_01::{calorie_totals, stream_calorie_totals};
+use std::io::BufRead;
 
 /// Sum all individual groups separated by newlines and return the max of the groups.
 ///
-/// Uses [parse_input] for day 01 to turn the input into a list of sums, then finds the maximum
+/// Uses [calorie_totals] for day 01 to turn the input into a list of sums, then finds the maximum
 /// sum in the group. If no maximum is found `0` is returned.
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     // get list of sums
-    let cal_counts = parse_input(input)?;
+    let cal_counts = calorie_totals(input)?;
+    crate::metrics::parsed();
     // find max or return 0
     Ok(cal_counts.iter().max().unwrap_or(&0).to_string())
 }
 
+/// Finds the largest group total the same way as [solve], reading the input a line at a time.
+pub(crate) fn solve_reader(reader: &mut dyn BufRead) -> Result<String, anyhow::Error> {
+    let mut max = 0;
+    stream_calorie_totals(reader, |total| max = max.max(total))?;
+    Ok(max.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -34,5 +43,9 @@ mod tests {
 
 10000";
         assert_eq!("24000", solve(input).expect("should return string result"));
+        assert_eq!(
+            "24000",
+            solve_reader(&mut input.as_bytes()).expect("should return string result")
+        );
     }
 }
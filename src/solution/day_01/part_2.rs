@@ -1,27 +1,48 @@
 //! Solver for part 2.
 
-use crate::solution::day_01::parse_input;
+use crate::solution::day_01::{calorie_totals, stream_calorie_totals};
+use std::io::BufRead;
 
 /// Sum all the individual groups separated by newlines and return the sum of the top three groups.
 ///
-/// Uses [parse_input] for day 01 to turn the input into a list of sums, then finds the three
+/// Uses [calorie_totals] for day 01 to turn the input into a list of sums, then finds the three
 /// highest sums in the group. If there are less than three elements in the list the sum of all
 /// list elements is returned. If the list is empty `0` is returned.
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     // get list of sums
-    let mut cal_counts = parse_input(input)?;
+    let mut cal_counts = calorie_totals(input)?;
+    crate::metrics::parsed();
     // sort the list descending
     cal_counts.sort();
     cal_counts.reverse();
-    // take at most the three largest numbers or return 0 if there are none
-    let res = match cal_counts.len() {
-        0 => 0,
-        1 | 2 | 3 => cal_counts.iter().sum(),
-        _ => cal_counts[0] + cal_counts[1] + cal_counts[2],
-    };
+    // take at most the three largest numbers or return 0 if there are none, summing them as a u64
+    // since three totals that each fit in a u32 may not fit together
+    let res = cal_counts
+        .iter()
+        .take(3)
+        .map(|&count| u64::from(count))
+        .sum::<u64>();
     Ok(res.to_string())
 }
 
+/// Sums the three largest group totals the same way as [solve], reading the input a line at a
+/// time and only keeping the largest totals so far.
+pub(crate) fn solve_reader(reader: &mut dyn BufRead) -> Result<String, anyhow::Error> {
+    // the largest totals so far, from largest to smallest
+    let mut largest = [0u32; 3];
+    stream_calorie_totals(reader, |total| {
+        if let Some(i) = largest.iter().position(|&l| total > l) {
+            largest[i..].rotate_right(1);
+            largest[i] = total;
+        }
+    })?;
+    Ok(largest
+        .iter()
+        .map(|&total| u64::from(total))
+        .sum::<u64>()
+        .to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,5 +64,23 @@ mod tests {
 
 10000";
         assert_eq!("45000", solve(input).expect("should return string result"));
+        assert_eq!(
+            "45000",
+            solve_reader(&mut input.as_bytes()).expect("should return string result")
+        );
+    }
+
+    #[test]
+    fn test_solve_large_totals() {
+        // each total fits in a u32 but their sum doesn't, which used to overflow
+        let input = ["4294967295"; 3].join("\n\n");
+        assert_eq!(
+            "12884901885",
+            solve(&input).expect("should return string result")
+        );
+        assert_eq!(
+            "12884901885",
+            solve_reader(&mut input.as_bytes()).expect("should return string result")
+        );
     }
 }
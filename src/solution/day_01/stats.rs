@@ -0,0 +1,37 @@
+//! Statistics of calorie lists.
+
+use crate::solution::day_01::calorie_totals;
+use crate::stats::{range, Stat};
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    let totals = calorie_totals(input)?;
+    let groups = input.split("\n\n").map(|group| group.split('\n').count());
+    Ok(vec![
+        Stat::new("elves", totals.len()),
+        Stat::new(
+            "food items",
+            input.split('\n').filter(|l| !l.is_empty()).count(),
+        ),
+        Stat::new("items per elf", range(groups)),
+        Stat::new("calories per elf", range(totals)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = stats("1000\n2000\n\n3000").expect("should return result");
+        assert_eq!(
+            vec![
+                Stat::new("elves", 2),
+                Stat::new("food items", 3),
+                Stat::new("items per elf", "1 to 2"),
+                Stat::new("calories per elf", 3000),
+            ],
+            stats
+        );
+    }
+}
@@ -0,0 +1,12 @@
+//! Structural checks of strategy guides.
+
+use crate::check::{check_lines, Problem};
+use crate::solution::day_02::{part_1, part_2};
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    // the second column means a different thing in each part, but the same letters are valid
+    check_lines(input, |line| {
+        part_1::parse_input(line)?;
+        part_2::parse_input(line)
+    })
+}
@@ -0,0 +1,17 @@
+//! Random rock paper scissors strategy guides.
+
+use crate::generate::Rng;
+
+/// Generates a strategy guide with `rounds` rounds.
+pub(crate) fn generate(rng: &mut Rng, rounds: usize) -> String {
+    (0..rounds)
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.choose(&['A', 'B', 'C']),
+                rng.choose(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
@@ -1,16 +1,79 @@
-// TODO: docs
+//! Solvers for day 02.
+//!
+//! The parts of day 02 score a strategy guide for rounds of rock, paper, scissors. Each line names
+//! the opponent's [Choice] and a second column, which part 1 reads as the player's [Choice] and
+//! part 2 reads as the [Outcome] the player should aim for.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc::day_02::{score_round, Choice};
+//!
+//! let opponent = "A".parse::<Choice>().unwrap();
+//! let player = "Y".parse::<Choice>().unwrap();
+//! assert_eq!(8, score_round(&opponent, &player));
+//! ```
 
+use anyhow::anyhow;
+use std::str::FromStr;
+
+pub(crate) mod check;
+pub(crate) mod generate;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod stats;
 
+/// A shape played in a round.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
-enum Choice {
+#[non_exhaustive]
+pub enum Choice {
     Rock,
     Paper,
     Scissors,
 }
 
-fn score_round(opponent: &Choice, player: &Choice) -> u32 {
+impl FromStr for Choice {
+    type Err = anyhow::Error;
+
+    /// Parses a column of the strategy guide, where `A` and `X` are rock, `B` and `Y` are paper,
+    /// and `C` and `Z` are scissors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" | "X" => Ok(Choice::Rock),
+            "B" | "Y" => Ok(Choice::Paper),
+            "C" | "Z" => Ok(Choice::Scissors),
+            _ => Err(anyhow!("couldn't convert input to Choice: {}", s)),
+        }
+    }
+}
+
+/// How a round ends for the player.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Outcome {
+    Win,
+    Draw,
+    Loss,
+}
+
+impl FromStr for Outcome {
+    type Err = anyhow::Error;
+
+    /// Parses the second column of the strategy guide as part 2 reads it, where `X` is a loss, `Y`
+    /// is a draw and `Z` is a win.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Outcome::Loss),
+            "Y" => Ok(Outcome::Draw),
+            "Z" => Ok(Outcome::Win),
+            _ => Err(anyhow!("couldn't convert input to Outcome: {}", s)),
+        }
+    }
+}
+
+/// Scores a round for the player: the shape's score (1 for rock, 2 for paper, 3 for scissors) plus
+/// the outcome's score (0 for a loss, 3 for a draw, 6 for a win).
+pub fn score_round(opponent: &Choice, player: &Choice) -> u32 {
     // base score: 1 for rock, 2 for paper, 3 for scissors
     let base_score = match player {
         Choice::Rock => 1,
@@ -37,6 +100,13 @@ fn score_round(opponent: &Choice, player: &Choice) -> u32 {
     }
 }
 
+/// Parses the input for both parts without solving them.
+pub(crate) fn parse(input: &str) -> Result<(), anyhow::Error> {
+    part_1::parse_input(input)?;
+    part_2::parse_input(input)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
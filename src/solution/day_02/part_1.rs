@@ -2,30 +2,27 @@
 
 use crate::solution::day_02::{score_round, Choice};
 use anyhow::anyhow;
+use std::io::BufRead;
 
-fn parse_choice(s: &str) -> Result<Choice, anyhow::Error> {
-    match s {
-        "A" | "X" => Ok(Choice::Rock),
-        "B" | "Y" => Ok(Choice::Paper),
-        "C" | "Z" => Ok(Choice::Scissors),
-        _ => Err(anyhow!("couldn't convert input to Choice: {}", s)),
+fn parse_line(line: &str) -> Result<(Choice, Choice), anyhow::Error> {
+    let components: Vec<&str> = line.split(' ').collect();
+    if components.len() < 2 {
+        return Err(anyhow!("invalid number of components on line: {}", &line));
     }
+    Ok((components[0].parse()?, components[1].parse()?))
 }
 
-fn parse_input(input: &str) -> Result<Vec<(Choice, Choice)>, anyhow::Error> {
+pub(super) fn parse_input(input: &str) -> Result<Vec<(Choice, Choice)>, anyhow::Error> {
     let mut output = Vec::<(Choice, Choice)>::new();
     for line in input.split('\n') {
-        let components: Vec<&str> = line.split(' ').collect();
-        if components.len() < 2 {
-            return Err(anyhow!("invalid number of components on line: {}", &line));
-        }
-        output.push((parse_choice(components[0])?, parse_choice(components[1])?));
+        output.push(parse_line(line)?);
     }
     Ok(output)
 }
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     let games = parse_input(input)?;
+    crate::metrics::parsed();
     let mut total = 0;
     for game in games {
         total += score_round(&game.0, &game.1);
@@ -33,6 +30,17 @@ pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     Ok(total.to_string())
 }
 
+/// Scores the rounds the same way as [solve], reading the input a line at a time.
+pub(crate) fn solve_reader(reader: &mut dyn BufRead) -> Result<String, anyhow::Error> {
+    let mut total = 0;
+    crate::stream::for_each_line(reader, |line| {
+        let game = parse_line(line)?;
+        total += score_round(&game.0, &game.1);
+        Ok(())
+    })?;
+    Ok(total.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,5 +62,9 @@ C Z";
 B X
 C Z";
         assert_eq!("15", solve(input).expect("should return string result"));
+        assert_eq!(
+            "15",
+            solve_reader(&mut input.as_bytes()).expect("should return string result")
+        );
     }
 }
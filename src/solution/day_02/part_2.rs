@@ -1,7 +1,8 @@
 // TODO: docs
 
-use crate::solution::day_02::{score_round, Choice};
+use crate::solution::day_02::{score_round, Choice, Outcome};
 use anyhow::anyhow;
+use std::io::BufRead;
 
 fn parse_choice(s: &str) -> Result<Choice, anyhow::Error> {
     match s {
@@ -12,30 +13,18 @@ fn parse_choice(s: &str) -> Result<Choice, anyhow::Error> {
     }
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-enum Outcome {
-    Win,
-    Draw,
-    Loss,
-}
-
-fn parse_outcome(s: &str) -> Result<Outcome, anyhow::Error> {
-    match s {
-        "X" => Ok(Outcome::Loss),
-        "Y" => Ok(Outcome::Draw),
-        "Z" => Ok(Outcome::Win),
-        _ => Err(anyhow!("couldn't convert input to Outcome: {}", s)),
+fn parse_line(line: &str) -> Result<(Choice, Outcome), anyhow::Error> {
+    let components: Vec<&str> = line.split(' ').collect();
+    if components.len() < 2 {
+        return Err(anyhow!("invalid number of components on line: {}", &line));
     }
+    Ok((parse_choice(components[0])?, components[1].parse()?))
 }
 
-fn parse_input(input: &str) -> Result<Vec<(Choice, Outcome)>, anyhow::Error> {
+pub(super) fn parse_input(input: &str) -> Result<Vec<(Choice, Outcome)>, anyhow::Error> {
     let mut output = Vec::<(Choice, Outcome)>::new();
     for line in input.split('\n') {
-        let components: Vec<&str> = line.split(' ').collect();
-        if components.len() < 2 {
-            return Err(anyhow!("invalid number of components on line: {}", &line));
-        }
-        output.push((parse_choice(components[0])?, parse_outcome(components[1])?));
+        output.push(parse_line(line)?);
     }
     Ok(output)
 }
@@ -63,6 +52,7 @@ fn build_game(opponent: &Choice, outcome: &Outcome) -> (Choice, Choice) {
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     let codes = parse_input(input)?;
+    crate::metrics::parsed();
     let mut games = Vec::<(Choice, Choice)>::new();
     for code in codes {
         games.push(build_game(&code.0, &code.1))
@@ -74,6 +64,18 @@ pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     Ok(total.to_string())
 }
 
+/// Scores the rounds the same way as [solve], reading the input a line at a time.
+pub(crate) fn solve_reader(reader: &mut dyn BufRead) -> Result<String, anyhow::Error> {
+    let mut total = 0;
+    crate::stream::for_each_line(reader, |line| {
+        let code = parse_line(line)?;
+        let game = build_game(&code.0, &code.1);
+        total += score_round(&game.0, &game.1);
+        Ok(())
+    })?;
+    Ok(total.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,5 +97,9 @@ C Z";
 B X
 C Z";
         assert_eq!("12", solve(input).expect("should return string result"));
+        assert_eq!(
+            "12",
+            solve_reader(&mut input.as_bytes()).expect("should return string result")
+        );
     }
 }
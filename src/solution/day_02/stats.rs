@@ -0,0 +1,9 @@
+//! Statistics of strategy guides.
+
+use crate::solution::day_02::part_1;
+use crate::stats::Stat;
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    let rounds = part_1::parse_input(input)?;
+    Ok(vec![Stat::new("rounds", rounds.len())])
+}
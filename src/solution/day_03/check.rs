@@ -0,0 +1,59 @@
+//! Structural checks of rucksack lists.
+
+use crate::check::Problem;
+use crate::solution::day_03::Rucksack;
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let lines = input.split('\n').collect::<Vec<&str>>();
+    for (i, line) in lines.iter().enumerate() {
+        match Rucksack::new(line) {
+            // the solver skips rucksacks like this, giving a total that's too small
+            Ok(rucksack) if rucksack.find_common().is_none() => problems.push(Problem::at(
+                i + 1,
+                "no item is in both compartments of the rucksack",
+            )),
+            Ok(_) => (),
+            Err(e) => problems.push(Problem::at(i + 1, format!("{:#}", e))),
+        }
+    }
+    if !lines.len().is_multiple_of(3) {
+        problems.push(Problem::general(format!(
+            "{} rucksacks can't be split into groups of three",
+            lines.len()
+        )));
+    }
+    for (i, group) in lines.chunks_exact(3).enumerate() {
+        let badge = group[0]
+            .chars()
+            .find(|&item| group[1].contains(item) && group[2].contains(item));
+        if badge.is_none() {
+            problems.push(Problem::at(
+                i * 3 + 1,
+                "no item is in all three rucksacks of the group starting here",
+            ));
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check(
+            "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg"
+        )
+        .is_empty());
+        assert_eq!(
+            vec![
+                Problem::at(1, "input does not have even number of characters"),
+                Problem::at(2, "no item is in both compartments of the rucksack"),
+                Problem::general("2 rucksacks can't be split into groups of three"),
+            ],
+            check("abc\nabcd")
+        );
+    }
+}
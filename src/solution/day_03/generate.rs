@@ -0,0 +1,35 @@
+//! Random rucksacks, in groups of three that share a badge.
+
+use crate::generate::Rng;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Generates the rucksacks for `groups` groups of three elves.
+///
+/// The puzzle relies on each rucksack having exactly one item in both compartments and each group
+/// having exactly one item in all three rucksacks. To guarantee that, every compartment draws its
+/// other items from its own share of the item types.
+pub(crate) fn generate(rng: &mut Rng, groups: usize) -> String {
+    let mut rucksacks = Vec::new();
+    for _ in 0..groups {
+        let mut items = ITEMS.to_vec();
+        rng.shuffle(&mut items);
+        let badge = items[0];
+        let shared = &items[1..4];
+        // split what's left between the six compartments
+        let pools = items[4..].chunks(8).collect::<Vec<&[u8]>>();
+        for (elf, &shared) in shared.iter().enumerate() {
+            let length = rng.range(4..=24) as usize;
+            let mut compartments = [vec![shared], vec![shared]];
+            compartments[rng.index(2)].push(badge);
+            for (compartment, pool) in compartments.iter_mut().zip(&pools[elf * 2..]) {
+                while compartment.len() < length {
+                    compartment.push(*rng.choose(pool));
+                }
+                rng.shuffle(compartment);
+            }
+            rucksacks.push(String::from_utf8(compartments.concat()).expect("items are ASCII"));
+        }
+    }
+    rucksacks.join("\n")
+}
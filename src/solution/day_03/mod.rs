@@ -1,19 +1,41 @@
-// TODO: docs
+//! Solvers for day 03.
+//!
+//! The parts of day 03 look for items that appear in more than one place: in both compartments of
+//! a [Rucksack] for part 1, and in all three rucksacks of a group for part 2. Each item found adds
+//! its [priority] to the answer.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc::day_03::{priority, Rucksack};
+//!
+//! let rucksack = "vJrwpWtwJgWrhcsFMMfFFhFp".parse::<Rucksack>().unwrap();
+//! assert_eq!(Some('p'), rucksack.find_common());
+//! assert_eq!(16, priority('p').unwrap());
+//! ```
 
 use anyhow::anyhow;
 
+pub(crate) mod check;
+pub(crate) mod generate;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
 mod rucksack;
+pub(crate) mod stats;
 
-fn compute_priority(input: &char) -> Result<u32, anyhow::Error> {
+pub use rucksack::Rucksack;
+
+/// Returns the priority of an item: 1 to 26 for `a` to `z`, and 27 to 52 for `A` to `Z`.
+///
+/// Returns an error if the item is not an ASCII letter.
+pub fn priority(input: char) -> Result<u32, anyhow::Error> {
     if !input.is_ascii_alphabetic() {
         return Err(anyhow!(
             "invalid character for priority computation: {}",
             input
         ));
     }
-    let mut val = *input as u32;
+    let mut val = input as u32;
     if (65..=90).contains(&val) {
         val -= 38
     } else if (97..=122).contains(&val) {
@@ -22,15 +44,24 @@ fn compute_priority(input: &char) -> Result<u32, anyhow::Error> {
     Ok(val)
 }
 
+/// Parses the input for both parts without solving them.
+pub(crate) fn parse(input: &str) -> Result<(), anyhow::Error> {
+    for line in input.split('\n') {
+        Rucksack::new(line)?;
+    }
+    part_2::create_groups(input)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_compute_priority() {
-        assert_eq!(1, compute_priority(&'a').expect("should return result"));
-        assert_eq!(26, compute_priority(&'z').expect("should return result"));
-        assert_eq!(27, compute_priority(&'A').expect("should return result"));
-        assert_eq!(52, compute_priority(&'Z').expect("should return result"));
+    fn test_priority() {
+        assert_eq!(1, priority('a').expect("should return result"));
+        assert_eq!(26, priority('z').expect("should return result"));
+        assert_eq!(27, priority('A').expect("should return result"));
+        assert_eq!(52, priority('Z').expect("should return result"));
     }
 }
@@ -1,18 +1,32 @@
 // TODO: docs
 
-use crate::solution::day_03::{compute_priority, rucksack::Rucksack};
+use crate::solution::day_03::{priority, Rucksack};
+use std::io::BufRead;
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     let mut total = 0;
     for line in input.split('\n') {
         let rucksack = Rucksack::new(line)?;
         if let Some(val) = rucksack.find_common() {
-            total += compute_priority(&val)?
+            total += priority(val)?
         }
     }
     Ok(total.to_string())
 }
 
+/// Sums the priorities the same way as [solve], reading the input a line at a time.
+pub(crate) fn solve_reader(reader: &mut dyn BufRead) -> Result<String, anyhow::Error> {
+    let mut total = 0;
+    crate::stream::for_each_line(reader, |line| {
+        let rucksack = Rucksack::new(line)?;
+        if let Some(val) = rucksack.find_common() {
+            total += priority(val)?
+        }
+        Ok(())
+    })?;
+    Ok(total.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -26,5 +40,9 @@ wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
 ttgJtRGJQctTZtZT
 CrZsJsPPZsGzwwsLwLmpwMDw";
         assert_eq!("157", solve(input).expect("should return result"));
+        assert_eq!(
+            "157",
+            solve_reader(&mut input.as_bytes()).expect("should return result")
+        );
     }
 }
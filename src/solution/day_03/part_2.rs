@@ -1,19 +1,21 @@
 // TOOD: docs
 
-use crate::solution::day_03::compute_priority;
+use crate::solution::day_03::priority;
 use anyhow::anyhow;
+use std::io::BufRead;
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     let groups = create_groups(input)?;
+    crate::metrics::parsed();
     let commons = find_group_commons(&groups)?;
     let priority = sum_priorities(&commons)?;
     Ok(priority.to_string())
 }
 
-fn create_groups(input: &str) -> Result<Vec<Vec<String>>, anyhow::Error> {
+pub(super) fn create_groups(input: &str) -> Result<Vec<Vec<String>>, anyhow::Error> {
     let mut groups = Vec::new();
     let lines: Vec<&str> = input.split('\n').collect();
-    if lines.len() % 3 != 0 {
+    if !lines.len().is_multiple_of(3) {
         return Err(anyhow!("must be at least three in each group"));
     }
     for i in (0..lines.len()).step_by(3) {
@@ -26,6 +28,27 @@ fn create_groups(input: &str) -> Result<Vec<Vec<String>>, anyhow::Error> {
     Ok(groups)
 }
 
+/// Sums the priorities the same way as [solve], reading the input a line at a time and only
+/// keeping the group being read.
+pub(crate) fn solve_reader(reader: &mut dyn BufRead) -> Result<String, anyhow::Error> {
+    let mut total = 0;
+    let mut group = Vec::with_capacity(3);
+    crate::stream::for_each_line(reader, |line| {
+        group.push(line.to_string());
+        if group.len() == 3 {
+            if let Some(common) = find_common(&group)? {
+                total += priority(common)?;
+            }
+            group.clear();
+        }
+        Ok(())
+    })?;
+    if !group.is_empty() {
+        return Err(anyhow!("must be at least three in each group"));
+    }
+    Ok(total.to_string())
+}
+
 fn find_group_commons(groups: &Vec<Vec<String>>) -> Result<Vec<char>, anyhow::Error> {
     let mut commons = Vec::new();
     for group in groups {
@@ -39,12 +62,12 @@ fn find_group_commons(groups: &Vec<Vec<String>>) -> Result<Vec<char>, anyhow::Er
 fn sum_priorities(commons: &Vec<char>) -> Result<u32, anyhow::Error> {
     let mut total = 0;
     for item in commons {
-        total += compute_priority(item)?;
+        total += priority(*item)?;
     }
     Ok(total)
 }
 
-fn find_common(group: &Vec<String>) -> Result<Option<char>, anyhow::Error> {
+fn find_common(group: &[String]) -> Result<Option<char>, anyhow::Error> {
     if group.len() != 3 {
         return Err(anyhow!("groups can only have three members"));
     }
@@ -69,6 +92,10 @@ wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
 ttgJtRGJQctTZtZT
 CrZsJsPPZsGzwwsLwLmpwMDw";
         assert_eq!("70", solve(input).expect("should return result"));
+        assert_eq!(
+            "70",
+            solve_reader(&mut input.as_bytes()).expect("should return result")
+        );
     }
 
     #[test]
@@ -1,16 +1,23 @@
-// TODO: docs
+//! Rucksacks and their compartments.
 
 use anyhow::anyhow;
 use std::collections::HashMap;
+use std::str::FromStr;
 
-pub(super) struct Rucksack {
+/// A rucksack's items, split evenly between its two compartments.
+#[derive(Debug)]
+pub struct Rucksack {
     first: HashMap<char, u32>,
     second: HashMap<char, u32>,
 }
 
 impl Rucksack {
     pub(super) fn new(input: &str) -> Result<Self, anyhow::Error> {
-        if input.len() % 2 != 0 {
+        // items are single letters, which also makes splitting by bytes safe
+        if let Some(item) = input.chars().find(|c| !c.is_ascii_alphabetic()) {
+            return Err(anyhow!("invalid item in rucksack: {}", item));
+        }
+        if !input.len().is_multiple_of(2) {
             return Err(anyhow!("input does not have even number of characters"));
         }
         let mut rucksack = Rucksack {
@@ -27,7 +34,13 @@ impl Rucksack {
         Ok(rucksack)
     }
 
-    pub(super) fn find_common(&self) -> Option<char> {
+    /// Counts the items in both compartments.
+    pub fn item_count(&self) -> u32 {
+        self.first.values().chain(self.second.values()).sum()
+    }
+
+    /// Finds an item that is in both compartments, if there is one.
+    pub fn find_common(&self) -> Option<char> {
         for key in self.first.keys() {
             if self.second.contains_key(key) {
                 return Some(*key);
@@ -37,6 +50,15 @@ impl Rucksack {
     }
 }
 
+impl FromStr for Rucksack {
+    type Err = anyhow::Error;
+
+    /// Parses a line of items, where the first half of the line is the first compartment.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Rucksack::new(s)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,4 +70,12 @@ mod tests {
         let result = rucksack.find_common().expect("should have item in common");
         assert_eq!('p', result);
     }
+
+    #[test]
+    fn test_rucksack_invalid() {
+        // splitting this in half used to land in the middle of the character
+        let error = Rucksack::new("\u{655}").expect_err("should return error");
+        assert_eq!("invalid item in rucksack: \u{655}", error.to_string());
+        assert!(Rucksack::new("abc").is_err());
+    }
 }
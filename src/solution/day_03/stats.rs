@@ -0,0 +1,37 @@
+//! Statistics of rucksack lists.
+
+use crate::solution::day_03::part_2::create_groups;
+use crate::solution::day_03::Rucksack;
+use crate::stats::{range, Stat};
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    let rucksacks = input
+        .split('\n')
+        .map(Rucksack::new)
+        .collect::<Result<Vec<Rucksack>, anyhow::Error>>()?;
+    let groups = create_groups(input)?;
+    let items = rucksacks.iter().map(Rucksack::item_count);
+    Ok(vec![
+        Stat::new("rucksacks", rucksacks.len()),
+        Stat::new("groups", groups.len()),
+        Stat::new("items per rucksack", range(items)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = stats("abca\nAbcdAe\nxx").expect("should return result");
+        assert_eq!(
+            vec![
+                Stat::new("rucksacks", 3),
+                Stat::new("groups", 1),
+                Stat::new("items per rucksack", "2 to 6"),
+            ],
+            stats
+        );
+    }
+}
@@ -0,0 +1,35 @@
+//! Structural checks of section assignment pairs.
+
+use crate::check::{check_lines, Problem};
+use crate::solution::day_04::build_assignment_pair;
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    check_lines(input, |line| {
+        let (first, second) = build_assignment_pair(line)?;
+        // a backwards range parses, but never contains or overlaps the way it should
+        for assignment in [first, second] {
+            if assignment.lower > assignment.upper {
+                return Err(anyhow::anyhow!(
+                    "section range is backwards: {}-{}",
+                    assignment.lower,
+                    assignment.upper
+                ));
+            }
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("2-4,6-8\n2-3,4-5").is_empty());
+        assert_eq!(
+            vec![Problem::at(2, "section range is backwards: 5-4")],
+            check("2-4,6-8\n2-3,5-4")
+        );
+    }
+}
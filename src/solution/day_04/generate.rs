@@ -0,0 +1,17 @@
+//! Random pairs of section assignments.
+
+use crate::generate::Rng;
+
+/// Generates `pairs` pairs of section assignments.
+pub(crate) fn generate(rng: &mut Rng, pairs: usize) -> String {
+    (0..pairs)
+        .map(|_| format!("{},{}", assignment(rng), assignment(rng)))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn assignment(rng: &mut Rng) -> String {
+    let start = rng.range(1..=99);
+    let end = rng.range(start..=99);
+    format!("{}-{}", start, end)
+}
@@ -1,26 +1,58 @@
-// TODO: docs
+//! Solvers for day 04.
+//!
+//! The parts of day 04 compare pairs of section [Assignment]s, counting the pairs where one
+//! assignment contains the other for part 1, and the pairs that overlap at all for part 2.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc::day_04::Assignment;
+//!
+//! let outer = "2-8".parse::<Assignment>().unwrap();
+//! let inner = "3-7".parse::<Assignment>().unwrap();
+//! assert!(outer.contains(&inner));
+//! assert!(inner.overlaps(&outer));
+//! ```
 
 use anyhow::{anyhow, Context};
+use std::str::FromStr;
 
+pub(crate) mod check;
+pub(crate) mod generate;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod reference;
+pub(crate) mod stats;
 
+/// A range of section IDs assigned to an elf, including both ends.
 #[derive(Debug, PartialEq, Eq)]
-struct Assignment {
+pub struct Assignment {
     upper: u32,
     lower: u32,
 }
 
 impl Assignment {
-    fn new(lower: u32, upper: u32) -> Self {
+    pub fn new(lower: u32, upper: u32) -> Self {
         Self { upper, lower }
     }
 
-    fn contains(&self, other: &Assignment) -> bool {
+    /// Returns the first section in the assignment.
+    pub fn lower(&self) -> u32 {
+        self.lower
+    }
+
+    /// Returns the last section in the assignment.
+    pub fn upper(&self) -> u32 {
+        self.upper
+    }
+
+    /// Checks whether every section of the other assignment is also in this one.
+    pub fn contains(&self, other: &Assignment) -> bool {
         self.lower <= other.lower && self.upper >= other.upper
     }
 
-    fn overlaps(&self, other: &Assignment) -> bool {
+    /// Checks whether the assignments have at least one section in common.
+    pub fn overlaps(&self, other: &Assignment) -> bool {
         (other.lower >= self.lower && other.lower <= self.upper)
             || (other.upper >= self.lower && other.upper <= self.upper)
             || (self.lower >= other.lower && self.lower <= other.upper)
@@ -49,12 +81,30 @@ fn build_assignment(input: &str) -> Result<Assignment, anyhow::Error> {
     if parts.len() != 2 {
         return Err(anyhow!("invalid assignment string: {}", input));
     }
-    // TODO: Fix contexts
-    let lower = parts[0].parse::<u32>().context("lk")?;
-    let upper = parts[1].parse::<u32>().context("lk")?;
+    let lower = parts[0]
+        .parse::<u32>()
+        .with_context(|| format!("could not parse section to u32: {}", parts[0]))?;
+    let upper = parts[1]
+        .parse::<u32>()
+        .with_context(|| format!("could not parse section to u32: {}", parts[1]))?;
     Ok(Assignment::new(lower, upper))
 }
 
+impl FromStr for Assignment {
+    type Err = anyhow::Error;
+
+    /// Parses an assignment written as its first and last sections, such as `2-4`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        build_assignment(s)
+    }
+}
+
+/// Parses the input for both parts without solving them.
+pub(crate) fn parse(input: &str) -> Result<(), anyhow::Error> {
+    parse_input(input)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,10 +1,12 @@
 // TODO: docs
 
-use crate::solution::day_04::parse_input;
+use crate::solution::day_04::{build_assignment_pair, parse_input};
+use std::io::BufRead;
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     let mut total = 0;
     let pairs = parse_input(input)?;
+    crate::metrics::parsed();
     for pair in pairs {
         if pair.0.overlaps(&pair.1) {
             total += 1
@@ -13,6 +15,19 @@ pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     Ok(total.to_string())
 }
 
+/// Counts the overlapping pairs the same way as [solve], reading the input a line at a time.
+pub(crate) fn solve_reader(reader: &mut dyn BufRead) -> Result<String, anyhow::Error> {
+    let mut total = 0;
+    crate::stream::for_each_line(reader, |line| {
+        let pair = build_assignment_pair(line)?;
+        if pair.0.overlaps(&pair.1) {
+            total += 1
+        }
+        Ok(())
+    })?;
+    Ok(total.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -26,5 +41,9 @@ mod tests {
 6-6,4-6
 2-6,4-8";
         assert_eq!("4", solve(input).expect("should return result"));
+        assert_eq!(
+            "4",
+            solve_reader(&mut input.as_bytes()).expect("should return result")
+        );
     }
 }
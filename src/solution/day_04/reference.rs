@@ -0,0 +1,52 @@
+//! A slow reference implementation of day 04, for differential testing.
+//!
+//! Instead of comparing the ends of the ranges, every section of each assignment is listed and the
+//! two lists are compared section by section, which follows the puzzle's wording directly.
+
+use crate::solution::day_04::{parse_input, Assignment};
+use std::collections::HashSet;
+
+fn sections(assignment: &Assignment) -> HashSet<u32> {
+    (assignment.lower..=assignment.upper).collect()
+}
+
+pub(crate) fn part_1(input: &str) -> Result<String, anyhow::Error> {
+    let count = parse_input(input)?
+        .iter()
+        .filter(|(first, second)| {
+            let (first, second) = (sections(first), sections(second));
+            first.is_subset(&second) || second.is_subset(&first)
+        })
+        .count();
+    Ok(count.to_string())
+}
+
+pub(crate) fn part_2(input: &str) -> Result<String, anyhow::Error> {
+    let count = parse_input(input)?
+        .iter()
+        .filter(|(first, second)| !sections(first).is_disjoint(&sections(second)))
+        .count();
+    Ok(count.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!("2", part_1(INPUT).expect("should return result"));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!("4", part_2(INPUT).expect("should return result"));
+    }
+}
@@ -0,0 +1,30 @@
+//! Statistics of section assignment pairs.
+
+use crate::solution::day_04::parse_input;
+use crate::stats::{range, Stat};
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    let pairs = parse_input(input)?;
+    let assignments = pairs.iter().flat_map(|(first, second)| [first, second]);
+    Ok(vec![
+        Stat::new("pairs", pairs.len()),
+        Stat::new(
+            "sections",
+            range(assignments.flat_map(|a| [a.lower, a.upper])),
+        ),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = stats("2-4,6-8\n2-3,4-5").expect("should return result");
+        assert_eq!(
+            vec![Stat::new("pairs", 2), Stat::new("sections", "2 to 8")],
+            stats
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Structural checks of crate drawings and crane procedures.
+
+use crate::check::Problem;
+use crate::solution::day_05::parser::{parse_command, parse_stack_group};
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    let Some((drawing, procedure)) = input.split_once("\n\n") else {
+        return vec![Problem::general(
+            "expected a drawing of the stacks and a list of moves separated by a blank line",
+        )];
+    };
+    let mut problems = Vec::new();
+    // follow how many crates are in each stack, so that moves can be checked against them
+    let mut heights = match parse_stack_group(drawing) {
+        Ok(stack_group) => Some(
+            stack_group
+                .stacks
+                .iter()
+                .map(Vec::len)
+                .collect::<Vec<usize>>(),
+        ),
+        Err(e) => {
+            problems.push(Problem::general(format!("invalid drawing: {:#}", e)));
+            None
+        }
+    };
+    let first_move_line = drawing.split('\n').count() + 2;
+    for (i, line) in procedure.split('\n').enumerate() {
+        let line_number = first_move_line + i;
+        let command = match parse_command(line) {
+            Ok(command) => command,
+            Err(e) => {
+                problems.push(Problem::at(line_number, format!("{:#}", e)));
+                continue;
+            }
+        };
+        let Some(heights) = heights.as_mut() else {
+            continue;
+        };
+        // the placeholder at index 0 isn't a real stack
+        let stacks = 1..heights.len();
+        let missing = [command.source, command.target]
+            .into_iter()
+            .filter(|stack| !stacks.contains(stack))
+            .collect::<Vec<usize>>();
+        if !missing.is_empty() {
+            for stack in missing {
+                problems.push(Problem::at(
+                    line_number,
+                    format!("stack {} does not exist", stack),
+                ));
+            }
+            continue;
+        }
+        let count = command.count as usize;
+        let available = heights[command.source];
+        if count > available {
+            problems.push(Problem::at(
+                line_number,
+                format!(
+                    "moves {} crates from stack {}, which only has {}",
+                    count, command.source, available
+                ),
+            ));
+        }
+        heights[command.source] -= count.min(available);
+        heights[command.target] += count.min(available);
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let drawing = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\n";
+        assert!(check(&format!(
+            "{}move 1 from 2 to 1\nmove 3 from 1 to 3",
+            drawing
+        ))
+        .is_empty());
+        assert_eq!(
+            vec![
+                Problem::at(6, "moves 4 crates from stack 2, which only has 3"),
+                Problem::at(7, "invalid input string: move 1 from 2"),
+                Problem::at(8, "stack 4 does not exist"),
+            ],
+            check(&format!(
+                "{}move 4 from 2 to 1\nmove 1 from 2\nmove 1 from 1 to 4",
+                drawing
+            ))
+        );
+        assert_eq!(1, check("move 1 from 2 to 1").len());
+    }
+}
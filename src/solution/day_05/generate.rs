@@ -0,0 +1,53 @@
+//! Random stacks of crates and crane procedures.
+
+use crate::generate::Rng;
+
+/// Generates a drawing of up to nine stacks followed by `moves` crane moves.
+///
+/// Moves are chosen by following the stacks as they change, so a move never takes more crates than
+/// its stack holds.
+pub(crate) fn generate(rng: &mut Rng, moves: usize) -> String {
+    let stack_count = rng.range(3..=9) as usize;
+    let mut heights = (0..stack_count)
+        .map(|_| rng.range(1..=8) as usize)
+        .collect::<Vec<usize>>();
+    let tallest = *heights.iter().max().expect("there is at least one stack");
+    let mut lines = Vec::new();
+    for level in (0..tallest).rev() {
+        let line = heights
+            .iter()
+            .map(|&height| match level < height {
+                true => format!("[{}]", (b'A' + rng.range(0..=25) as u8) as char),
+                false => "   ".to_string(),
+            })
+            .collect::<Vec<String>>()
+            .join(" ");
+        lines.push(line.trim_end().to_string());
+    }
+    let labels = (1..=stack_count)
+        .map(|i| format!(" {} ", i))
+        .collect::<Vec<String>>()
+        .join(" ");
+    lines.push(labels.trim_end().to_string());
+    lines.push(String::new());
+    for _ in 0..moves {
+        let non_empty = (0..stack_count)
+            .filter(|&i| heights[i] > 0)
+            .collect::<Vec<usize>>();
+        let source = *rng.choose(&non_empty);
+        let mut target = rng.index(stack_count - 1);
+        if target >= source {
+            target += 1;
+        }
+        let count = rng.range(1..=heights[source].min(6) as u64) as usize;
+        heights[source] -= count;
+        heights[target] += count;
+        lines.push(format!(
+            "move {} from {} to {}",
+            count,
+            source + 1,
+            target + 1
+        ));
+    }
+    lines.join("\n")
+}
@@ -1,14 +1,71 @@
-// TODO: docs
+//! Solvers for day 05.
+//!
+//! The parts of day 05 rearrange crates between the stacks of a [StackGroup] by following a list of
+//! [Command]s, then read the crates left on top of each stack. Part 1's crane moves crates one at a
+//! time, while part 2's crane moves them all at once and so preserves their order.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc::day_05::{Command, StackGroup};
+//!
+//! let mut stacks = "[Z] [M] [P]\n 1   2   3".parse::<StackGroup>().unwrap();
+//! let command = "move 1 from 2 to 1".parse::<Command>().unwrap();
+//! stacks.move_crates(&command, false).unwrap();
+//! assert_eq!("MP", stacks.top_of_stacks());
+//! assert_eq!(Some(&['Z', 'M'][..]), stacks.stack(1));
+//! ```
 
+use anyhow::anyhow;
+use std::str::FromStr;
+
+pub(crate) mod check;
+pub(crate) mod generate;
 mod parser;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod stats;
+pub(crate) mod visualize;
 
-struct StackGroup {
+/// Numbered stacks of crates, each listed from the bottom up.
+#[derive(Debug)]
+pub struct StackGroup {
+    /// The stacks, with an empty placeholder at index 0 so that stack numbers can be used directly.
     stacks: Vec<Vec<char>>,
 }
 
 impl StackGroup {
+    /// Returns the number of stacks.
+    pub fn stack_count(&self) -> usize {
+        self.stacks.len() - 1
+    }
+
+    /// Returns a stack's crates from the bottom up, where stacks are numbered from 1.
+    pub fn stack(&self, number: usize) -> Option<&[char]> {
+        match number {
+            0 => None,
+            _ => self.stacks.get(number).map(Vec::as_slice),
+        }
+    }
+
+    /// Carries out a command, moving the crates one at a time unless `preserve_order` is set.
+    ///
+    /// Returns an error if the command refers to a stack that does not exist. Moving more crates
+    /// than a stack holds moves all of them.
+    pub fn move_crates(
+        &mut self,
+        command: &Command,
+        preserve_order: bool,
+    ) -> Result<(), anyhow::Error> {
+        for stack in [command.source, command.target] {
+            if self.stack(stack).is_none() {
+                return Err(anyhow!("stack {} does not exist", stack));
+            }
+        }
+        self.execute_command(command, preserve_order);
+        Ok(())
+    }
+
     fn execute_command(&mut self, command: &Command, preserve_order: bool) {
         let mut buf = Vec::new();
         for _ in 0..command.count {
@@ -22,7 +79,8 @@ impl StackGroup {
         self.stacks[command.target].append(&mut buf);
     }
 
-    fn top_of_stacks(&self) -> String {
+    /// Lists the crate on top of each stack, skipping empty stacks.
+    pub fn top_of_stacks(&self) -> String {
         let mut output = String::new();
         for stack in &self.stacks {
             if let Some(c) = stack.last() {
@@ -33,12 +91,55 @@ impl StackGroup {
     }
 }
 
-struct Command {
+impl FromStr for StackGroup {
+    type Err = anyhow::Error;
+
+    /// Parses a drawing of the stacks, whose last line numbers them.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parser::parse_stack_group(s)
+    }
+}
+
+/// A step of the rearrangement procedure, moving crates from one stack to another.
+#[derive(Debug)]
+pub struct Command {
     source: usize,
     target: usize,
     count: u32,
 }
 
+impl Command {
+    /// Returns the number of the stack the crates are taken from.
+    pub fn source(&self) -> usize {
+        self.source
+    }
+
+    /// Returns the number of the stack the crates are put on.
+    pub fn target(&self) -> usize {
+        self.target
+    }
+
+    /// Returns the number of crates moved.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+impl FromStr for Command {
+    type Err = anyhow::Error;
+
+    /// Parses a command written as `move <count> from <source> to <target>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parser::parse_command(s)
+    }
+}
+
+/// Parses the input for both parts without solving them.
+pub(crate) fn parse(input: &str) -> Result<(), anyhow::Error> {
+    parser::parse_input(input)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -70,4 +171,24 @@ mod tests {
         stack_group.execute_command(&command, true);
         assert_eq!("acy", stack_group.top_of_stacks());
     }
+
+    #[test]
+    fn test_move_crates() {
+        let mut stack_group = StackGroup {
+            stacks: vec![vec![], vec!['a', 'b', 'c'], vec!['f']],
+        };
+        let command = "move 5 from 1 to 2"
+            .parse::<Command>()
+            .expect("should return result");
+        stack_group
+            .move_crates(&command, false)
+            .expect("should return result");
+        assert_eq!(Some(&['f', 'c', 'b', 'a'][..]), stack_group.stack(2));
+        assert_eq!(Some(&[][..]), stack_group.stack(1));
+        assert_eq!(None, stack_group.stack(0));
+        let command = "move 1 from 2 to 3"
+            .parse::<Command>()
+            .expect("should return result");
+        assert!(stack_group.move_crates(&command, false).is_err());
+    }
 }
@@ -10,6 +10,18 @@ pub(super) fn parse_input(input: &str) -> Result<(StackGroup, Vec<Command>), any
     }
     let stack_group = parse_stack_group(parts[0])?;
     let commands = parse_commands(parts[1])?;
+    // the placeholder at index 0 isn't a real stack
+    let stacks = 1..stack_group.stacks.len();
+    for command in &commands {
+        if !stacks.contains(&command.source) || !stacks.contains(&command.target) {
+            return Err(anyhow!(
+                "command refers to a stack that does not exist: move {} from {} to {}",
+                command.count,
+                command.source,
+                command.target
+            ));
+        }
+    }
     Ok((stack_group, commands))
 }
 
@@ -33,12 +45,20 @@ pub(super) fn parse_stack_group(input: &str) -> Result<StackGroup, anyhow::Error
         raw_stack_frames.push(new_frame);
     }
     // populate the stacks
-    let stack_count = match parts[0].split(' ').last() {
+    let stack_count = match parts[0].split(' ').next_back() {
         Some(count) => count
             .parse::<usize>()
             .with_context(|| format!("could not parse token to usize: {}", count))?,
         None => return Err(anyhow!("no stacks present")),
     };
+    // check the count against the labels before making room for that many stacks
+    let label_count = parts[0].split_whitespace().count();
+    if stack_count != label_count {
+        return Err(anyhow!(
+            "last stack label does not match the number of stacks: {}",
+            stack_count
+        ));
+    }
     let mut stacks: Vec<Vec<char>> = vec![Vec::new()];
     for _ in 0..stack_count {
         stacks.push(Vec::new());
@@ -137,4 +157,21 @@ move 1 from 1 to 2";
         assert_eq!('C', stack_group.stacks[2][1]);
         assert_eq!(2, commands[2].source);
     }
+
+    #[test]
+    fn test_parse_input_invalid() {
+        // a huge stack count used to be allocated before checking the labels
+        let error =
+            parse_input("[A]\n 99999999\n\nmove 1 from 1 to 1").expect_err("should return error");
+        assert_eq!(
+            "last stack label does not match the number of stacks: 99999999",
+            error.to_string()
+        );
+        // moving from a stack that doesn't exist used to panic when solving
+        let error = parse_input("[A]\n 1\n\nmove 1 from 2 to 1").expect_err("should return error");
+        assert_eq!(
+            "command refers to a stack that does not exist: move 1 from 2 to 1",
+            error.to_string()
+        );
+    }
 }
@@ -4,6 +4,7 @@ use crate::solution::day_05::parser::parse_input;
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     let (mut stack_group, commands) = parse_input(input)?;
+    crate::metrics::parsed();
     for command in commands {
         stack_group.execute_command(&command, false);
     }
@@ -0,0 +1,44 @@
+//! Statistics of crate drawings and crane procedures.
+
+use crate::solution::day_05::parser::parse_input;
+use crate::stats::{range, Stat};
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    let (stack_group, commands) = parse_input(input)?;
+    // the placeholder at index 0 isn't a real stack
+    let stacks = &stack_group.stacks[1..];
+    Ok(vec![
+        Stat::new("stacks", stacks.len()),
+        Stat::new("crates", stacks.iter().map(Vec::len).sum::<usize>()),
+        Stat::new("crates per stack", range(stacks.iter().map(Vec::len))),
+        Stat::new("moves", commands.len()),
+        Stat::new("crates per move", range(commands.iter().map(|c| c.count))),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let input = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3";
+        let stats = stats(input).expect("should return result");
+        assert_eq!(
+            vec![
+                Stat::new("stacks", 3),
+                Stat::new("crates", 6),
+                Stat::new("crates per stack", "1 to 3"),
+                Stat::new("moves", 2),
+                Stat::new("crates per move", "1 to 3"),
+            ],
+            stats
+        );
+    }
+}
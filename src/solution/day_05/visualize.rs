@@ -0,0 +1,122 @@
+//! Animation of the crane moving crates between stacks.
+
+use crate::solution::day_05::parser::parse_input;
+use crate::solution::day_05::{Command, StackGroup};
+use crate::visualize::Animation;
+
+/// Columns used to draw each stack, e.g. `[A] `.
+const STACK_WIDTH: usize = 4;
+
+pub(crate) struct CraneAnimation {
+    stack_group: StackGroup,
+    commands: Vec<Command>,
+    next: usize,
+    preserve_order: bool,
+}
+
+impl CraneAnimation {
+    /// Creates the animation, moving crates one at a time for part 1 or all at once for part 2.
+    pub(crate) fn new(input: &str, preserve_order: bool) -> Result<Self, anyhow::Error> {
+        let (stack_group, commands) = parse_input(input)?;
+        Ok(Self {
+            stack_group,
+            commands,
+            next: 0,
+            preserve_order,
+        })
+    }
+}
+
+impl Animation for CraneAnimation {
+    fn step(&mut self) -> bool {
+        match self.commands.get(self.next) {
+            Some(command) => {
+                self.stack_group
+                    .execute_command(command, self.preserve_order);
+                self.next += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn status(&self) -> String {
+        let last = match self.next.checked_sub(1).map(|i| &self.commands[i]) {
+            Some(c) => format!("move {} from {} to {}", c.count, c.source, c.target),
+            None => "start".to_string(),
+        };
+        format!(
+            "Command {}/{}: {} | top of stacks: {}",
+            self.next,
+            self.commands.len(),
+            last,
+            self.stack_group.top_of_stacks()
+        )
+    }
+
+    fn render(&self, _width: usize, height: usize) -> Vec<String> {
+        // the first stack is an unused placeholder so stack numbers match the input
+        let stacks = &self.stack_group.stacks[1..];
+        let tallest = stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+        // keep the tops of the stacks in view, since that's where crates move
+        let rows = tallest.min(height.saturating_sub(1));
+        let mut lines = Vec::new();
+        for level in (tallest - rows..tallest).rev() {
+            let mut line = String::new();
+            for stack in stacks {
+                match stack.get(level) {
+                    Some(c) => line.push_str(&format!("[{}] ", c)),
+                    None => line.push_str(&" ".repeat(STACK_WIDTH)),
+                }
+            }
+            lines.push(line.trim_end().to_string());
+        }
+        let labels = (1..=stacks.len())
+            .map(|i| format!("{:^3} ", i % 10))
+            .collect::<String>();
+        lines.push(labels.trim_end().to_string());
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    #[test]
+    fn test_render() {
+        let mut animation = CraneAnimation::new(INPUT, false).expect("should return result");
+        assert_eq!(
+            vec!["    [D]", "[N] [C]", "[Z] [M] [P]", " 1   2   3"],
+            animation.render(80, 10)
+        );
+        assert!(animation.step());
+        assert_eq!(
+            "Command 1/4: move 1 from 2 to 1 | top of stacks: DCP",
+            animation.status()
+        );
+        // only the tops of the stacks fit
+        assert_eq!(
+            vec!["[D]", "[N] [C]", " 1   2   3"],
+            animation.render(80, 3)
+        );
+    }
+
+    #[test]
+    fn test_step() {
+        let mut animation = CraneAnimation::new(INPUT, true).expect("should return result");
+        while animation.step() {}
+        assert!(animation.status().ends_with("top of stacks: MCD"));
+        assert!(!animation.step());
+    }
+}
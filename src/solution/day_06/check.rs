@@ -0,0 +1,49 @@
+//! Structural checks of datastreams.
+
+use crate::check::Problem;
+use crate::solution::day_06::decode_bitstream;
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    if input.contains('\n') {
+        problems.push(Problem::general(
+            "datastream should be a single line, but it has line breaks",
+        ));
+    }
+    if let Some(c) = input
+        .chars()
+        .find(|c| !c.is_ascii_lowercase() && *c != '\n')
+    {
+        problems.push(Problem::general(format!(
+            "datastream should only have lowercase letters, but it has: {}",
+            c
+        )));
+    }
+    // the solvers answer 0 when there is no marker
+    for (name, length) in [("start-of-packet", 4), ("start-of-message", 14)] {
+        if decode_bitstream(input, length) == 0 {
+            problems.push(Problem::general(format!(
+                "no {} marker of {} different characters",
+                name, length
+            )));
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("mjqjpqmgbljsphdztnvjfqwrcgsmlb").is_empty());
+        assert_eq!(
+            vec![
+                Problem::general("datastream should only have lowercase letters, but it has: A"),
+                Problem::general("no start-of-message marker of 14 different characters"),
+            ],
+            check("abcdA")
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Random datastreams with start-of-packet and start-of-message markers.
+
+use crate::generate::Rng;
+
+/// Distinct characters needed for a start-of-message marker, the longer of the two markers.
+const MESSAGE_MARKER: usize = 14;
+
+/// Generates a datastream of `characters` characters.
+///
+/// Random letters would contain a marker almost immediately, so the stream repeats a few letters
+/// and a run of distinct letters is placed somewhere in its second half to make sure both markers
+/// exist.
+pub(crate) fn generate(rng: &mut Rng, characters: usize) -> String {
+    let mut letters = b"abcdefghijklmnopqrstuvwxyz".to_vec();
+    rng.shuffle(&mut letters);
+    let common = &letters[..rng.range(2..=5) as usize];
+    let mut stream = (0..characters)
+        .map(|_| *rng.choose(common))
+        .collect::<Vec<u8>>();
+    let last_start = characters - MESSAGE_MARKER;
+    let start = rng.range((characters / 2).min(last_start) as u64..=last_start as u64) as usize;
+    stream[start..start + MESSAGE_MARKER].copy_from_slice(&letters[..MESSAGE_MARKER]);
+    rng.shuffle(&mut stream[start..start + MESSAGE_MARKER]);
+    String::from_utf8(stream).expect("letters are ASCII")
+}
@@ -1,14 +1,59 @@
-// TODO: docs
+//! Solvers for day 06.
+//!
+//! The parts of day 06 search a datastream for a marker, a run of characters that are all
+//! different: four characters long for the start of a packet in part 1, and fourteen for the start
+//! of a message in part 2.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc::day_06::find_marker;
+//!
+//! assert_eq!(Some(7), find_marker("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4));
+//! assert_eq!(None, find_marker("aaaa", 4));
+//! ```
 
 use std::collections::{HashMap, VecDeque};
+use std::io::BufRead;
 
+pub(crate) mod check;
+pub(crate) mod generate;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod stats;
 
-fn decode_bitstream(input: &str, unique_count: usize) -> u32 {
+/// Finds the end of the first marker of the given length, counting characters from 1.
+///
+/// Returns `None` if the datastream has no such marker.
+pub fn find_marker(datastream: &str, length: usize) -> Option<usize> {
+    match decode_bitstream(datastream, length) {
+        0 => None,
+        end => Some(end),
+    }
+}
+
+fn decode_bitstream(input: &str, unique_count: usize) -> usize {
+    decode_chars(input.chars(), unique_count)
+}
+
+/// Decodes a datastream read a piece at a time, stopping once the marker has been found.
+fn stream_bitstream(reader: &mut dyn BufRead, unique_count: usize) -> Result<usize, anyhow::Error> {
+    let mut error = None;
+    let chars = crate::stream::chars(reader).map_while(|c| c.map_err(|e| error = Some(e)).ok());
+    let result = decode_chars(chars, unique_count);
+    match error {
+        Some(e) => Err(e),
+        None => Ok(result),
+    }
+}
+
+/// Returns the position of the end of the first marker, or 0 if there is none.
+///
+/// A stream can be longer than a `u32` can count, so the position is kept as a `usize`.
+fn decode_chars(chars: impl Iterator<Item = char>, unique_count: usize) -> usize {
     let mut result = 0;
     let mut buf: VecDeque<char> = VecDeque::with_capacity(unique_count);
-    for (i, elem) in input.chars().enumerate() {
+    for (i, elem) in chars.enumerate() {
         buf.push_back(elem);
         if buf.len() < unique_count {
             continue;
@@ -19,7 +64,7 @@ fn decode_bitstream(input: &str, unique_count: usize) -> u32 {
         }
         buf.pop_front();
     }
-    result as u32
+    result
 }
 
 fn contains_all_unique(queue: &VecDeque<char>) -> bool {
@@ -34,3 +79,10 @@ fn contains_all_unique(queue: &VecDeque<char>) -> bool {
     }
     true
 }
+
+/// Parses the input for both parts without solving them.
+///
+/// Any text is a valid datastream, so there is nothing to check.
+pub(crate) fn parse(_input: &str) -> Result<(), anyhow::Error> {
+    Ok(())
+}
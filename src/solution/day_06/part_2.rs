@@ -1,11 +1,17 @@
 // TODO: docs
 
-use crate::solution::day_06::decode_bitstream;
+use crate::solution::day_06::{decode_bitstream, stream_bitstream};
+use std::io::BufRead;
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     Ok(decode_bitstream(input, 14).to_string())
 }
 
+/// Finds the marker the same way as [solve], reading the input a piece at a time.
+pub(crate) fn solve_reader(reader: &mut dyn BufRead) -> Result<String, anyhow::Error> {
+    Ok(stream_bitstream(reader, 14)?.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -14,13 +20,33 @@ mod tests {
     fn test_solve() {
         let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
         assert_eq!("19", solve(input).expect("should return result"));
+        assert_eq!(
+            "19",
+            solve_reader(&mut input.as_bytes()).expect("should return result")
+        );
         let input = "bvwbjplbgvbhsrlpgdmjqwftvncz";
         assert_eq!("23", solve(input).expect("should return result"));
+        assert_eq!(
+            "23",
+            solve_reader(&mut input.as_bytes()).expect("should return result")
+        );
         let input = "nppdvjthqldpwncqszvftbrmjlhg";
         assert_eq!("23", solve(input).expect("should return result"));
+        assert_eq!(
+            "23",
+            solve_reader(&mut input.as_bytes()).expect("should return result")
+        );
         let input = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
         assert_eq!("29", solve(input).expect("should return result"));
+        assert_eq!(
+            "29",
+            solve_reader(&mut input.as_bytes()).expect("should return result")
+        );
         let input = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
         assert_eq!("26", solve(input).expect("should return result"));
+        assert_eq!(
+            "26",
+            solve_reader(&mut input.as_bytes()).expect("should return result")
+        );
     }
 }
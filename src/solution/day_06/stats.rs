@@ -0,0 +1,14 @@
+//! Statistics of datastreams.
+
+use crate::stats::Stat;
+use std::collections::HashSet;
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    Ok(vec![
+        Stat::new("characters", input.chars().count()),
+        Stat::new(
+            "distinct characters",
+            input.chars().collect::<HashSet<char>>().len(),
+        ),
+    ])
+}
@@ -0,0 +1,53 @@
+//! Structural checks of terminal output.
+
+use crate::check::Problem;
+use crate::solution::day_07::part_2::{DISK_SIZE, SPACE_NEEDED};
+use crate::solution::day_07::{calculate_size, new_directory, parse_line};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let root = Rc::new(RefCell::new(new_directory()));
+    let mut current_directory = root.clone();
+    for (i, line) in input.split('\n').enumerate() {
+        // stay in the same directory after a bad line so that the lines after it are still checked
+        match parse_line(&root, &current_directory, line) {
+            Ok(directory) => current_directory = directory,
+            Err(e) => problems.push(Problem::at(i + 1, format!("{:#}", e))),
+        }
+    }
+    match calculate_size(&root) {
+        Ok(used) if used > DISK_SIZE => problems.push(Problem::general(format!(
+            "files use {} but the disk only holds {}",
+            used, DISK_SIZE
+        ))),
+        Ok(used) if DISK_SIZE - used >= SPACE_NEEDED => problems.push(Problem::general(format!(
+            "files use {}, which already leaves room for the update without deleting anything",
+            used
+        ))),
+        Ok(_) => (),
+        Err(e) => problems.push(Problem::general(format!("{:#}", e))),
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("$ cd /\n$ ls\n50000000 a").is_empty());
+        assert_eq!(
+            vec![
+                Problem::at(2, "current directory does not contain directory: b"),
+                Problem::at(4, "invalid directory listing: 1 c d"),
+                Problem::general(
+                    "files use 10, which already leaves room for the update without deleting anything"
+                ),
+            ],
+            check("$ cd /\n$ cd b\n$ ls\n1 c d\n10 e")
+        );
+    }
+}
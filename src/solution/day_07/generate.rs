@@ -0,0 +1,95 @@
+//! Random terminal transcripts exploring a filesystem.
+
+use crate::generate::Rng;
+use std::collections::HashSet;
+
+/// Smallest and largest total size of the filesystem.
+///
+/// The puzzle expects the disk to be too full for the update, but not over capacity, so the total
+/// is kept above the 40000000 that part 2 needs and below the 70000000 the disk holds.
+const TOTAL_SIZES: (u64, u64) = (45_000_000, 69_000_000);
+
+struct Directory {
+    directories: Vec<(String, Directory)>,
+    files: Vec<(String, u64)>,
+}
+
+/// Generates a transcript that explores `directories` directories, including `/`.
+pub(crate) fn generate(rng: &mut Rng, directories: usize) -> String {
+    // pick a parent for every directory after the root, which gives a random tree
+    let parents = (1..directories)
+        .map(|i| rng.index(i))
+        .collect::<Vec<usize>>();
+    // weigh files so that most are small and a few are huge, then scale them to the total size
+    let mut weights = Vec::new();
+    for i in 0..directories {
+        let count = match i {
+            0 => rng.range(1..=4),
+            _ => rng.range(0..=4),
+        };
+        weights.push(
+            (0..count)
+                .map(|_| 10u64.pow(rng.range(0..=5) as u32) * rng.range(1..=9))
+                .collect::<Vec<u64>>(),
+        );
+    }
+    let total = rng.range(TOTAL_SIZES.0..=TOTAL_SIZES.1);
+    let weight_sum = weights.iter().flatten().sum::<u64>();
+    let root = build(rng, 0, &parents, &weights, total, weight_sum);
+    let mut lines = vec!["$ cd /".to_string()];
+    write(&root, &mut lines);
+    // there's no need to climb back out once everything has been listed
+    while lines.last().is_some_and(|line| line == "$ cd ..") {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+fn build(
+    rng: &mut Rng,
+    index: usize,
+    parents: &[usize],
+    weights: &[Vec<u64>],
+    total: u64,
+    weight_sum: u64,
+) -> Directory {
+    let mut names = HashSet::new();
+    let mut unique_name = |rng: &mut Rng, extension: bool| loop {
+        let mut name = rng.name(1..=8);
+        if extension && rng.chance(0.6) {
+            name = format!("{}.{}", name, rng.name(1..=3));
+        }
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+    let files = weights[index]
+        .iter()
+        .map(|weight| (unique_name(rng, true), (weight * total / weight_sum).max(1)))
+        .collect();
+    let children = (0..parents.len())
+        .filter(|&i| parents[i] == index)
+        .map(|i| i + 1)
+        .collect::<Vec<usize>>();
+    let mut directories = Vec::new();
+    for child in children {
+        let name = unique_name(rng, false);
+        directories.push((name, build(rng, child, parents, weights, total, weight_sum)));
+    }
+    Directory { directories, files }
+}
+
+fn write(directory: &Directory, lines: &mut Vec<String>) {
+    lines.push("$ ls".to_string());
+    for (name, _) in &directory.directories {
+        lines.push(format!("dir {}", name));
+    }
+    for (name, size) in &directory.files {
+        lines.push(format!("{} {}", size, name));
+    }
+    for (name, child) in &directory.directories {
+        lines.push(format!("$ cd {}", name));
+        write(child, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
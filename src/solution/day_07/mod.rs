@@ -1,18 +1,101 @@
-// TODO: docs
+//! Solvers for day 07.
+//!
+//! The parts of day 07 rebuild a [FileSystem] from the output of `cd` and `ls` commands, then look
+//! at the total sizes of its directories: summing the small ones for part 1, and finding the
+//! smallest one that frees enough space for an update for part 2.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc::day_07::FileSystem;
+//!
+//! let input = "$ cd /\n$ ls\ndir a\n100 b.txt\n$ cd a\n$ ls\n20 c";
+//! let file_system = input.parse::<FileSystem>().unwrap();
+//! assert_eq!(120, file_system.size());
+//! assert_eq!(Some(20), file_system.directory_size("/a"));
+//! assert_eq!(vec![20, 120], file_system.directory_sizes());
+//! ```
 
 use anyhow::{anyhow, Context};
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::{Rc, Weak};
+use std::str::FromStr;
 
+pub(crate) mod check;
+pub(crate) mod generate;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod repl;
+pub(crate) mod stats;
 
+/// Deepest nesting of directories allowed below the root.
+///
+/// Real transcripts only go a few directories deep, but sizes are worked out by recursing once per
+/// directory, so a limit stops a malformed transcript from overflowing the stack.
+const MAX_DEPTH: usize = 100;
+
+/// A tree of directories and files, with the total size of every directory worked out.
+#[derive(Debug)]
+pub struct FileSystem {
+    root: Rc<RefCell<Directory>>,
+}
+
+impl FileSystem {
+    /// Returns the total size of every file.
+    pub fn size(&self) -> u32 {
+        self.root.borrow().size
+    }
+
+    /// Returns the total size of the files in a directory and everything below it.
+    ///
+    /// Paths are followed from the root, with names separated by `/`, such as `/a/e`. Returns
+    /// `None` if there is no such directory.
+    pub fn directory_size(&self, path: &str) -> Option<u32> {
+        let mut directory = self.root.clone();
+        for name in path.split('/').filter(|name| !name.is_empty()) {
+            let child = directory.borrow().directories.get(name)?.clone();
+            directory = child;
+        }
+        let size = directory.borrow().size;
+        Some(size)
+    }
+
+    /// Returns the total size of every directory, including the root, from smallest to largest.
+    pub fn directory_sizes(&self) -> Vec<u32> {
+        fn visit(directory: &Rc<RefCell<Directory>>, sizes: &mut Vec<u32>) {
+            let directory = directory.borrow();
+            sizes.push(directory.size);
+            for child in directory.directories.values() {
+                visit(child, sizes);
+            }
+        }
+        let mut sizes = Vec::new();
+        visit(&self.root, &mut sizes);
+        sizes.sort();
+        sizes
+    }
+}
+
+impl FromStr for FileSystem {
+    type Err = anyhow::Error;
+
+    /// Parses the terminal output of browsing the file system, starting from the root.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            root: parse_input(s)?,
+        })
+    }
+}
+
+#[derive(Debug)]
 struct Directory {
     parent: Weak<RefCell<Directory>>,
     directories: HashMap<String, Rc<RefCell<Directory>>>,
     files: HashMap<String, u32>,
     size: u32,
+    /// How many directories deep this one is, where the root is 0.
+    depth: usize,
 }
 
 fn new_directory() -> Directory {
@@ -21,6 +104,7 @@ fn new_directory() -> Directory {
         directories: Default::default(),
         files: Default::default(),
         size: 0,
+        depth: 0,
     }
 }
 
@@ -31,11 +115,19 @@ fn add_directory(directory: &Rc<RefCell<Directory>>, name: &str) -> Result<(), a
             name
         ));
     }
+    let depth = directory.borrow().depth + 1;
+    if depth > MAX_DEPTH {
+        return Err(anyhow!(
+            "directories are nested more than {} deep",
+            MAX_DEPTH
+        ));
+    }
     let new_dir = Rc::new(RefCell::new(Directory {
         parent: Rc::downgrade(directory),
         directories: Default::default(),
         files: Default::default(),
         size: 0,
+        depth,
     }));
     directory
         .borrow_mut()
@@ -75,48 +167,72 @@ fn change_directory(
     }
 }
 
-fn calculate_size(directory: &Rc<RefCell<Directory>>) -> u32 {
-    let mut total = 0;
+fn calculate_size(directory: &Rc<RefCell<Directory>>) -> Result<u32, anyhow::Error> {
+    let mut total: u32 = 0;
     for dir in &directory.borrow().directories {
-        total += calculate_size(dir.1)
+        total = add_size(total, calculate_size(dir.1)?)?;
     }
     for file in &directory.borrow().files {
-        total += file.1;
+        total = add_size(total, *file.1)?;
     }
     directory.borrow_mut().size = total;
+    Ok(total)
+}
+
+fn add_size(total: u32, size: u32) -> Result<u32, anyhow::Error> {
     total
+        .checked_add(size)
+        .ok_or_else(|| anyhow!("directory size is too large to fit in a u32"))
 }
 
 fn parse_input(input: &str) -> Result<Rc<RefCell<Directory>>, anyhow::Error> {
     let root = Rc::new(RefCell::new(new_directory()));
     let mut current_directory = root.clone();
     for line in input.split('\n') {
-        // line is a command
-        if line.starts_with('$') {
-            let parts = line.split(' ').collect::<Vec<&str>>();
-            if parts[1] == "cd" {
-                match parts[2] {
-                    "/" => current_directory = root.clone(),
-                    _ => current_directory = change_directory(&current_directory, parts[2])?,
-                }
-            }
-            continue;
-        }
-        // line is from ls
-        let parts = line.split(' ').collect::<Vec<&str>>();
-        if parts[0] == "dir" {
-            add_directory(&current_directory, parts[1])?
-        } else {
-            let size = parts[0]
-                .parse::<u32>()
-                .with_context(|| format!("could not parse token to u32: {}", parts[0]))?;
-            add_file(&current_directory, parts[1], size)?;
-        }
+        current_directory = parse_line(&root, &current_directory, line)?;
     }
-    calculate_size(&root);
+    calculate_size(&root)?;
     Ok(root)
 }
 
+/// Applies one line of terminal output to the tree, returning the directory the line leaves us in.
+fn parse_line(
+    root: &Rc<RefCell<Directory>>,
+    current_directory: &Rc<RefCell<Directory>>,
+    line: &str,
+) -> Result<Rc<RefCell<Directory>>, anyhow::Error> {
+    // line is a command
+    if line.starts_with('$') {
+        let parts = line.split(' ').collect::<Vec<&str>>();
+        return match parts[..] {
+            ["$", "cd", "/"] => Ok(root.clone()),
+            ["$", "cd", name] => change_directory(current_directory, name),
+            ["$", "ls"] => Ok(current_directory.clone()),
+            _ => Err(anyhow!("invalid command: {}", line)),
+        };
+    }
+    // line is from ls
+    let parts = line.split(' ').collect::<Vec<&str>>();
+    if parts.len() != 2 {
+        return Err(anyhow!("invalid directory listing: {}", line));
+    }
+    if parts[0] == "dir" {
+        add_directory(current_directory, parts[1])?
+    } else {
+        let size = parts[0]
+            .parse::<u32>()
+            .with_context(|| format!("could not parse token to u32: {}", parts[0]))?;
+        add_file(current_directory, parts[1], size)?;
+    }
+    Ok(current_directory.clone())
+}
+
+/// Parses the input for both parts without solving them.
+pub(crate) fn parse(input: &str) -> Result<(), anyhow::Error> {
+    parse_input(input)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,4 +265,35 @@ $ ls
         let root = parse_input(input).expect("should return result");
         assert_eq!(48381165, root.borrow().size);
     }
+
+    #[test]
+    fn test_parse_input_invalid() {
+        // lines that are too short used to index past the end of their parts
+        for input in ["$", "$ cd", "$ cd a b", "$ dir", "dir", "123", "dir a b"] {
+            assert!(parse_input(input).is_err(), "{}", input);
+        }
+        let error = parse_input("4294967295 a\n1 b").expect_err("should return error");
+        assert_eq!(
+            "directory size is too large to fit in a u32",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_parse_input_too_deep() {
+        // sizes are worked out recursively, so deep nesting used to overflow the stack
+        let nested = |depth: usize| {
+            let mut lines = vec!["$ cd /".to_string()];
+            for _ in 0..depth {
+                lines.extend(["$ ls", "dir a", "$ cd a"].map(String::from));
+            }
+            lines.join("\n")
+        };
+        assert!(parse_input(&nested(MAX_DEPTH)).is_ok());
+        let error = parse_input(&nested(MAX_DEPTH + 1)).expect_err("should return error");
+        assert_eq!(
+            "directories are nested more than 100 deep",
+            error.to_string()
+        );
+    }
 }
@@ -1,24 +1,40 @@
 // TODO: docs
 
 use crate::solution::day_07::{parse_input, Directory};
+use anyhow::anyhow;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+/// Directories smaller than this are counted towards the answer.
+pub(super) const SIZE_LIMIT: u32 = 100000;
+
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     let root = parse_input(input)?;
-    Ok(sum_sizes(&root).to_string())
+    crate::metrics::parsed();
+    Ok(sum_sizes(&root, SIZE_LIMIT)?.to_string())
 }
 
-fn sum_sizes(directory: &Rc<RefCell<Directory>>) -> u32 {
+/// Sums the sizes of the directories smaller than `limit`, counting a directory inside another
+/// towards both.
+pub(super) fn sum_sizes(
+    directory: &Rc<RefCell<Directory>>,
+    limit: u32,
+) -> Result<u32, anyhow::Error> {
     let dir = directory.borrow();
     let mut total = 0;
     for child in &dir.directories {
-        total += sum_sizes(child.1)
+        total = add_to_sum(total, sum_sizes(child.1, limit)?)?;
     }
-    if dir.size < 100000 {
-        total += dir.size;
+    if dir.size < limit {
+        total = add_to_sum(total, dir.size)?;
     }
+    Ok(total)
+}
+
+fn add_to_sum(total: u32, size: u32) -> Result<u32, anyhow::Error> {
     total
+        .checked_add(size)
+        .ok_or_else(|| anyhow!("sum of directory sizes is too large to fit in a u32"))
 }
 
 #[cfg(test)]
@@ -52,4 +68,16 @@ $ ls
 7214296 k";
         assert_eq!("95437", solve(input).expect("should return result"));
     }
+
+    #[test]
+    fn test_sum_sizes_too_large() {
+        // the root and the directory inside it both count, which used to overflow
+        let root = parse_input("$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n3000000000 b")
+            .expect("should return result");
+        let error = sum_sizes(&root, u32::MAX).expect_err("should return error");
+        assert_eq!(
+            "sum of directory sizes is too large to fit in a u32",
+            error.to_string()
+        );
+    }
 }
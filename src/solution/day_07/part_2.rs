@@ -1,17 +1,42 @@
 // TODO: docs
 
+use crate::error::{Categorize, ErrorKind};
 use crate::solution::day_07::{parse_input, Directory};
+use anyhow::anyhow;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+/// Total size of the device's disk.
+pub(super) const DISK_SIZE: u32 = 70000000;
+/// Free space needed to run the update.
+pub(super) const SPACE_NEEDED: u32 = 30000000;
+
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     let root = parse_input(input)?;
-    let free_space = 70000000 - root.borrow().size;
-    let space_to_free = 30000000 - free_space;
+    crate::metrics::parsed();
+    let used = root.borrow().size;
+    let free_space = DISK_SIZE
+        .checked_sub(used)
+        .ok_or_else(|| anyhow!("files use {} but the disk only holds {}", used, DISK_SIZE))
+        .categorize(ErrorKind::Simulation)?;
+    // with enough space already free there is no directory worth deleting
+    let space_to_free = SPACE_NEEDED
+        .checked_sub(free_space)
+        .filter(|&space| space > 0)
+        .ok_or_else(|| {
+            anyhow!(
+                "files use {}, which already leaves room for the update without deleting anything",
+                used
+            )
+        })
+        .categorize(ErrorKind::Simulation)?;
     Ok(find_directory_to_delete(&root, space_to_free).to_string())
 }
 
-fn find_directory_to_delete(directory: &Rc<RefCell<Directory>>, space_to_free: u32) -> u32 {
+pub(super) fn find_directory_to_delete(
+    directory: &Rc<RefCell<Directory>>,
+    space_to_free: u32,
+) -> u32 {
     let dir = directory.borrow();
     if dir.size < space_to_free {
         return u32::MAX;
@@ -57,4 +82,12 @@ $ ls
 7214296 k";
         assert_eq!("24933642", solve(input).expect("should return result"));
     }
+
+    #[test]
+    fn test_solve_enough_free_space() {
+        let error = solve("$ cd /\n$ ls\n100 a").expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Simulation), ErrorKind::of(&error));
+        let error = solve("$ cd /\n$ ls\n80000000 a").expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Simulation), ErrorKind::of(&error));
+    }
 }
@@ -0,0 +1,246 @@
+//! Explorer for the directory tree built from the terminal output.
+
+use crate::error::{Categorize, ErrorKind};
+use crate::repl::{arg, arg_or, unknown_command, Explorer};
+use crate::solution::day_07::part_1::{sum_sizes, SIZE_LIMIT};
+use crate::solution::day_07::part_2::{find_directory_to_delete, DISK_SIZE, SPACE_NEEDED};
+use crate::solution::day_07::{change_directory, parse_input, Directory};
+use anyhow::anyhow;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const HELP: &str = "pwd                      print the current directory
+cd <path>                change the current directory
+ls [path]                list a directory's contents and sizes
+size [path]              print a directory's total size
+tree [path]              print a directory and everything below it
+small [limit]            sum the sizes of directories under the limit (part 1)
+delete [disk] [needed]   find the smallest directory to delete to free enough space (part 2)";
+
+pub(crate) struct DirectoryExplorer {
+    root: Rc<RefCell<Directory>>,
+    current: Vec<String>,
+}
+
+impl DirectoryExplorer {
+    pub(crate) fn new(input: &str) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            root: parse_input(input)?,
+            current: Vec::new(),
+        })
+    }
+
+    /// Resolves a path, which is either absolute or relative to the current directory, into the
+    /// names of the directories leading to it from the root.
+    fn resolve(&self, path: Option<&str>) -> Result<Vec<String>, anyhow::Error> {
+        let path = match path {
+            Some(path) => path,
+            None => return Ok(self.current.clone()),
+        };
+        let mut names = match path.starts_with('/') {
+            true => Vec::new(),
+            false => self.current.clone(),
+        };
+        for name in path.split('/') {
+            match name {
+                "" | "." => {}
+                ".." => {
+                    names.pop();
+                }
+                _ => names.push(name.to_string()),
+            }
+        }
+        // make sure the path exists
+        self.directory(&names)?;
+        Ok(names)
+    }
+
+    /// Finds the directory reached by following the names from the root.
+    fn directory(&self, names: &[String]) -> Result<Rc<RefCell<Directory>>, anyhow::Error> {
+        let mut directory = self.root.clone();
+        for name in names {
+            directory = change_directory(&directory, name).categorize(ErrorKind::Usage)?;
+        }
+        Ok(directory)
+    }
+
+    fn list(&self, directory: &Rc<RefCell<Directory>>) -> String {
+        let dir = directory.borrow();
+        let mut lines = Vec::new();
+        let mut names = dir.directories.keys().collect::<Vec<&String>>();
+        names.sort();
+        for name in names {
+            lines.push(format!(
+                "dir {} ({})",
+                name,
+                dir.directories[name].borrow().size
+            ));
+        }
+        let mut names = dir.files.keys().collect::<Vec<&String>>();
+        names.sort();
+        for name in names {
+            lines.push(format!("{} {}", dir.files[name], name));
+        }
+        lines.join("\n")
+    }
+
+    fn tree(&self, name: &str, directory: &Rc<RefCell<Directory>>, depth: usize) -> Vec<String> {
+        let dir = directory.borrow();
+        let indent = "  ".repeat(depth);
+        let mut lines = vec![format!("{}- {} (dir, size={})", indent, name, dir.size)];
+        let mut names = dir.directories.keys().collect::<Vec<&String>>();
+        names.sort();
+        for child in names {
+            lines.extend(self.tree(child, &dir.directories[child], depth + 1));
+        }
+        let mut names = dir.files.keys().collect::<Vec<&String>>();
+        names.sort();
+        for file in names {
+            lines.push(format!(
+                "{}  - {} (file, size={})",
+                indent, file, dir.files[file]
+            ));
+        }
+        lines
+    }
+}
+
+impl Explorer for DirectoryExplorer {
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String, anyhow::Error> {
+        match command {
+            "pwd" => Ok(format!("/{}", self.current.join("/"))),
+            "cd" => {
+                let path = arg::<String>(args, 0, "path")?;
+                self.current = self.resolve(Some(&path))?;
+                Ok(String::new())
+            }
+            "ls" => {
+                let names = self.resolve(args.first().copied())?;
+                Ok(self.list(&self.directory(&names)?))
+            }
+            "size" => {
+                let names = self.resolve(args.first().copied())?;
+                Ok(self.directory(&names)?.borrow().size.to_string())
+            }
+            "tree" => {
+                let names = self.resolve(args.first().copied())?;
+                let name = names.last().map_or("/", |n| n.as_str());
+                Ok(self.tree(name, &self.directory(&names)?, 0).join("\n"))
+            }
+            "small" => {
+                let limit = arg_or(args, 0, "limit", SIZE_LIMIT)?;
+                Ok(sum_sizes(&self.root, limit)?.to_string())
+            }
+            "delete" => {
+                let disk = arg_or(args, 0, "disk", DISK_SIZE)?;
+                let needed = arg_or(args, 1, "needed", SPACE_NEEDED)?;
+                let used = self.root.borrow().size;
+                let space_to_free = used
+                    .checked_add(needed)
+                    .ok_or_else(|| anyhow!("space needed is too large: {}", needed))
+                    .categorize(ErrorKind::Usage)?
+                    .saturating_sub(disk);
+                if space_to_free == 0 {
+                    return Ok("there is already enough free space".to_string());
+                }
+                match find_directory_to_delete(&self.root, space_to_free) {
+                    u32::MAX => Err(anyhow!(
+                        "deleting everything would not free {} more bytes",
+                        space_to_free
+                    ))
+                    .categorize(ErrorKind::Usage),
+                    size => Ok(size.to_string()),
+                }
+            }
+            _ => Err(unknown_command(command)),
+        }
+    }
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explorer() -> DirectoryExplorer {
+        let input = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+        DirectoryExplorer::new(input).expect("should return result")
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut explorer = explorer();
+        assert_eq!("584", explorer.execute("size", &["a/e"]).unwrap());
+        explorer.execute("cd", &["a/e"]).unwrap();
+        assert_eq!("/a/e", explorer.execute("pwd", &[]).unwrap());
+        explorer.execute("cd", &[".."]).unwrap();
+        assert_eq!("94853", explorer.execute("size", &[]).unwrap());
+        assert_eq!(
+            "dir e (584)\n29116 f\n2557 g\n62596 h.lst",
+            explorer.execute("ls", &[]).unwrap()
+        );
+        assert_eq!("24933642", explorer.execute("size", &["/d"]).unwrap());
+        assert!(explorer.execute("cd", &["/x"]).is_err());
+        assert_eq!("/a", explorer.execute("pwd", &[]).unwrap());
+    }
+
+    #[test]
+    fn test_tree() {
+        let tree = explorer().execute("tree", &["/a"]).unwrap();
+        assert_eq!(
+            "- a (dir, size=94853)
+  - e (dir, size=584)
+    - i (file, size=584)
+  - f (file, size=29116)
+  - g (file, size=2557)
+  - h.lst (file, size=62596)",
+            tree
+        );
+    }
+
+    #[test]
+    fn test_parts_with_parameters() {
+        let mut explorer = explorer();
+        assert_eq!("95437", explorer.execute("small", &[]).unwrap());
+        assert_eq!("584", explorer.execute("small", &["1000"]).unwrap());
+        assert_eq!("24933642", explorer.execute("delete", &[]).unwrap());
+        assert_eq!(
+            "94853",
+            explorer.execute("delete", &["48381165", "90000"]).unwrap()
+        );
+        assert_eq!(
+            "there is already enough free space",
+            explorer.execute("delete", &["80000000"]).unwrap()
+        );
+        assert!(explorer.execute("delete", &["1000"]).is_err());
+        let error = explorer
+            .execute("delete", &["70000000", "4294967295"])
+            .expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+    }
+}
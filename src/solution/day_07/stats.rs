@@ -0,0 +1,70 @@
+//! Statistics of directory trees.
+
+use crate::solution::day_07::{parse_input, Directory};
+use crate::stats::Stat;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    let root = parse_input(input)?;
+    let mut totals = Totals::default();
+    totals.visit(&root, 0);
+    let size = root.borrow().size;
+    Ok(vec![
+        Stat::new("directories", totals.directories),
+        Stat::new("files", totals.files),
+        Stat::new("deepest directory", totals.depth),
+        Stat::new("total size", size),
+    ])
+}
+
+/// Counts gathered while walking the directory tree.
+#[derive(Default)]
+struct Totals {
+    directories: usize,
+    files: usize,
+    depth: usize,
+}
+
+impl Totals {
+    /// Adds a directory and everything below it, where the root is at depth 0.
+    fn visit(&mut self, directory: &Rc<RefCell<Directory>>, depth: usize) {
+        let directory = directory.borrow();
+        self.directories += 1;
+        self.files += directory.files.len();
+        self.depth = self.depth.max(depth);
+        for child in directory.directories.values() {
+            self.visit(child, depth + 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let input = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+$ cd a
+$ ls
+dir e
+29116 f
+$ cd e
+$ ls
+584 i";
+        let stats = stats(input).expect("should return result");
+        assert_eq!(
+            vec![
+                Stat::new("directories", 3),
+                Stat::new("files", 3),
+                Stat::new("deepest directory", 2),
+                Stat::new("total size", 14878214),
+            ],
+            stats
+        );
+    }
+}
@@ -0,0 +1,38 @@
+//! Structural checks of tree height grids.
+
+use crate::check::{check_grid, Problem};
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    if input.is_empty() {
+        return vec![Problem::general("input is empty")];
+    }
+    let mut problems = Vec::new();
+    for (i, line) in input.split('\n').enumerate() {
+        if let Some(c) = line.chars().find(|c| !c.is_ascii_digit()) {
+            problems.push(Problem::at(
+                i + 1,
+                format!("tree height is not a digit: {}", c),
+            ));
+        }
+    }
+    problems.extend(check_grid(input, "trees"));
+    problems.sort_by_key(|problem| problem.line);
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("303\n255\n653").is_empty());
+        assert_eq!(
+            vec![
+                Problem::at(2, "tree height is not a digit: x"),
+                Problem::at(3, "row has 4 trees, but the first row has 3"),
+            ],
+            check("303\n2x5\n6533")
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! Random square grids of tree heights.
+
+use crate::generate::Rng;
+
+/// Generates a grid with `size` trees along each side.
+pub(crate) fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| (0..size).map(|_| rng.range(0..=9).to_string()).collect())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
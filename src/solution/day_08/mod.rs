@@ -2,11 +2,26 @@
 //!
 //! The parts of day 08 use a grid of single-digit integers that represents the height of trees in
 //! a forest. Each part focuses on "visibility" from a given point in the grid in different ways.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc::day_08::TreeGrid;
+//!
+//! let grid = "30373\n25512\n65332\n33549\n35390".parse::<TreeGrid>().unwrap();
+//! assert_eq!(21, grid.count_visible());
+//! assert_eq!(8, grid.max_scenic_score());
+//! assert_eq!((5, 5), (grid.width(), grid.height()));
+//! ```
 
 use anyhow::anyhow;
+use std::str::FromStr;
 
+pub(crate) mod check;
+pub(crate) mod generate;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod stats;
 
 /// Indicates the direction of travel when checking visibility in the [TreeGrid].
 enum Direction {
@@ -16,8 +31,9 @@ enum Direction {
     Right,
 }
 
-/// Represents a grid of trees with heights from 1-9.
-struct TreeGrid {
+/// Represents a grid of trees with heights from 0-9.
+#[derive(Debug)]
+pub struct TreeGrid {
     trees: Vec<Vec<u8>>,
 }
 
@@ -33,7 +49,7 @@ impl TreeGrid {
             return Err(anyhow!("input is empty"));
         }
         // build the rows and columns
-        let mut trees = Vec::new();
+        let mut trees: Vec<Vec<u8>> = Vec::new();
         for line in input.split('\n') {
             let mut row = Vec::new();
             for c in line.chars() {
@@ -43,17 +59,41 @@ impl TreeGrid {
                     None => return Err(anyhow!("could not parse token to u8: {}", c)),
                 }
             }
+            // the visibility checks rely on every row being as long as the first
+            if !trees.is_empty() && row.len() != trees[0].len() {
+                return Err(anyhow!(
+                    "row {} has {} trees, but the first row has {}",
+                    trees.len() + 1,
+                    row.len(),
+                    trees[0].len()
+                ));
+            }
             trees.push(row);
         }
         Ok(TreeGrid { trees })
     }
 
+    /// Returns the number of trees in each row.
+    pub fn width(&self) -> usize {
+        self.trees[0].len()
+    }
+
+    /// Returns the number of rows of trees.
+    pub fn height(&self) -> usize {
+        self.trees.len()
+    }
+
+    /// Returns the height of the tree in a column and row, counting from 0 at the top left.
+    pub fn tree_height(&self, x: usize, y: usize) -> Option<u8> {
+        self.trees.get(y)?.get(x).copied()
+    }
+
     /// Count the number of trees visible from outside the grid.
     ///
     /// A tree is considered visible from outside the grid if it can be seen from the top, bottom,
     /// left, or right. Trees on the edge are always considered visible. Visibility is blocked by
     /// trees of equal or greater height.
-    pub(crate) fn count_visible(&self) -> u32 {
+    pub fn count_visible(&self) -> u32 {
         let mut visible_count = 0;
         for y in 0..self.trees.len() {
             for x in 0..self.trees[0].len() {
@@ -72,7 +112,7 @@ impl TreeGrid {
     /// multiplying them together. Trees can be seen if they are of equal or lesser height. Once
     /// a tree of equal or greater height is found no further trees are considered. Trees on the
     /// perimeter have a score of zero in the direction towards the edge.
-    pub(crate) fn max_scenic_score(&self) -> u32 {
+    pub fn max_scenic_score(&self) -> u32 {
         let mut score = 0;
         // loop over each tree
         for y in 0..self.trees.len() {
@@ -184,6 +224,20 @@ impl TreeGrid {
     }
 }
 
+impl FromStr for TreeGrid {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TreeGrid::new(s)
+    }
+}
+
+/// Parses the input for both parts without solving them.
+pub(crate) fn parse(input: &str) -> Result<(), anyhow::Error> {
+    TreeGrid::new(input)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -209,4 +263,13 @@ mod tests {
         let tree_grid = TreeGrid::new(input).expect("should return result");
         assert_eq!(8, tree_grid.max_scenic_score());
     }
+
+    #[test]
+    fn test_tree_grid_ragged() {
+        let error = TreeGrid::new("303\n25\n653").expect_err("should return error");
+        assert_eq!(
+            "row 2 has 2 trees, but the first row has 3",
+            error.to_string()
+        );
+    }
 }
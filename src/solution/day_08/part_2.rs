@@ -7,6 +7,7 @@ use crate::solution::day_08::TreeGrid;
 /// Uses the [TreeGrid] to parse the input and calculate the maximum scenic score of all the trees.
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     let tree_grid = TreeGrid::new(input)?;
+    crate::metrics::parsed();
     Ok(tree_grid.max_scenic_score().to_string())
 }
 
@@ -0,0 +1,31 @@
+//! Statistics of tree height grids.
+
+use crate::solution::day_08::TreeGrid;
+use crate::stats::{range, Stat};
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    let grid = TreeGrid::new(input)?;
+    Ok(vec![
+        Stat::new("columns", grid.trees[0].len()),
+        Stat::new("rows", grid.trees.len()),
+        Stat::new("heights", range(grid.trees.iter().flatten())),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = stats("303\n255\n653\n335").expect("should return result");
+        assert_eq!(
+            vec![
+                Stat::new("columns", 3),
+                Stat::new("rows", 4),
+                Stat::new("heights", "0 to 6"),
+            ],
+            stats
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Structural checks of rope motions.
+
+use crate::check::{check_lines, Problem};
+use crate::solution::day_09::parse_input;
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    check_lines(input, parse_input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("R 4\nU 4").is_empty());
+        assert_eq!(
+            vec![
+                Problem::at(1, "invalid direction: X"),
+                Problem::at(3, "invalid line: R"),
+            ],
+            check("X 4\nU 4\nR")
+        );
+    }
+}
@@ -0,0 +1,17 @@
+//! Random rope motions.
+
+use crate::generate::Rng;
+
+/// Generates `moves` motions of the head of the rope.
+pub(crate) fn generate(rng: &mut Rng, moves: usize) -> String {
+    (0..moves)
+        .map(|_| {
+            format!(
+                "{} {}",
+                rng.choose(&['U', 'D', 'L', 'R']),
+                rng.range(1..=19)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
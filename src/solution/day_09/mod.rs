@@ -2,28 +2,104 @@
 //!
 //! The parts of day 09 use a coordinate system to simulate a sort of rope physics. As the head of
 //! the rope moves up, down, left, or right, the "tail" of the rope moves according to some rules.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc::day_09::{Movement, Rope};
+//!
+//! let mut rope = Rope::new(1).unwrap();
+//! for line in ["R 4", "U 4", "L 3", "D 1", "R 4", "D 1", "L 5", "R 2"] {
+//!     rope.process_move(&line.parse::<Movement>().unwrap());
+//! }
+//! assert_eq!(13, rope.tail_visit_count());
+//! ```
+//!
+//! A [RopeSimulation] makes the same moves one unit at a time, so the rope can be inspected part of
+//! the way through.
 
+use crate::simulation::Simulation;
 use anyhow::{anyhow, Context};
 use std::collections::HashMap;
+use std::io::BufRead;
+use std::str::FromStr;
 
+pub(crate) mod check;
+pub(crate) mod generate;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod stats;
+pub(crate) mod visualize;
 
 /// Indicates the direction the head of the rope will travel.
-#[derive(Debug, PartialEq, Eq)]
-enum Direction {
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[non_exhaustive]
+pub enum Direction {
     Up,
     Down,
     Left,
     Right,
 }
 
+/// Largest number of units allowed in a single movement.
+///
+/// Every square the tail visits is stored, so a limit far beyond any real movement stops a single
+/// line from using up all of the memory.
+const MAX_COUNT: u32 = 10_000;
+
 /// A single movement of the rope head in a certain direction for a certain number of units.
-struct Movement {
+#[derive(Debug)]
+pub struct Movement {
     direction: Direction,
     count: u32,
 }
 
+impl Movement {
+    pub fn new(direction: Direction, count: u32) -> Self {
+        Self { direction, count }
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Returns the number of units the head moves.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+impl FromStr for Movement {
+    type Err = anyhow::Error;
+
+    /// Parses a movement of the form `<Direction> <Count>`, where:
+    /// - Direction is one of: `U`, `D`, `L`, `R`, representing up, down, left, and right
+    /// - Count is a natural number no larger than 10000
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split(' ').collect::<Vec<&str>>();
+        // each line should have exactly two parts: the direction token and the count token
+        if parts.len() != 2 {
+            return Err(anyhow!("invalid line: {}", s));
+        }
+        // get the direction
+        let direction = match parts[0] {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(anyhow!("invalid direction: {}", parts[0])),
+        };
+        // get the count
+        let count = parts[1]
+            .parse::<u32>()
+            .with_context(|| format!("could not convert token to u32: {}", parts[1]))?;
+        if count > MAX_COUNT {
+            return Err(anyhow!("count is larger than {}: {}", MAX_COUNT, s));
+        }
+        Ok(Movement { direction, count })
+    }
+}
+
 /// Represents whole-number coordinates within a grid.
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 struct Coordinates {
@@ -79,17 +155,20 @@ impl Coordinates {
 /// Represents a rope with "knots" being moved around in a coordinate system.
 ///
 /// A rope can have some number of knots after the head greater than zero. When the head moves
-/// according to a [Movement] each knot follows the knot in front of it according to the rules
-/// defined in [Coordinates::update]. Each unique set of coordinates visited by the last knot in the rope is
-/// tracked.
-struct Rope {
+/// according to a [Movement] each knot follows the knot in front of it, moving one step towards it
+/// whenever the two are no longer touching. Each unique set of coordinates visited by the last knot
+/// in the rope is tracked.
+#[derive(Debug)]
+pub struct Rope {
     knots: Vec<Coordinates>,
     visited: HashMap<Coordinates, bool>,
 }
 
 impl Rope {
     /// Creates a new rope with the provided number of knots.
-    pub(crate) fn new(knot_count: u32) -> Result<Self, anyhow::Error> {
+    ///
+    /// Returns an error if there are no knots after the head.
+    pub fn new(knot_count: u32) -> Result<Self, anyhow::Error> {
         // should have at least one knot other than the head
         if knot_count == 0 {
             return Err(anyhow!("must have at least one knot"));
@@ -106,7 +185,7 @@ impl Rope {
     }
 
     /// Convenience method for processing a list of [Movement] objects.
-    pub(crate) fn process_moves(&mut self, movements: &Vec<Movement>) {
+    pub fn process_moves(&mut self, movements: &[Movement]) {
         for movement in movements {
             self.process_move(movement);
         }
@@ -117,62 +196,133 @@ impl Rope {
     /// The head of the rope moves in some direction some number of times and each time it moves
     /// all knots are updated following the knot in front of it. When a new coordinate is visited
     /// in the grid it is added to the list of visited coordinates.
-    pub(crate) fn process_move(&mut self, movement: &Movement) {
+    pub fn process_move(&mut self, movement: &Movement) {
         // update the knots n number of times based on the movement
         for _ in 0..movement.count {
-            // move the head
-            match movement.direction {
-                Direction::Up => self.knots[0].y += 1,
-                Direction::Down => self.knots[0].y -= 1,
-                Direction::Left => self.knots[0].x -= 1,
-                Direction::Right => self.knots[0].x += 1,
-            }
-            // update all subsequent knots based on the knot in front of it
-            for i in 1..self.knots.len() {
-                let prev = self.knots[i - 1];
-                self.knots[i].update(&prev);
-            }
-            // insert will add if not exists, or overwrite if exists so we don't need to check
-            self.visited.insert(self.knots[self.knots.len() - 1], true);
+            self.move_head(movement.direction);
+        }
+    }
+
+    /// Moves the head a single unit and updates the knots behind it.
+    fn move_head(&mut self, direction: Direction) {
+        match direction {
+            Direction::Up => self.knots[0].y += 1,
+            Direction::Down => self.knots[0].y -= 1,
+            Direction::Left => self.knots[0].x -= 1,
+            Direction::Right => self.knots[0].x += 1,
+        }
+        // update all subsequent knots based on the knot in front of it
+        for i in 1..self.knots.len() {
+            let prev = self.knots[i - 1];
+            self.knots[i].update(&prev);
         }
+        // insert will add if not exists, or overwrite if exists so we don't need to check
+        self.visited.insert(self.knots[self.knots.len() - 1], true);
     }
 
     /// Return the number of unique coordinates visited by the last knot in the rope.
-    pub(crate) fn tail_visit_count(&self) -> u32 {
+    pub fn tail_visit_count(&self) -> u32 {
         // We only track visited nodes, so just return the count
         self.visited.values().len() as u32
     }
 }
 
-/// Parse a string input into a list of [Movement] objects.
+/// Moves a [Rope] through a list of [Movement]s, one unit of head movement per step.
 ///
-/// Each line in the input should be of the form `<Direction> <Count>`, where:
-/// - Direction is one of: `U`, `D`, `L`, `R`, representing up, down, left, and right
-/// - Count is a natural number
-fn parse_input(input: &str) -> Result<Vec<Movement>, anyhow::Error> {
-    let mut movements = Vec::new();
-    // loop over each line in the input
-    for line in input.split('\n') {
-        let parts = line.split(' ').collect::<Vec<&str>>();
-        // each line should have exactly two parts: the direction token and the count token
-        if parts.len() != 2 {
-            return Err(anyhow!("invalid line: {}", line));
-        }
-        // get the direction
-        let direction = match parts[0] {
-            "U" => Direction::Up,
-            "D" => Direction::Down,
-            "L" => Direction::Left,
-            "R" => Direction::Right,
-            _ => return Err(anyhow!("invalid direction: {}", parts[0])),
+/// The state is the position of each knot, starting with the head, where x grows to the right and y
+/// grows upwards.
+#[derive(Debug)]
+pub struct RopeSimulation {
+    rope: Rope,
+    movements: Vec<Movement>,
+    /// Index of the movement being made.
+    next: usize,
+    /// Units of the current movement that have already been made.
+    progress: u32,
+}
+
+impl RopeSimulation {
+    pub fn new(rope: Rope, movements: Vec<Movement>) -> Self {
+        let mut simulation = Self {
+            rope,
+            movements,
+            next: 0,
+            progress: 0,
         };
-        // get the count
-        let count = parts[1]
-            .parse::<u32>()
-            .with_context(|| format!("could not convert token to u32: {}", parts[1]))?;
-        movements.push(Movement { direction, count });
+        simulation.skip_finished();
+        simulation
+    }
+
+    pub fn rope(&self) -> &Rope {
+        &self.rope
+    }
+
+    /// Returns how many movements have been finished.
+    pub fn finished_moves(&self) -> usize {
+        self.next
+    }
+
+    /// Returns how many movements there are in total.
+    pub fn total_moves(&self) -> usize {
+        self.movements.len()
+    }
+
+    /// Moves on past finished movements, including any that don't move at all.
+    fn skip_finished(&mut self) {
+        while let Some(movement) = self.movements.get(self.next) {
+            if self.progress < movement.count {
+                break;
+            }
+            self.next += 1;
+            self.progress = 0;
+        }
     }
-    Ok(movements)
+}
+
+impl Simulation for RopeSimulation {
+    type State = Vec<(i32, i32)>;
+
+    fn step(&mut self) -> Result<(), anyhow::Error> {
+        if let Some(movement) = self.movements.get(self.next) {
+            self.rope.move_head(movement.direction);
+            self.progress += 1;
+            self.skip_finished();
+        }
+        Ok(())
+    }
+
+    fn is_done(&self) -> bool {
+        self.next >= self.movements.len()
+    }
+
+    fn state(&self) -> Self::State {
+        self.rope.knots.iter().map(|k| (k.x, k.y)).collect()
+    }
+}
+
+/// Parse a string input into a list of [Movement] objects, one per line.
+fn parse_input(input: &str) -> Result<Vec<Movement>, anyhow::Error> {
+    input.split('\n').map(Movement::from_str).collect()
+}
+
+/// Moves a rope with the given number of knots after its head by each movement while reading the
+/// input a line at a time, and counts the unique coordinates its tail visits.
+fn stream_tail_visit_count(
+    reader: &mut dyn BufRead,
+    knot_count: u32,
+) -> Result<u32, anyhow::Error> {
+    let mut rope = Rope::new(knot_count)?;
+    crate::stream::for_each_line(reader, |line| {
+        rope.process_move(&line.parse::<Movement>()?);
+        Ok(())
+    })?;
+    Ok(rope.tail_visit_count())
+}
+
+/// Parses the input for both parts without solving them.
+pub(crate) fn parse(input: &str) -> Result<(), anyhow::Error> {
+    parse_input(input)?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -264,6 +414,22 @@ mod tests {
         assert_eq!(13, rope.tail_visit_count());
     }
 
+    #[test]
+    fn test_rope_simulation() {
+        let movements = parse_input("R 2\nU 0\nU 2").expect("should return result");
+        let rope = Rope::new(1).expect("should return result");
+        let mut simulation = RopeSimulation::new(rope, movements);
+        simulation.step().expect("should return result");
+        assert_eq!(vec![(1, 0), (0, 0)], simulation.state());
+        simulation.step().expect("should return result");
+        // the empty movement is skipped along with the finished one
+        assert_eq!(2, simulation.finished_moves());
+        assert_eq!(2, simulation.run().expect("should return result"));
+        assert!(simulation.is_done());
+        assert_eq!(vec![(2, 2), (2, 1)], simulation.state());
+        assert_eq!(3, simulation.rope().tail_visit_count());
+    }
+
     #[test]
     fn test_parse_input() {
         let input = "R 4
@@ -276,4 +442,11 @@ U 5";
         assert_eq!(Direction::Down, movements[2].direction);
         assert_eq!(1, movements[2].count);
     }
+
+    #[test]
+    fn test_parse_input_count_too_large() {
+        // every square the tail visits is stored, so this used to run out of memory
+        let error = parse_input("R 4\nL 585685394").expect_err("should return error");
+        assert_eq!("count is larger than 10000: L 585685394", error.to_string());
+    }
 }
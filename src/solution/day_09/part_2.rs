@@ -1,6 +1,8 @@
 //! Solver for part 2.
 
-use crate::solution::day_09::{parse_input, Rope};
+use crate::simulation::Simulation;
+use crate::solution::day_09::{parse_input, stream_tail_visit_count, Rope, RopeSimulation};
+use std::io::BufRead;
 
 /// Count the unique coordinates that the tail of a rope of length 10 visits.
 ///
@@ -10,10 +12,16 @@ use crate::solution::day_09::{parse_input, Rope};
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     // get movements from input
     let movements = parse_input(input)?;
-    let mut rope_bridge = Rope::new(9)?;
-    // loop over movements updating rope
-    rope_bridge.process_moves(&movements);
-    Ok(rope_bridge.tail_visit_count().to_string())
+    crate::metrics::parsed();
+    let mut simulation = RopeSimulation::new(Rope::new(9)?, movements);
+    // make every movement, updating the rope
+    simulation.run()?;
+    Ok(simulation.rope().tail_visit_count().to_string())
+}
+
+/// Count the unique coordinates the same way as [solve], reading the input a line at a time.
+pub(crate) fn solve_reader(reader: &mut dyn BufRead) -> Result<String, anyhow::Error> {
+    Ok(stream_tail_visit_count(reader, 9)?.to_string())
 }
 
 #[cfg(test)]
@@ -31,5 +39,9 @@ D 10
 L 25
 U 20";
         assert_eq!("36", solve(input).expect("should return result"));
+        assert_eq!(
+            "36",
+            solve_reader(&mut input.as_bytes()).expect("should return result")
+        );
     }
 }
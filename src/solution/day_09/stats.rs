@@ -0,0 +1,51 @@
+//! Statistics of rope motions.
+
+use crate::solution::day_09::{parse_input, Direction};
+use crate::stats::{range, Stat};
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    let movements = parse_input(input)?;
+    // follow the head to find how far the rope reaches in each direction
+    let (mut x, mut y) = (0i64, 0i64);
+    let mut xs = vec![x];
+    let mut ys = vec![y];
+    for movement in &movements {
+        let count = movement.count as i64;
+        match movement.direction {
+            Direction::Up => y += count,
+            Direction::Down => y -= count,
+            Direction::Left => x -= count,
+            Direction::Right => x += count,
+        }
+        xs.push(x);
+        ys.push(y);
+    }
+    Ok(vec![
+        Stat::new("moves", movements.len()),
+        Stat::new(
+            "steps",
+            movements.iter().map(|m| m.count as u64).sum::<u64>(),
+        ),
+        Stat::new("head x", range(xs)),
+        Stat::new("head y", range(ys)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = stats("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2").expect("should return result");
+        assert_eq!(
+            vec![
+                Stat::new("moves", 8),
+                Stat::new("steps", 24),
+                Stat::new("head x", "0 to 5"),
+                Stat::new("head y", "0 to 4"),
+            ],
+            stats
+        );
+    }
+}
@@ -0,0 +1,125 @@
+//! Animation of the rope knots following the head.
+
+use crate::simulation::Simulation;
+use crate::solution::day_09::{parse_input, Coordinates, Rope, RopeSimulation};
+use crate::visualize::Animation;
+
+/// Animates the rope one unit of head movement at a time.
+pub(crate) struct RopeAnimation {
+    simulation: RopeSimulation,
+}
+
+impl RopeAnimation {
+    /// Creates the animation for a rope with the given number of knots after the head.
+    pub(crate) fn new(input: &str, knot_count: u32) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            simulation: RopeSimulation::new(Rope::new(knot_count)?, parse_input(input)?),
+        })
+    }
+
+    /// Chooses the character for a square, with knots nearer the head drawn on top.
+    fn square(&self, coordinates: &Coordinates) -> char {
+        let rope = self.simulation.rope();
+        if let Some(i) = rope.knots.iter().position(|k| k == coordinates) {
+            return match (i, rope.knots.len()) {
+                (0, _) => 'H',
+                (_, 2) => 'T',
+                _ => char::from_digit(i as u32 % 10, 10).unwrap_or('?'),
+            };
+        }
+        if *coordinates == Coordinates::new() {
+            's'
+        } else if rope.visited.contains_key(coordinates) {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+impl Animation for RopeAnimation {
+    fn step(&mut self) -> bool {
+        if self.simulation.is_done() {
+            return false;
+        }
+        // moving the rope can't fail
+        self.simulation.step().is_ok()
+    }
+
+    fn status(&self) -> String {
+        // count the movement being made along with the finished ones
+        let moves = match self.simulation.progress {
+            0 => self.simulation.finished_moves(),
+            _ => self.simulation.finished_moves() + 1,
+        };
+        format!(
+            "Move {}/{} | squares visited by the tail: {}",
+            moves,
+            self.simulation.total_moves(),
+            self.simulation.rope().tail_visit_count()
+        )
+    }
+
+    fn render(&self, width: usize, height: usize) -> Vec<String> {
+        // keep the head in the middle of the view
+        let head = self.simulation.rope().knots[0];
+        let left = head.x - width as i32 / 2;
+        let top = head.y + height as i32 / 2;
+        let mut lines = Vec::new();
+        for row in 0..height as i32 {
+            let mut line = String::new();
+            for column in 0..width as i32 {
+                line.push(self.square(&Coordinates {
+                    x: left + column,
+                    y: top - row,
+                }));
+            }
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    #[test]
+    fn test_step() {
+        let mut animation = RopeAnimation::new(INPUT, 1).expect("should return result");
+        assert!(animation.step());
+        assert!(animation.step());
+        assert_eq!(
+            "Move 1/8 | squares visited by the tail: 2",
+            animation.status()
+        );
+        assert_eq!(vec![".....", "sTH..", "....."], animation.render(5, 3));
+        while animation.step() {}
+        assert_eq!(
+            "Move 8/8 | squares visited by the tail: 13",
+            animation.status()
+        );
+    }
+
+    #[test]
+    fn test_render_long_rope() {
+        let mut animation = RopeAnimation::new(INPUT, 9).expect("should return result");
+        for _ in 0..8 {
+            animation.step();
+        }
+        // after R 4 and U 4
+        assert_eq!(
+            vec![".....", ".....", "..H..", "..1..", "432.."],
+            animation.render(5, 5)
+        );
+    }
+}
@@ -0,0 +1,46 @@
+//! Structural checks of CRT programs.
+
+use crate::check::Problem;
+use crate::solution::day_10::Instruction;
+
+const SCREEN_PIXELS: u32 = 240;
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut cycles = 0;
+    for (i, line) in input.split('\n').enumerate() {
+        match Instruction::new(line) {
+            Ok(Instruction::Noop) => cycles += 1,
+            Ok(Instruction::Addx(_)) => cycles += 2,
+            Err(e) => problems.push(Problem::at(i + 1, format!("{:#}", e))),
+        }
+    }
+    if cycles > SCREEN_PIXELS {
+        problems.push(Problem::general(format!(
+            "program runs for {} cycles, but the CRT only has {} pixels",
+            cycles, SCREEN_PIXELS
+        )));
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("noop\naddx 3\naddx -5").is_empty());
+        assert_eq!(
+            vec![Problem::at(2, "input is not an instruction")],
+            check("noop\nadd 3")
+        );
+        let long = vec!["addx 1"; 121].join("\n");
+        assert_eq!(
+            vec![Problem::general(
+                "program runs for 242 cycles, but the CRT only has 240 pixels"
+            )],
+            check(&long)
+        );
+    }
+}
@@ -0,0 +1,30 @@
+//! Random CRT programs.
+
+use crate::generate::Rng;
+
+/// Width of the CRT, which the sprite is kept within.
+const CRT_WIDTH: i64 = 40;
+
+/// Generates a program that takes exactly `cycles` cycles to run.
+///
+/// The CRT only has 240 pixels, so longer programs would draw past its last row. The register is
+/// kept on the screen so that the sprite is actually drawn.
+pub(crate) fn generate(rng: &mut Rng, cycles: usize) -> String {
+    let mut register = 1;
+    let mut remaining = cycles;
+    let mut lines = Vec::new();
+    while remaining > 0 {
+        if remaining >= 2 && rng.chance(0.6) {
+            let low = (-register).max(-15);
+            let high = (CRT_WIDTH - 1 - register).min(15);
+            let value = low + rng.range(0..=(high - low) as u64) as i64;
+            register += value;
+            lines.push(format!("addx {}", value));
+            remaining -= 2;
+        } else {
+            lines.push("noop".to_string());
+            remaining -= 1;
+        }
+    }
+    lines.join("\n")
+}
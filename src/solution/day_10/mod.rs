@@ -1,13 +1,46 @@
-// TODO: docs
+//! Solvers for day 10.
+//!
+//! The parts of day 10 run a program of [Instruction]s on a handheld [Device], whose single
+//! register sets the signal strength sampled for part 1 and the sprite position that the CRT draws
+//! for part 2.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc::day_10::{Device, Instruction};
+//!
+//! let mut device = Device::new();
+//! for line in ["noop", "addx 3", "addx -5"] {
+//!     device.execute_instruction(&line.parse::<Instruction>().unwrap()).unwrap();
+//! }
+//! assert_eq!(6, device.cycle());
+//! assert_eq!(-1, device.register());
+//! assert!(device.display().starts_with("#####."));
+//! ```
+//!
+//! A [DeviceSimulation] runs a whole program one cycle at a time instead, so the device can be
+//! inspected in the middle of an instruction.
 
+use crate::error::{Categorize, ErrorKind};
+use crate::simulation::Simulation;
 use anyhow::{anyhow, Context};
+use std::io::BufRead;
+use std::str::FromStr;
 
+pub(crate) mod check;
+pub(crate) mod generate;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod stats;
+pub(crate) mod visualize;
 
+/// An instruction for the device's CPU.
 #[derive(Debug, PartialEq, Eq)]
-enum Instruction {
+#[non_exhaustive]
+pub enum Instruction {
+    /// Takes one cycle and does nothing.
     Noop,
+    /// Takes two cycles, then adds the value to the register.
     Addx(i32),
 }
 
@@ -27,17 +60,34 @@ impl Instruction {
         }
         Err(anyhow!("input is not an instruction"))
     }
+
+    /// Returns the number of cycles the instruction takes and how it changes the register.
+    fn effect(&self) -> (u32, i32) {
+        match self {
+            Instruction::Noop => (1, 0),
+            Instruction::Addx(count) => (2, *count),
+        }
+    }
 }
 
-struct Device {
+/// The handheld device, tracking its CPU and what its CRT has drawn.
+#[derive(Debug)]
+pub struct Device {
     cycle: u32,
     register: i32,
     signal_strength: i32,
     display: Vec<Vec<char>>,
 }
 
+impl Default for Device {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Device {
-    pub(crate) fn new() -> Self {
+    /// Creates a device at its first cycle, with the register set to 1 and a dark screen.
+    pub fn new() -> Self {
         Device {
             cycle: 1,
             register: 1,
@@ -46,33 +96,51 @@ impl Device {
         }
     }
 
-    pub(crate) fn execute_program(&mut self, instructions: &Vec<Instruction>, for_cycles: u32) {
-        for instruction in instructions {
-            self.execute_instruction(instruction);
-            if self.cycle > for_cycles {
-                break;
-            }
+    /// Runs an instruction, drawing a pixel and sampling the signal strength on each of its cycles.
+    ///
+    /// Returns an error if the register or the signal strength grows too large for an `i32`.
+    pub fn execute_instruction(&mut self, instruction: &Instruction) -> Result<(), anyhow::Error> {
+        let (cycles, register_update) = instruction.effect();
+        for _ in 0..cycles {
+            self.tick()?;
         }
+        self.update_register(register_update)
     }
 
-    pub(crate) fn execute_instruction(&mut self, instruction: &Instruction) {
-        let (cycles, register_update) = match instruction {
-            Instruction::Noop => (1, 0),
-            Instruction::Addx(count) => (2, *count),
-        };
-        for _ in 0..cycles {
-            self.update_display();
-            self.update_signal_strength();
-            self.cycle += 1;
-        }
-        self.register += register_update;
+    pub(crate) fn tick(&mut self) -> Result<(), anyhow::Error> {
+        self.update_display();
+        self.update_signal_strength()?;
+        self.cycle += 1;
+        Ok(())
+    }
+
+    fn update_register(&mut self, register_update: i32) -> Result<(), anyhow::Error> {
+        self.register = self
+            .register
+            .checked_add(register_update)
+            .ok_or_else(|| anyhow!("register is too large on cycle {}", self.cycle))
+            .categorize(ErrorKind::Simulation)?;
+        Ok(())
     }
 
-    pub(crate) fn get_signal_strength(&self) -> i32 {
+    /// Returns the cycle the device is on, counting from 1.
+    pub fn cycle(&self) -> u32 {
+        self.cycle
+    }
+
+    /// Returns the value of the register.
+    pub fn register(&self) -> i32 {
+        self.register
+    }
+
+    /// Returns the sum of the signal strengths sampled so far, on the 20th cycle and every 40th
+    /// cycle after it.
+    pub fn signal_strength(&self) -> i32 {
         self.signal_strength
     }
 
-    pub(crate) fn get_display(&self) -> String {
+    /// Returns the six rows of the CRT, with `#` for lit pixels and `.` for dark ones.
+    pub fn display(&self) -> String {
         let mut display = String::new();
         for row in &self.display {
             for c in row {
@@ -83,24 +151,99 @@ impl Device {
         display
     }
 
-    fn update_signal_strength(&mut self) {
-        if self.cycle > 19 && (self.cycle == 20 || (self.cycle - 20) % 40 == 0) {
-            self.signal_strength += self.cycle as i32 * self.register
+    fn update_signal_strength(&mut self) -> Result<(), anyhow::Error> {
+        if self.cycle > 19 && (self.cycle == 20 || (self.cycle - 20).is_multiple_of(40)) {
+            self.signal_strength = i32::try_from(self.cycle)
+                .ok()
+                .and_then(|cycle| cycle.checked_mul(self.register))
+                .and_then(|strength| strength.checked_add(self.signal_strength))
+                .ok_or_else(|| anyhow!("signal strength is too large on cycle {}", self.cycle))
+                .categorize(ErrorKind::Simulation)?;
         }
+        Ok(())
     }
 
     fn update_display(&mut self) {
         let mut row = self.cycle / 40;
-        if self.cycle % 40 == 0 {
+        if self.cycle.is_multiple_of(40) {
             row -= 1;
         }
         let col = (self.cycle - 1) % 40;
-        if (col as i32 - self.register).abs() <= 1 {
+        // the screen is full after 240 cycles
+        if row as usize >= self.display.len() {
+            return;
+        }
+        if (col as i32).abs_diff(self.register) <= 1 {
             self.display[row as usize][col as usize] = '#';
         }
     }
 }
 
+impl FromStr for Instruction {
+    type Err = anyhow::Error;
+
+    /// Parses an instruction written as `noop` or `addx <value>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Instruction::new(s)
+    }
+}
+
+/// Runs a program on a [Device], one cycle per step, until every instruction has completed.
+///
+/// The state is the cycle the device is on and the value of its register.
+#[derive(Debug)]
+pub struct DeviceSimulation {
+    device: Device,
+    instructions: Vec<Instruction>,
+    /// Index of the instruction being run.
+    next: usize,
+    /// Cycles the current instruction has already taken.
+    progress: u32,
+}
+
+impl DeviceSimulation {
+    pub fn new(device: Device, instructions: Vec<Instruction>) -> Self {
+        Self {
+            device,
+            instructions,
+            next: 0,
+            progress: 0,
+        }
+    }
+
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+}
+
+impl Simulation for DeviceSimulation {
+    type State = (u32, i32);
+
+    fn step(&mut self) -> Result<(), anyhow::Error> {
+        let (cycles, register_update) = match self.instructions.get(self.next) {
+            Some(instruction) => instruction.effect(),
+            None => return Ok(()),
+        };
+        self.device.tick()?;
+        self.progress += 1;
+        // the register only changes once the instruction has completed
+        if self.progress == cycles {
+            self.device.update_register(register_update)?;
+            self.next += 1;
+            self.progress = 0;
+        }
+        Ok(())
+    }
+
+    fn is_done(&self) -> bool {
+        self.next >= self.instructions.len()
+    }
+
+    fn state(&self) -> Self::State {
+        (self.device.cycle, self.device.register)
+    }
+}
+
 fn parse_input(input: &str) -> Result<Vec<Instruction>, anyhow::Error> {
     let mut instructions = Vec::new();
     for line in input.split('\n') {
@@ -109,6 +252,28 @@ fn parse_input(input: &str) -> Result<Vec<Instruction>, anyhow::Error> {
     Ok(instructions)
 }
 
+/// Runs a program on a new [Device] while reading it a line at a time, until the given number of
+/// cycles have passed.
+///
+/// The rest of the program is still read, so an invalid instruction is reported wherever it is.
+fn stream_device(reader: &mut dyn BufRead, cycles: u32) -> Result<Device, anyhow::Error> {
+    let mut device = Device::new();
+    crate::stream::for_each_line(reader, |line| {
+        let instruction = Instruction::new(line)?;
+        if device.cycle() <= cycles {
+            device.execute_instruction(&instruction)?;
+        }
+        Ok(())
+    })?;
+    Ok(device)
+}
+
+/// Parses the input for both parts without solving them.
+pub(crate) fn parse(input: &str) -> Result<(), anyhow::Error> {
+    parse_input(input)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -280,9 +445,12 @@ noop
 noop
 noop";
         let instructions = parse_input(input).expect("should return result");
-        let mut device = Device::new();
-        device.execute_program(&instructions, 220);
-        assert_eq!(13140, device.get_signal_strength());
+        let mut simulation = DeviceSimulation::new(Device::new(), instructions);
+        simulation
+            .run_until(|s| s.device().cycle() > 220)
+            .expect("should return result");
+        let device = simulation.device();
+        assert_eq!(13140, device.signal_strength());
         let gt = "
 ##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
@@ -290,6 +458,65 @@ noop";
 #####.....#####.....#####.....#####.....
 ######......######......######......####
 #######.......######....................\n";
-        assert_eq!(gt, format!("\n{}", device.get_display()));
+        assert_eq!(gt, format!("\n{}", device.display()));
+    }
+
+    #[test]
+    fn test_device_past_last_pixel() {
+        let mut device = Device::new();
+        for _ in 0..300 {
+            device
+                .execute_instruction(&Instruction::Noop)
+                .expect("should return result");
+        }
+        assert_eq!(301, device.cycle());
+        assert_eq!(6, device.display().lines().count());
+    }
+
+    #[test]
+    fn test_device_simulation() {
+        let instructions = parse_input("noop\naddx 3\naddx -5").expect("should return result");
+        let mut simulation = DeviceSimulation::new(Device::new(), instructions);
+        assert_eq!((1, 1), simulation.state());
+        simulation.step().expect("should return result");
+        simulation.step().expect("should return result");
+        // addx 3 is halfway done, so the register hasn't changed yet
+        assert_eq!((3, 1), simulation.state());
+        simulation.step().expect("should return result");
+        assert_eq!((4, 4), simulation.state());
+        assert_eq!(2, simulation.run().expect("should return result"));
+        assert!(simulation.is_done());
+        assert_eq!((6, -1), simulation.state());
+    }
+
+    #[test]
+    fn test_device_overflow() {
+        // these used to overflow an i32 and panic
+        let mut device = Device::new();
+        let error = device
+            .execute_instruction(&Instruction::Addx(i32::MAX))
+            .expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Simulation), ErrorKind::of(&error));
+        assert_eq!("register is too large on cycle 3", error.to_string());
+        let instructions =
+            parse_input(&["addx 100000000"; 10].join("\n")).expect("should return result");
+        let mut simulation = DeviceSimulation::new(Device::new(), instructions);
+        let error = simulation.run().expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Simulation), ErrorKind::of(&error));
+        assert_eq!(
+            "signal strength is too large on cycle 20",
+            error.to_string()
+        );
+        // the smallest register fits, but its distance from a pixel used to overflow
+        let mut device = Device::new();
+        for instruction in
+            parse_input("addx -2147483648\naddx -1\nnoop").expect("should return result")
+        {
+            device
+                .execute_instruction(&instruction)
+                .expect("should return result");
+        }
+        assert_eq!(i32::MIN, device.register());
+        assert!(device.display().starts_with("##...."));
     }
 }
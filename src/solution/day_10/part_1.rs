@@ -1,12 +1,20 @@
 // TODO: docs
 
-use crate::solution::day_10::{parse_input, Device};
+use crate::simulation::Simulation;
+use crate::solution::day_10::{parse_input, stream_device, Device, DeviceSimulation};
+use std::io::BufRead;
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     let instructions = parse_input(input)?;
-    let mut device = Device::new();
-    device.execute_program(&instructions, 220);
-    Ok(device.get_signal_strength().to_string())
+    crate::metrics::parsed();
+    let mut simulation = DeviceSimulation::new(Device::new(), instructions);
+    simulation.run_until(|s| s.device().cycle() > 220)?;
+    Ok(simulation.device().signal_strength().to_string())
+}
+
+/// Sums the signal strengths the same way as [solve], reading the input a line at a time.
+pub(crate) fn solve_reader(reader: &mut dyn BufRead) -> Result<String, anyhow::Error> {
+    Ok(stream_device(reader, 220)?.signal_strength().to_string())
 }
 
 #[cfg(test)]
@@ -162,5 +170,9 @@ noop
 noop
 noop";
         assert_eq!("13140", solve(input).expect("should return result"));
+        assert_eq!(
+            "13140",
+            solve_reader(&mut input.as_bytes()).expect("should return result")
+        );
     }
 }
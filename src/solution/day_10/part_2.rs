@@ -1,12 +1,21 @@
 // TODO: docs
 
-use crate::solution::day_10::{parse_input, Device};
+use crate::simulation::Simulation;
+use crate::solution::day_10::{parse_input, stream_device, Device, DeviceSimulation};
+use std::io::BufRead;
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     let instructions = parse_input(input)?;
-    let mut device = Device::new();
-    device.execute_program(&instructions, 240);
-    let display = device.get_display();
+    crate::metrics::parsed();
+    let mut simulation = DeviceSimulation::new(Device::new(), instructions);
+    simulation.run_until(|s| s.device().cycle() > 240)?;
+    let display = simulation.device().display();
+    Ok(format!("\n{}", display))
+}
+
+/// Draws the display the same way as [solve], reading the input a line at a time.
+pub(crate) fn solve_reader(reader: &mut dyn BufRead) -> Result<String, anyhow::Error> {
+    let display = stream_device(reader, 240)?.display();
     Ok(format!("\n{}", display))
 }
 
@@ -170,5 +179,9 @@ noop";
 ######......######......######......####
 #######.......#######.......#######.....\n";
         assert_eq!(gt, solve(input).expect("should return result"));
+        assert_eq!(
+            gt,
+            solve_reader(&mut input.as_bytes()).expect("should return result")
+        );
     }
 }
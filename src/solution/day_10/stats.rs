@@ -0,0 +1,38 @@
+//! Statistics of CRT programs.
+
+use crate::solution::day_10::{parse_input, Instruction};
+use crate::stats::Stat;
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    let instructions = parse_input(input)?;
+    let adds = instructions
+        .iter()
+        .filter(|i| matches!(i, Instruction::Addx(_)))
+        .count();
+    let noops = instructions.len() - adds;
+    Ok(vec![
+        Stat::new("instructions", instructions.len()),
+        Stat::new("addx", adds),
+        Stat::new("noop", noops),
+        Stat::new("cycles", adds * 2 + noops),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = stats("noop\naddx 3\naddx -5").expect("should return result");
+        assert_eq!(
+            vec![
+                Stat::new("instructions", 3),
+                Stat::new("addx", 2),
+                Stat::new("noop", 1),
+                Stat::new("cycles", 5),
+            ],
+            stats
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! Animation of the CRT being drawn cycle by cycle.
+
+use crate::simulation::Simulation;
+use crate::solution::day_10::{parse_input, Device, DeviceSimulation};
+use crate::visualize::Animation;
+
+/// Width of the CRT in pixels.
+const CRT_WIDTH: u32 = 40;
+/// Number of cycles needed to draw every pixel of the CRT.
+const CRT_CYCLES: u32 = 240;
+
+pub(crate) struct CrtAnimation {
+    simulation: DeviceSimulation,
+}
+
+impl CrtAnimation {
+    pub(crate) fn new(input: &str) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            simulation: DeviceSimulation::new(Device::new(), parse_input(input)?),
+        })
+    }
+}
+
+impl Animation for CrtAnimation {
+    fn step(&mut self) -> bool {
+        // the CRT has no more rows to draw on
+        if self.simulation.is_done() || self.simulation.device().cycle > CRT_CYCLES {
+            return false;
+        }
+        // running the program can't fail
+        self.simulation.step().is_ok()
+    }
+
+    fn status(&self) -> String {
+        let device = self.simulation.device();
+        format!(
+            "Cycle {}/{} | X={} | signal strength: {}",
+            (device.cycle - 1).min(CRT_CYCLES),
+            CRT_CYCLES,
+            device.register,
+            device.signal_strength()
+        )
+    }
+
+    fn render(&self, _width: usize, _height: usize) -> Vec<String> {
+        let device = self.simulation.device();
+        let mut lines = device
+            .display()
+            .lines()
+            .map(String::from)
+            .collect::<Vec<String>>();
+        // show the sprite and the pixel that will be drawn next
+        let sprite = (0..CRT_WIDTH as i32)
+            .map(|x| match x.abs_diff(device.register) <= 1 {
+                true => '#',
+                false => '.',
+            })
+            .collect::<String>();
+        lines.push(String::new());
+        lines.push(sprite);
+        if device.cycle <= CRT_CYCLES {
+            let column = (device.cycle - 1) % CRT_WIDTH;
+            lines.push(format!("{}^", " ".repeat(column as usize)));
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let mut animation =
+            CrtAnimation::new("noop\naddx 3\naddx -5").expect("should return result");
+        assert!(animation.step());
+        assert!(animation.step());
+        // addx 3 is halfway done, so the register hasn't changed yet
+        assert_eq!("Cycle 2/240 | X=1 | signal strength: 0", animation.status());
+        assert!(animation.step());
+        assert_eq!("Cycle 3/240 | X=4 | signal strength: 0", animation.status());
+        assert!(animation.step());
+        assert!(animation.step());
+        assert!(!animation.step());
+        let lines = animation.render(80, 24);
+        assert_eq!("#####...................................", lines[0]);
+        assert_eq!("#.......................................", lines[7]);
+        assert_eq!("     ^", lines[8]);
+    }
+
+    #[test]
+    fn test_render_smallest_register() {
+        let mut animation =
+            CrtAnimation::new("addx -2147483648\naddx -1\nnoop").expect("should return result");
+        while animation.step() {}
+        // the sprite is far off the screen, which used to overflow
+        let lines = animation.render(80, 24);
+        assert_eq!(".".repeat(40), lines[7]);
+    }
+}
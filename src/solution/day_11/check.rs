@@ -0,0 +1,94 @@
+//! Structural checks of monkey notes.
+
+use crate::check::Problem;
+use crate::solution::day_11::{Monkey, Monkeys};
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut line = 1;
+    let mut monkeys = Vec::new();
+    for (i, block) in input.split("\n\n").enumerate() {
+        let header = block.split('\n').next().unwrap_or_default();
+        if header != format!("Monkey {}:", i) {
+            problems.push(Problem::at(
+                line,
+                format!("expected \"Monkey {}:\" but found: {}", i, header),
+            ));
+        }
+        match Monkey::new(block, None) {
+            Ok(monkey) => monkeys.push((line, i, monkey)),
+            Err(e) => problems.push(Problem::at(line, format!("monkey {}: {:#}", i, e))),
+        }
+        line += block.split('\n').count() + 1;
+    }
+    let count = input.split("\n\n").count();
+    for (start, i, monkey) in &monkeys {
+        let targets = [
+            (start + 4, monkey.decision.true_target),
+            (start + 5, monkey.decision.false_target),
+        ];
+        for (target_line, target) in targets {
+            if target >= count {
+                problems.push(Problem::at(
+                    target_line,
+                    format!(
+                        "monkey {} throws to monkey {}, but there are only {} monkeys",
+                        i, target, count
+                    ),
+                ));
+            } else if target == *i {
+                problems.push(Problem::at(
+                    target_line,
+                    format!("monkey {} throws to itself", i),
+                ));
+            }
+        }
+    }
+    // problems with the monkeys as a group, such as divisors that multiply past the worry bound
+    if monkeys.len() == count {
+        if let Err(e) = Monkeys::new(input, None) {
+            problems.push(Problem::general(format!("{:#}", e)));
+        }
+    }
+    problems.sort_by_key(|problem| problem.line);
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MONKEY_0: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 1
+    If false: throw to monkey 2";
+    const MONKEY_1: &str = "Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 1
+    If false: throw to monkey 0";
+
+    #[test]
+    fn test_check() {
+        assert_eq!(
+            vec![
+                Problem::at(
+                    6,
+                    "monkey 0 throws to monkey 2, but there are only 2 monkeys"
+                ),
+                Problem::at(12, "monkey 1 throws to itself"),
+            ],
+            check(&format!("{}\n\n{}", MONKEY_0, MONKEY_1))
+        );
+        assert_eq!(
+            vec![
+                Problem::at(1, "expected \"Monkey 0:\" but found: Monkey 1:"),
+                Problem::at(1, "monkey 0: invalid number of lines for monkey"),
+            ],
+            check("Monkey 1:\n  Starting items: 1")
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Random monkey notes.
+
+use crate::generate::Rng;
+
+/// Divisors for the monkeys' tests, which must be different primes.
+///
+/// Worry levels are kept below the product of every divisor, and squaring one has to fit in a
+/// `u64`, so the product of the divisors can't go past `u32::MAX`. That allows at most nine.
+const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+/// Generates the notes for `monkeys` monkeys.
+///
+/// Part 1 has to keep exact worry levels, which squaring can quickly make too large for a `u64`,
+/// so notes are drawn again until they stay small enough for the 20 rounds of part 1.
+pub(crate) fn generate(rng: &mut Rng, monkeys: usize) -> String {
+    loop {
+        let notes = notes(rng, monkeys);
+        if super::part_1::solve(&notes).is_ok() {
+            return notes;
+        }
+    }
+}
+
+fn notes(rng: &mut Rng, monkeys: usize) -> String {
+    let mut divisors = DIVISORS;
+    rng.shuffle(&mut divisors);
+    let mut notes = Vec::new();
+    for (i, divisor) in divisors.iter().take(monkeys).enumerate() {
+        let items = (0..rng.range(1..=8))
+            .map(|_| rng.range(50..=99).to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        let operation = match rng.range(0..=9) {
+            0 => "old * old".to_string(),
+            1..=4 => format!("old * {}", rng.range(2..=19)),
+            _ => format!("old + {}", rng.range(1..=8)),
+        };
+        let true_target = other_monkey(rng, monkeys, &[i]);
+        let false_target = match monkeys {
+            2 => true_target,
+            _ => other_monkey(rng, monkeys, &[i, true_target]),
+        };
+        notes.push(format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+            i, items, operation, divisor, true_target, false_target
+        ));
+    }
+    notes.join("\n\n")
+}
+
+/// Picks a monkey that isn't one of the excluded monkeys.
+fn other_monkey(rng: &mut Rng, monkeys: usize, excluded: &[usize]) -> usize {
+    let choices = (0..monkeys)
+        .filter(|m| !excluded.contains(m))
+        .collect::<Vec<usize>>();
+    *rng.choose(&choices)
+}
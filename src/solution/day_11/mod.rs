@@ -1,20 +1,63 @@
-// TODO: docs
+//! Solvers for day 11.
+//!
+//! The parts of day 11 simulate [Monkeys] throwing items to each other based on how worried the
+//! owner is about each item, then multiply the number of items the two busiest monkeys inspected.
+//! Part 1 plays 20 rounds with the worry divided by 3 after each inspection, while part 2 plays
+//! 10000 rounds without any relief.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc::day_11::Monkeys;
+//!
+//! let input = "Monkey 0:
+//!   Starting items: 79, 98
+//!   Operation: new = old * 19
+//!   Test: divisible by 23
+//!     If true: throw to monkey 1
+//!     If false: throw to monkey 1
+//!
+//! Monkey 1:
+//!   Starting items: 54
+//!   Operation: new = old + 6
+//!   Test: divisible by 19
+//!     If true: throw to monkey 0
+//!     If false: throw to monkey 0";
+//! let mut monkeys = Monkeys::new(input, Some(3)).unwrap();
+//! monkeys.execute_rounds(1).unwrap();
+//! assert_eq!(vec![2, 3], monkeys.inspections());
+//! assert_eq!(6, monkeys.monkey_business());
+//! ```
+//!
+//! A [MonkeySimulation] plays the same rounds one at a time, stopping after a set number of rounds.
 
+use crate::error::{Categorize, ErrorKind};
+use crate::simulation::Simulation;
 use anyhow::{anyhow, Context};
+use std::str::FromStr;
 
+pub(crate) mod check;
+pub(crate) mod generate;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod reference;
+pub(crate) mod repl;
+pub(crate) mod stats;
+pub(crate) mod visualize;
 
+#[derive(Debug)]
 enum Operator {
     Add,
     Multiply,
 }
 
+#[derive(Debug)]
 enum Other {
     Old,
     Value(u64),
 }
 
+#[derive(Debug)]
 struct Operation {
     operator: Operator,
     other: Other,
@@ -43,18 +86,20 @@ impl Operation {
         Ok(Self { operator, other })
     }
 
-    pub(crate) fn perform(&self, item: u64) -> u64 {
+    /// Returns the new worry level, or `None` if it doesn't fit in a `u64`.
+    pub(crate) fn perform(&self, item: u64) -> Option<u64> {
         let value = match self.other {
             Other::Old => item,
             Other::Value(val) => val,
         };
         match self.operator {
-            Operator::Add => item + value,
-            Operator::Multiply => item * value,
+            Operator::Add => item.checked_add(value),
+            Operator::Multiply => item.checked_mul(value),
         }
     }
 }
 
+#[derive(Debug)]
 struct Decision {
     value: u64,
     true_target: usize,
@@ -69,21 +114,24 @@ impl Decision {
         }
         let value_str = parts[0]
             .split(' ')
-            .last()
+            .next_back()
             .ok_or_else(|| anyhow!("could not find decision value in line: {}", parts[0]))?;
         let value = value_str
             .parse::<u64>()
             .with_context(|| format!("could not parse token into u64: {}", value_str))?;
+        if value == 0 {
+            return Err(anyhow!("cannot test for divisibility by zero"));
+        }
         let true_target_str = parts[1]
             .split(' ')
-            .last()
+            .next_back()
             .ok_or_else(|| anyhow!("could not find decision true target in line: {}", parts[1]))?;
         let true_target = true_target_str
             .parse::<usize>()
             .with_context(|| format!("could not parse token into usize: {}", true_target_str))?;
         let false_target_str = parts[2]
             .split(' ')
-            .last()
+            .next_back()
             .ok_or_else(|| anyhow!("could not find decision false target in line: {}", parts[2]))?;
         let false_target = false_target_str
             .parse::<usize>()
@@ -96,13 +144,14 @@ impl Decision {
     }
 
     pub(crate) fn decide(&self, item: u64) -> usize {
-        match item % self.value == 0 {
+        match item.is_multiple_of(self.value) {
             true => self.true_target,
             false => self.false_target,
         }
     }
 }
 
+#[derive(Debug)]
 struct Monkey {
     items: Vec<u64>,
     operation: Operation,
@@ -147,12 +196,17 @@ impl Monkey {
         })
     }
 
-    pub(crate) fn inspect_items(&mut self) {
+    pub(crate) fn inspect_items(&mut self) -> Result<(), anyhow::Error> {
         for i in 0..self.items.len() {
             self.inspections += 1;
-            self.items[i] = self.operation.perform(self.items[i]);
+            self.items[i] = self
+                .operation
+                .perform(self.items[i])
+                .ok_or_else(|| anyhow!("worry level is too large to keep track of"))
+                .categorize(ErrorKind::Simulation)?;
             self.items[i] /= self.reduce_worry;
         }
+        Ok(())
     }
 
     pub(crate) fn throw_items(&mut self) -> Vec<(usize, u64)> {
@@ -172,40 +226,81 @@ impl Monkey {
     }
 }
 
-struct Monkeys {
+/// A group of monkeys and the items they hold.
+#[derive(Debug)]
+pub struct Monkeys {
     monkeys: Vec<Monkey>,
     field_bound: u64,
 }
 
 impl Monkeys {
-    pub(crate) fn new(input: &str, reduce_worry: Option<u64>) -> Result<Self, anyhow::Error> {
+    /// Parses the notes on each monkey, which are separated by blank lines.
+    ///
+    /// After each inspection the worry level of an item is divided by `reduce_worry`, if it's
+    /// given. Returns an error categorized as [ErrorKind::Usage] if `reduce_worry` is 0.
+    pub fn new(input: &str, reduce_worry: Option<u64>) -> Result<Self, anyhow::Error> {
+        if reduce_worry == Some(0) {
+            return Err(anyhow!("worry relief must be at least 1")).categorize(ErrorKind::Usage);
+        }
         let mut monkeys = Vec::new();
         for monkey_input in input.split("\n\n") {
             monkeys.push(Monkey::new(monkey_input, reduce_worry)?);
         }
-        let field_bound = monkeys.iter().map(|m| m.decision.value).product::<u64>();
+        // worry levels are squared, so the bound they're kept under has to fit in half a u64
+        let field_bound = monkeys
+            .iter()
+            .try_fold(1u64, |bound, m| bound.checked_mul(m.decision.value))
+            .filter(|bound| *bound <= u32::MAX as u64)
+            .ok_or_else(|| anyhow!("product of the test divisors is too large"))?;
         Ok(Self {
             monkeys,
             field_bound,
         })
     }
 
-    pub(crate) fn execute_rounds(&mut self, rounds: u32) -> Result<(), anyhow::Error> {
+    /// Plays rounds in which every monkey in turn inspects and throws each of its items.
+    ///
+    /// Returns an error if a monkey throws to a monkey that does not exist, or if a worry level
+    /// grows too large for a `u64` while there is relief from worry.
+    pub fn execute_rounds(&mut self, rounds: u32) -> Result<(), anyhow::Error> {
         for _ in 0..rounds {
-            for i in 0..self.monkeys.len() {
-                self.monkeys[i].inspect_items();
-                for (target, item) in self.monkeys[i].throw_items() {
-                    if target >= self.monkeys.len() {
-                        return Err(anyhow!("tried to throw item to non-existent monkey"));
-                    }
-                    self.monkeys[target].catch_item(item % self.field_bound);
+            self.execute_round()?;
+        }
+        Ok(())
+    }
+
+    fn execute_round(&mut self) -> Result<(), anyhow::Error> {
+        for i in 0..self.monkeys.len() {
+            self.monkeys[i].inspect_items()?;
+            // dividing doesn't keep the remainder by the bound, so with relief from worry the
+            // exact worry levels have to be kept
+            let relieved = self.monkeys[i].reduce_worry != 1;
+            for (target, item) in self.monkeys[i].throw_items() {
+                if target >= self.monkeys.len() {
+                    return Err(anyhow!("tried to throw item to non-existent monkey"))
+                        .categorize(ErrorKind::Simulation);
                 }
+                self.monkeys[target].catch_item(match relieved {
+                    true => item,
+                    false => item % self.field_bound,
+                });
             }
         }
         Ok(())
     }
 
-    pub(crate) fn calculate_monkey_business(&self) -> u64 {
+    /// Returns the worry levels of the items a monkey holds, where monkeys are numbered from 0.
+    pub fn items(&self, monkey: usize) -> Option<&[u64]> {
+        self.monkeys.get(monkey).map(|m| m.items.as_slice())
+    }
+
+    /// Returns how many items each monkey has inspected.
+    pub fn inspections(&self) -> Vec<u64> {
+        self.monkeys.iter().map(|m| m.inspections).collect()
+    }
+
+    /// Multiplies the number of inspections of the two busiest monkeys.
+    pub fn monkey_business(&self) -> u64 {
         let mut inspections = self
             .monkeys
             .iter()
@@ -221,11 +316,157 @@ impl Monkeys {
     }
 }
 
-// TODO: add tests
+impl FromStr for Monkeys {
+    type Err = anyhow::Error;
+
+    /// Parses the notes on each monkey, without any relief from worry as in part 2.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Monkeys::new(s, None)
+    }
+}
+
+/// Plays a number of rounds of [Monkeys] passing items, one round per step.
+///
+/// The state is the worry levels of the items each monkey holds.
+#[derive(Debug)]
+pub struct MonkeySimulation {
+    monkeys: Monkeys,
+    round: u32,
+    rounds: u32,
+}
+
+impl MonkeySimulation {
+    pub fn new(monkeys: Monkeys, rounds: u32) -> Self {
+        Self {
+            monkeys,
+            round: 0,
+            rounds,
+        }
+    }
+
+    pub fn monkeys(&self) -> &Monkeys {
+        &self.monkeys
+    }
+
+    /// Returns how many rounds have been played.
+    pub fn round(&self) -> u32 {
+        self.round
+    }
+
+    /// Returns how many rounds are played in total.
+    pub fn rounds(&self) -> u32 {
+        self.rounds
+    }
+}
+
+impl Simulation for MonkeySimulation {
+    type State = Vec<Vec<u64>>;
+
+    fn step(&mut self) -> Result<(), anyhow::Error> {
+        if self.is_done() {
+            return Ok(());
+        }
+        // the round is over even if it failed part of the way through
+        self.round += 1;
+        self.monkeys.execute_round()
+    }
+
+    fn is_done(&self) -> bool {
+        self.round >= self.rounds
+    }
+
+    fn state(&self) -> Self::State {
+        self.monkeys
+            .monkeys
+            .iter()
+            .map(|m| m.items.clone())
+            .collect()
+    }
+}
+
+/// Parses the input for both parts without solving them.
+pub(crate) fn parse(input: &str) -> Result<(), anyhow::Error> {
+    Monkeys::new(input, None)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_stuff() {}
+    fn test_monkeys_new() {
+        let input = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 54
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+        let monkeys = Monkeys::new(input, None).expect("should return result");
+        assert_eq!(Some(&[79, 98][..]), monkeys.items(0));
+        assert_eq!(Some(&[54][..]), monkeys.items(1));
+        assert_eq!(None, monkeys.items(2));
+        assert_eq!(vec![0, 0], monkeys.inspections());
+        assert_eq!(23 * 19, monkeys.field_bound);
+        // dividing by zero used to panic once the monkeys inspected their items
+        let error = Monkeys::new(input, Some(0)).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+        assert_eq!("worry relief must be at least 1", error.to_string());
+    }
+
+    #[test]
+    fn test_monkeys_invalid_divisors() {
+        let monkey = |divisor: u64| {
+            format!(
+                "Monkey 0:
+  Starting items: 79
+  Operation: new = old * 19
+  Test: divisible by {}
+    If true: throw to monkey 0
+    If false: throw to monkey 0",
+                divisor
+            )
+        };
+        let error = Monkeys::new(&monkey(0), None).expect_err("should return error");
+        assert_eq!("cannot test for divisibility by zero", error.to_string());
+        // multiplying the divisors together used to overflow
+        let input = [monkey(u64::MAX), monkey(u64::MAX)].join("\n\n");
+        let error = Monkeys::new(&input, None).expect_err("should return error");
+        assert_eq!(
+            "product of the test divisors is too large",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_monkey_simulation() {
+        let input = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 54
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 0
+    If false: throw to monkey 2";
+        let monkeys = Monkeys::new(input, Some(3)).expect("should return result");
+        let mut simulation = MonkeySimulation::new(monkeys, 3);
+        assert_eq!(vec![vec![79, 98], vec![54]], simulation.state());
+        // monkey 0 throws both items to monkey 1, which throws all three to a missing monkey
+        let error = simulation.step().expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Simulation), ErrorKind::of(&error));
+        assert_eq!(1, simulation.round());
+        assert_eq!(vec![Vec::<u64>::new(), Vec::new()], simulation.state());
+    }
 }
@@ -1,11 +1,14 @@
 // TODO: docs
 
-use crate::solution::day_11::Monkeys;
+use crate::simulation::Simulation;
+use crate::solution::day_11::{MonkeySimulation, Monkeys};
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
-    let mut monkeys = Monkeys::new(input, Some(3))?;
-    monkeys.execute_rounds(20)?;
-    Ok(monkeys.calculate_monkey_business().to_string())
+    let monkeys = Monkeys::new(input, Some(3))?;
+    crate::metrics::parsed();
+    let mut simulation = MonkeySimulation::new(monkeys, 20);
+    simulation.run()?;
+    Ok(simulation.monkeys().monkey_business().to_string())
 }
 
 #[cfg(test)]
@@ -43,4 +46,27 @@ Monkey 3:
     If false: throw to monkey 1";
         assert_eq!("10605", solve(input).expect("should return result"));
     }
+
+    #[test]
+    fn test_solve_too_large() {
+        // dividing by 3 doesn't keep remainders, so worry levels can't be kept below a bound
+        let input = "Monkey 0:
+  Starting items: 99
+  Operation: new = old * old
+  Test: divisible by 2
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items:
+  Operation: new = old * old
+  Test: divisible by 3
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+        let error = solve(input).expect_err("should return error");
+        assert_eq!(
+            Some(crate::error::ErrorKind::Simulation),
+            crate::error::ErrorKind::of(&error)
+        );
+    }
 }
@@ -1,11 +1,14 @@
 // TODO: docs
 
-use crate::solution::day_11::Monkeys;
+use crate::simulation::Simulation;
+use crate::solution::day_11::{MonkeySimulation, Monkeys};
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
-    let mut monkeys = Monkeys::new(input, None)?;
-    monkeys.execute_rounds(10000)?;
-    Ok(monkeys.calculate_monkey_business().to_string())
+    let monkeys = Monkeys::new(input, None)?;
+    crate::metrics::parsed();
+    let mut simulation = MonkeySimulation::new(monkeys, 10000);
+    simulation.run()?;
+    Ok(simulation.monkeys().monkey_business().to_string())
 }
 
 #[cfg(test)]
@@ -0,0 +1,173 @@
+//! A slow reference implementation of day 11, for differential testing.
+//!
+//! The solver keeps worry levels below the product of every monkey's divisor, which only works
+//! because the divisors are what the monkeys test. This implementation doesn't rely on that. Part
+//! 1 keeps the exact worry levels, and gives up if one no longer fits in a `u128`. Part 2 runs for
+//! too many rounds to do the same, so each item keeps its remainder by each monkey's divisor, which
+//! is all that monkey's test needs.
+
+use crate::error::{Categorize, ErrorKind};
+use crate::solution::day_11::{Monkey, Operation, Operator, Other};
+use anyhow::anyhow;
+
+fn parse_input(input: &str) -> Result<Vec<Monkey>, anyhow::Error> {
+    input
+        .split("\n\n")
+        .map(|monkey| Monkey::new(monkey, None))
+        .collect()
+}
+
+/// Plays the rounds, where inspecting an item gives its new worry level and whether it passed
+/// the monkey's test, and returns the monkey business.
+fn play<W>(
+    monkeys: &[Monkey],
+    mut items: Vec<Vec<W>>,
+    rounds: u32,
+    inspect: impl Fn(usize, W) -> Result<(W, bool), anyhow::Error>,
+) -> Result<String, anyhow::Error> {
+    let mut inspections = vec![0u64; monkeys.len()];
+    for _ in 0..rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                inspections[i] += 1;
+                let (item, passed) = inspect(i, item)?;
+                let target = match passed {
+                    true => monkey.decision.true_target,
+                    false => monkey.decision.false_target,
+                };
+                items
+                    .get_mut(target)
+                    .ok_or_else(|| anyhow!("tried to throw item to non-existent monkey"))
+                    .categorize(ErrorKind::Simulation)?
+                    .push(item);
+            }
+        }
+    }
+    inspections.sort();
+    Ok(inspections
+        .iter()
+        .rev()
+        .take(2)
+        .product::<u64>()
+        .to_string())
+}
+
+fn perform(operation: &Operation, old: u128) -> Option<u128> {
+    let other = match operation.other {
+        Other::Old => old,
+        Other::Value(value) => value as u128,
+    };
+    match operation.operator {
+        Operator::Add => old.checked_add(other),
+        Operator::Multiply => old.checked_mul(other),
+    }
+}
+
+pub(crate) fn part_1(input: &str) -> Result<String, anyhow::Error> {
+    let monkeys = parse_input(input)?;
+    let items = monkeys
+        .iter()
+        .map(|m| m.items.iter().map(|&item| item as u128).collect())
+        .collect();
+    play(&monkeys, items, 20, |i, item| {
+        let item = perform(&monkeys[i].operation, item)
+            .ok_or_else(|| anyhow!("worry level is too large to keep exactly"))
+            .categorize(ErrorKind::Simulation)?
+            / 3;
+        Ok((item, item % monkeys[i].decision.value as u128 == 0))
+    })
+}
+
+pub(crate) fn part_2(input: &str) -> Result<String, anyhow::Error> {
+    let monkeys = parse_input(input)?;
+    let divisors = monkeys
+        .iter()
+        .map(|m| m.decision.value as u128)
+        .collect::<Vec<u128>>();
+    // an item's remainder by each divisor, in the same order as the monkeys
+    let items = monkeys
+        .iter()
+        .map(|m| {
+            m.items
+                .iter()
+                .map(|&item| divisors.iter().map(|d| item as u128 % d).collect())
+                .collect()
+        })
+        .collect();
+    play(&monkeys, items, 10000, |i, item: Vec<u128>| {
+        let item = item
+            .iter()
+            .zip(&divisors)
+            .map(|(&remainder, d)| {
+                // the remainders are below the divisors, so this can't overflow
+                perform(&monkeys[i].operation, remainder).map(|worry| worry % d)
+            })
+            .collect::<Option<Vec<u128>>>()
+            .ok_or_else(|| anyhow!("worry level is too large to keep exactly"))
+            .categorize(ErrorKind::Simulation)?;
+        let passed = item[i] == 0;
+        Ok((item, passed))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!("10605", part_1(INPUT).expect("should return result"));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!("2713310158", part_2(INPUT).expect("should return result"));
+    }
+
+    #[test]
+    fn test_part_1_too_large() {
+        let input = "Monkey 0:
+  Starting items: 99
+  Operation: new = old * old
+  Test: divisible by 2
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items:
+  Operation: new = old * old
+  Test: divisible by 3
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+        let error = part_1(input).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Simulation), ErrorKind::of(&error));
+    }
+}
@@ -0,0 +1,224 @@
+//! Explorer for the monkeys passing items around.
+
+use crate::error::{Categorize, ErrorKind};
+use crate::repl::{arg, arg_or, unknown_command, Explorer};
+use crate::solution::day_11::{Monkey, Monkeys, Operator, Other};
+use anyhow::anyhow;
+
+const HELP: &str = "state                    print each monkey's items and inspection count
+monkey <monkey>          print a monkey's items and rules
+step [rounds]            play rounds and print the new state
+business                 print the monkey business so far
+reset [relief]           start over, dividing worry by relief after each inspection
+run <rounds> [relief]    start over and print the monkey business after playing rounds";
+
+/// Worry relief used by part 1, where worry is divided by three after each inspection.
+const PART_1_RELIEF: u64 = 3;
+
+pub(crate) struct MonkeyExplorer {
+    input: String,
+    monkeys: Monkeys,
+    relief: u64,
+    round: u32,
+}
+
+impl MonkeyExplorer {
+    pub(crate) fn new(input: &str) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            input: input.to_string(),
+            monkeys: Monkeys::new(input, Some(PART_1_RELIEF))?,
+            relief: PART_1_RELIEF,
+            round: 0,
+        })
+    }
+
+    fn reset(&mut self, relief: u64) -> Result<(), anyhow::Error> {
+        self.monkeys = Monkeys::new(&self.input, Some(relief))?;
+        self.relief = relief;
+        self.round = 0;
+        Ok(())
+    }
+
+    fn state(&self) -> String {
+        let mut lines = vec![format!(
+            "After round {} with worry relief {}:",
+            self.round, self.relief
+        )];
+        for (i, monkey) in self.monkeys.monkeys.iter().enumerate() {
+            let line = format!(
+                "Monkey {} ({} inspections): {}",
+                i,
+                monkey.inspections,
+                format_items(&monkey.items)
+            );
+            // monkeys without items would otherwise leave trailing whitespace
+            lines.push(line.trim_end().to_string());
+        }
+        lines.join("\n")
+    }
+}
+
+impl Explorer for MonkeyExplorer {
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String, anyhow::Error> {
+        match command {
+            "state" => Ok(self.state()),
+            "monkey" => {
+                let i = arg::<usize>(args, 0, "monkey")?;
+                match self.monkeys.monkeys.get(i) {
+                    Some(monkey) => Ok(describe(i, monkey)),
+                    None => Err(anyhow!("no such monkey: {}", i)).categorize(ErrorKind::Usage),
+                }
+            }
+            "step" => {
+                let rounds = arg_or(args, 0, "rounds", 1)?;
+                self.monkeys.execute_rounds(rounds)?;
+                self.round += rounds;
+                Ok(self.state())
+            }
+            "business" => Ok(self.monkeys.monkey_business().to_string()),
+            "reset" => {
+                let relief = arg_or(args, 0, "relief", PART_1_RELIEF)?;
+                self.reset(relief)?;
+                Ok(self.state())
+            }
+            "run" => {
+                let rounds = arg::<u32>(args, 0, "rounds")?;
+                let relief = arg_or(args, 1, "relief", PART_1_RELIEF)?;
+                self.reset(relief)?;
+                self.monkeys.execute_rounds(rounds)?;
+                self.round = rounds;
+                Ok(self.monkeys.monkey_business().to_string())
+            }
+            _ => Err(unknown_command(command)),
+        }
+    }
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+}
+
+/// Describes a monkey's items and rules in the same words as the puzzle input.
+fn describe(i: usize, monkey: &Monkey) -> String {
+    let operator = match monkey.operation.operator {
+        Operator::Add => "+",
+        Operator::Multiply => "*",
+    };
+    let other = match monkey.operation.other {
+        Other::Old => "old".to_string(),
+        Other::Value(value) => value.to_string(),
+    };
+    format!(
+        "Monkey {}:
+  Items: {}
+  Operation: new = old {} {}
+  Test: divisible by {}
+    If true: throw to monkey {}
+    If false: throw to monkey {}
+  Inspections: {}",
+        i,
+        format_items(&monkey.items),
+        operator,
+        other,
+        monkey.decision.value,
+        monkey.decision.true_target,
+        monkey.decision.false_target,
+        monkey.inspections
+    )
+}
+
+fn format_items(items: &[u64]) -> String {
+    items
+        .iter()
+        .map(u64::to_string)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explorer() -> MonkeyExplorer {
+        let input = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+        MonkeyExplorer::new(input).expect("should return result")
+    }
+
+    #[test]
+    fn test_step() {
+        let mut explorer = explorer();
+        let state = explorer.execute("step", &[]).expect("should return result");
+        assert_eq!(
+            "After round 1 with worry relief 3:
+Monkey 0 (2 inspections): 20, 23, 27, 26
+Monkey 1 (4 inspections): 2080, 25, 167, 207, 401, 1046
+Monkey 2 (3 inspections):
+Monkey 3 (5 inspections):",
+            state
+        );
+        explorer
+            .execute("step", &["19"])
+            .expect("should return result");
+        assert_eq!(
+            "10605",
+            explorer
+                .execute("business", &[])
+                .expect("should return result")
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let mut explorer = explorer();
+        assert_eq!(
+            "10605",
+            explorer
+                .execute("run", &["20"])
+                .expect("should return result")
+        );
+        assert_eq!(
+            "2713310158",
+            explorer
+                .execute("run", &["10000", "1"])
+                .expect("should return result")
+        );
+    }
+
+    #[test]
+    fn test_monkey() {
+        let mut explorer = explorer();
+        let description = explorer
+            .execute("monkey", &["2"])
+            .expect("should return result");
+        assert!(description.contains("Operation: new = old * old"));
+        assert!(description.contains("If true: throw to monkey 1"));
+        assert!(explorer.execute("monkey", &["4"]).is_err());
+        assert!(explorer.execute("reset", &["0"]).is_err());
+    }
+}
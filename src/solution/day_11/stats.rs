@@ -0,0 +1,52 @@
+//! Statistics of monkey notes.
+
+use crate::solution::day_11::Monkeys;
+use crate::stats::{range, Stat};
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    let monkeys = Monkeys::new(input, None)?;
+    let items = monkeys.monkeys.iter().map(|m| m.items.len());
+    Ok(vec![
+        Stat::new("monkeys", monkeys.monkeys.len()),
+        Stat::new("items", items.clone().sum::<usize>()),
+        Stat::new("items per monkey", range(items)),
+        Stat::new(
+            "test divisors",
+            range(monkeys.monkeys.iter().map(|m| m.decision.value)),
+        ),
+        Stat::new("divisor product", monkeys.field_bound),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let input = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+        let stats = stats(input).expect("should return result");
+        assert_eq!(
+            vec![
+                Stat::new("monkeys", 2),
+                Stat::new("items", 6),
+                Stat::new("items per monkey", "2 to 4"),
+                Stat::new("test divisors", "19 to 23"),
+                Stat::new("divisor product", 437),
+            ],
+            stats
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Animation of the monkeys passing items round by round.
+
+use crate::simulation::Simulation;
+use crate::solution::day_11::{MonkeySimulation, Monkeys};
+use crate::visualize::Animation;
+
+pub(crate) struct MonkeyAnimation {
+    simulation: MonkeySimulation,
+    // a simulation error can't be shown as a frame, so it ends the animation and is reported
+    error: Option<String>,
+}
+
+impl MonkeyAnimation {
+    /// Creates the animation for the number of rounds and worry relief of a part.
+    pub(crate) fn new(
+        input: &str,
+        reduce_worry: Option<u64>,
+        rounds: u32,
+    ) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            simulation: MonkeySimulation::new(Monkeys::new(input, reduce_worry)?, rounds),
+            error: None,
+        })
+    }
+}
+
+impl Animation for MonkeyAnimation {
+    fn step(&mut self) -> bool {
+        if self.simulation.is_done() || self.error.is_some() {
+            return false;
+        }
+        if let Err(e) = self.simulation.step() {
+            self.error = Some(format!("{:#}", e));
+        }
+        true
+    }
+
+    fn status(&self) -> String {
+        let (round, rounds) = (self.simulation.round(), self.simulation.rounds());
+        match &self.error {
+            Some(error) => format!("Round {}/{} | ERROR: {}", round, rounds, error),
+            None => format!(
+                "Round {}/{} | monkey business: {}",
+                round,
+                rounds,
+                self.simulation.monkeys().monkey_business()
+            ),
+        }
+    }
+
+    fn render(&self, width: usize, _height: usize) -> Vec<String> {
+        let monkeys = &self.simulation.monkeys().monkeys;
+        let most = monkeys
+            .iter()
+            .map(|m| m.inspections)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        // leave room for the label and count around each bar
+        let bar_width = width.saturating_sub(30).max(10) as u64;
+        let mut lines = Vec::new();
+        for (i, monkey) in monkeys.iter().enumerate() {
+            let bar = "#".repeat((monkey.inspections * bar_width / most) as usize);
+            lines.push(format!("Monkey {:<2} {:>8} {}", i, monkey.inspections, bar));
+            let items = monkey
+                .items
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<String>>()
+                .join(", ");
+            lines.push(format!("  items: {}", items).trim_end().to_string());
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    #[test]
+    fn test_step() {
+        let mut animation = MonkeyAnimation::new(INPUT, Some(3), 20).expect("should return result");
+        assert!(animation.step());
+        let lines = animation.render(40, 24);
+        assert_eq!("Monkey 0         2 ####", lines[0]);
+        assert_eq!("  items: 20, 23, 27, 26", lines[1]);
+        assert_eq!("Monkey 3         5 ##########", lines[6]);
+        assert_eq!("  items:", lines[7]);
+        while animation.step() {}
+        assert_eq!("Round 20/20 | monkey business: 10605", animation.status());
+    }
+
+    #[test]
+    fn test_error() {
+        let input = "Monkey 0:
+  Starting items: 79
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3";
+        let mut animation = MonkeyAnimation::new(input, Some(3), 20).expect("should return result");
+        assert!(animation.step());
+        assert!(!animation.step());
+        assert!(animation.status().contains("ERROR: tried to throw item"));
+    }
+}
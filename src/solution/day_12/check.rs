@@ -0,0 +1,51 @@
+//! Structural checks of heightmaps.
+
+use crate::check::{check_grid, Problem};
+use crate::solution::day_12::map_char_to_elevation;
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    let mut problems = check_grid(input, "squares");
+    let mut starts = 0;
+    let mut ends = 0;
+    for (y, line) in input.split('\n').enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            match c {
+                'S' => starts += 1,
+                'E' => ends += 1,
+                _ => (),
+            }
+            if let Err(e) = map_char_to_elevation(c) {
+                problems.push(Problem::at(y + 1, format!("column {}: {:#}", x + 1, e)));
+            }
+        }
+    }
+    problems.sort_by_key(|problem| problem.line);
+    for (name, c, count) in [("start", 'S', starts), ("end", 'E', ends)] {
+        if count != 1 {
+            problems.push(Problem::general(format!(
+                "expected one {} marked {}, but found {}",
+                name, c, count
+            )));
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("Sabqponm\nabcryxxl\naccszExk").is_empty());
+        assert_eq!(
+            vec![
+                Problem::at(2, "row has 7 squares, but the first row has 8"),
+                Problem::at(3, "column 2: invalid input char: 1"),
+                Problem::general("expected one start marked S, but found 2"),
+                Problem::general("expected one end marked E, but found 0"),
+            ],
+            check("Sabqponm\nabcryxx\na1cszSxk")
+        );
+    }
+}
@@ -0,0 +1,109 @@
+//! Random heightmaps with a route from the start to the best signal.
+
+use crate::generate::Rng;
+use std::collections::VecDeque;
+
+/// Highest elevation, marked `z`, which is also the elevation of `E`.
+const HIGHEST: usize = 25;
+/// Share of squares that are random rocks or holes rather than part of the slope.
+const OBSTACLES: f64 = 0.08;
+
+/// Generates a heightmap `columns` squares wide, with a little over a quarter as many rows.
+///
+/// Elevations fall away from `E` by at most one per step, except at scattered obstacles, so every
+/// square on the slope can climb to `E`. `S` is placed at the bottom of the slope, which the map
+/// is wide enough to always reach.
+pub(crate) fn generate(rng: &mut Rng, columns: usize) -> String {
+    let rows = (columns * 2 / 7).max(5);
+    let end = (
+        rng.range((columns - 5) as u64..=(columns - 1) as u64) as usize,
+        rng.index(rows),
+    );
+    let mut obstacles = OBSTACLES;
+    loop {
+        let blocked = (0..rows)
+            .map(|_| (0..columns).map(|_| rng.chance(obstacles)).collect())
+            .collect::<Vec<Vec<bool>>>();
+        let steps = steps_from(end, &blocked);
+        let furthest = steps.iter().flatten().flatten().max().copied().unwrap_or(0);
+        // how many steps it takes for the elevation to drop by one
+        let scale = rng.range(1..=(furthest / (HIGHEST + 1)).max(1) as u64) as usize;
+        let mut elevations = vec![vec![0; columns]; rows];
+        let mut lowest = Vec::new();
+        for y in 0..rows {
+            for x in 0..columns {
+                elevations[y][x] = match (blocked[y][x], steps[y][x]) {
+                    (true, _) => rng.index(HIGHEST + 1),
+                    (false, Some(steps)) => HIGHEST - (steps / scale).min(HIGHEST),
+                    (false, None) => 0,
+                };
+                if steps[y][x].is_some_and(|steps| steps / scale >= HIGHEST) {
+                    lowest.push((x, y));
+                }
+            }
+        }
+        // obstacles may have walled in the end, so try again without any
+        if lowest.is_empty() {
+            obstacles = 0.0;
+            continue;
+        }
+        let start = *rng.choose(&lowest);
+        return (0..rows)
+            .map(|y| {
+                (0..columns)
+                    .map(|x| match (x, y) {
+                        square if square == start => 'S',
+                        square if square == end => 'E',
+                        _ => (b'a' + elevations[y][x] as u8) as char,
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+    }
+}
+
+/// Counts the steps from the end to every square that isn't blocked, if it can be reached.
+fn steps_from(end: (usize, usize), blocked: &[Vec<bool>]) -> Vec<Vec<Option<usize>>> {
+    let (rows, columns) = (blocked.len(), blocked[0].len());
+    let mut steps = vec![vec![None; columns]; rows];
+    steps[end.1][end.0] = Some(0);
+    let mut queue = VecDeque::from([end]);
+    while let Some((x, y)) = queue.pop_front() {
+        let next = steps[y][x].map(|s| s + 1);
+        let neighbours = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+        for (nx, ny) in neighbours {
+            if nx < columns && ny < rows && !blocked[ny][nx] && steps[ny][nx].is_none() {
+                steps[ny][nx] = next;
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::day_12::HeightMap;
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let input = generate(&mut Rng::new(seed), 30);
+            let lines = input.lines().collect::<Vec<&str>>();
+            assert_eq!(8, lines.len());
+            assert!(lines.iter().all(|line| line.len() == 30));
+            assert_eq!(1, input.matches('S').count());
+            assert_eq!(1, input.matches('E').count());
+            let map = HeightMap::new(&input).expect("should return result");
+            let steps = map.shortest_path_length().expect("should return result");
+            assert!(steps < u32::MAX, "no path for seed {}:\n{}", seed, input);
+        }
+    }
+}
@@ -1,13 +1,36 @@
-// TODO: docs
-
+//! Solvers for day 12.
+//!
+//! The parts of day 12 find the fewest steps needed to climb a [HeightMap] to its highest point,
+//! marked `E`, where each step can climb at most one level. Part 1 starts from the square marked
+//! `S`, and part 2 from whichever square at the lowest elevation is closest.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc::day_12::HeightMap;
+//!
+//! let input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi";
+//! let map = input.parse::<HeightMap>().unwrap();
+//! assert_eq!((0, 0), map.start());
+//! assert_eq!((5, 2), map.end());
+//! assert_eq!(31, map.shortest_path_length().unwrap());
+//! assert_eq!(29, map.shortest_path_length_from_base().unwrap());
+//! ```
+
+use crate::search::{self, Search};
 use anyhow::anyhow;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 use std::hash::Hash;
+use std::str::FromStr;
 
+pub(crate) mod check;
+pub(crate) mod generate;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod repl;
+pub(crate) mod stats;
 
-#[derive(PartialEq, Eq, Copy, Clone, Hash)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
 struct Coordinates {
     x: usize,
     y: usize,
@@ -19,13 +42,15 @@ impl Coordinates {
     }
 }
 
-struct Map {
+/// The elevation of each square of a grid, from 0 for `a` to 25 for `z`.
+#[derive(Debug)]
+pub struct HeightMap {
     terrain: HashMap<Coordinates, u32>,
     start: Coordinates,
     end: Coordinates,
 }
 
-impl Map {
+impl HeightMap {
     pub(crate) fn new(input: &str) -> Result<Self, anyhow::Error> {
         let mut terrain = HashMap::new();
         let mut start = Coordinates { x: 0, y: 0 };
@@ -49,96 +74,78 @@ impl Map {
         })
     }
 
-    pub(crate) fn shortest_path_length(&self) -> Result<u32, anyhow::Error> {
-        let distances = self.dijkstra(true)?;
-        let distance_from_start = distances
-            .get(&self.start)
-            .ok_or_else(|| anyhow!("distances should have every node"))?;
-        Ok(*distance_from_start)
+    /// Returns the column and row of the start, counting from 0 at the top left.
+    pub fn start(&self) -> (usize, usize) {
+        (self.start.x, self.start.y)
     }
 
-    pub(crate) fn shortest_path_length_from_base(&self) -> Result<u32, anyhow::Error> {
-        let distances = self.dijkstra(false)?;
-        let mut min = u32::MAX;
-        for loc in self.terrain.iter().filter(|c| *c.1 == 0) {
-            let distance = distances
-                .get(loc.0)
-                .ok_or_else(|| anyhow!("distances should have every node"))?;
-            if *distance < min {
-                min = *distance
-            }
-        }
-        Ok(min)
+    /// Returns the column and row of the highest point, counting from 0 at the top left.
+    pub fn end(&self) -> (usize, usize) {
+        (self.end.x, self.end.y)
     }
 
-    fn dijkstra(&self, exit_early: bool) -> Result<HashMap<Coordinates, u32>, anyhow::Error> {
-        let mut unvisited = HashSet::new();
-        let mut distance = HashMap::new();
-        for coord in self.terrain.keys() {
-            distance.insert(*coord, u32::MAX);
-            unvisited.insert(*coord);
-        }
-        distance.insert(self.end, 0);
-
-        while !unvisited.is_empty() {
-            // could be optimized by keeping track of min on the fly
-            // replace this lookup with a min-heap using custom Ord implementation on coord height tuple
-            let current = get_min_distance(&unvisited, &distance)?;
-            if current == self.start && exit_early {
-                return Ok(distance);
-            }
-            unvisited.remove(&current);
-            if *distance
-                .get(&current)
-                .ok_or_else(|| anyhow!("distance should have every node"))?
-                == u32::MAX
-            {
-                continue;
-            }
+    /// Returns the elevation of a square, counting from 0 at the top left.
+    pub fn elevation(&self, x: usize, y: usize) -> Option<u32> {
+        self.terrain.get(&Coordinates::new(x, y)).copied()
+    }
 
-            let mut targets = Vec::new();
-            targets.push(Coordinates::new(current.x + 1, current.y));
-            targets.push(Coordinates::new(current.x, current.y + 1));
-            if current.x > 0 {
-                targets.push(Coordinates::new(current.x - 1, current.y));
-            }
-            if current.y > 0 {
-                targets.push(Coordinates::new(current.x, current.y - 1));
-            }
-            for target in targets {
-                if self.can_pass(&current, &target)? && unvisited.contains(&target) {
-                    let new_dist = distance
-                        .get(&current)
-                        .ok_or_else(|| anyhow!("distance should have every node"))?
-                        + 1;
-                    if new_dist
-                        < *distance
-                            .get(&target)
-                            .ok_or_else(|| anyhow!("distance should have every node"))?
-                    {
-                        distance.insert(target, new_dist);
-                    }
+    /// Finds the fewest steps from the start to the highest point, or `u32::MAX` if there is no
+    /// path.
+    pub fn shortest_path_length(&self) -> Result<u32, anyhow::Error> {
+        let search = self.search_from_end(|square| *square == self.start);
+        steps(search.distance(&self.start))
+    }
+
+    /// Finds the fewest steps from any square at elevation 0 to the highest point, or `u32::MAX` if
+    /// there is no path.
+    pub fn shortest_path_length_from_base(&self) -> Result<u32, anyhow::Error> {
+        let search = self.search_from_end(|square| self.terrain.get(square) == Some(&0));
+        steps(search.goal().and_then(|square| search.distance(square)))
+    }
+
+    /// Searches backwards from the highest point until reaching a square accepted by `goal`.
+    ///
+    /// Walking backwards means the distance to each square reached is the fewest steps from that
+    /// square to the highest point, so a single search finds the closest of several squares.
+    fn search_from_end(&self, goal: impl FnMut(&Coordinates) -> bool) -> Search<Coordinates> {
+        search::bfs(
+            [self.end],
+            |current| {
+                let mut targets = vec![
+                    Coordinates::new(current.x + 1, current.y),
+                    Coordinates::new(current.x, current.y + 1),
+                ];
+                if current.x > 0 {
+                    targets.push(Coordinates::new(current.x - 1, current.y));
                 }
+                if current.y > 0 {
+                    targets.push(Coordinates::new(current.x, current.y - 1));
+                }
+                targets.retain(|target| self.can_pass(current, target));
+                targets
+            },
+            goal,
+        )
+    }
+
+    fn can_pass(&self, current: &Coordinates, target: &Coordinates) -> bool {
+        match (self.terrain.get(current), self.terrain.get(target)) {
+            // working backwards from end to start we can go down only one but up an unlimited amount
+            (Some(current_height), Some(target_height)) => {
+                *current_height as i32 - *target_height as i32 <= 1
             }
+            _ => false,
         }
-
-        Ok(distance)
     }
+}
 
-    fn can_pass(&self, current: &Coordinates, target: &Coordinates) -> Result<bool, anyhow::Error> {
-        if !self.terrain.contains_key(target) {
-            return Ok(false);
-        }
-        let current_height = self
-            .terrain
-            .get(current)
-            .ok_or_else(|| anyhow!("terrain should have every node"))?;
-        let target_height = self
-            .terrain
-            .get(target)
-            .ok_or_else(|| anyhow!("terrain should have every node"))?;
-        // working backwards from end to start we can go down only one but up an unlimited amount
-        Ok(*current_height as i32 - *target_height as i32 <= 1)
+impl FromStr for HeightMap {
+    type Err = anyhow::Error;
+
+    /// Parses a grid of lowercase letters, with `S` marking the start at elevation `a` and `E`
+    /// marking the highest point at elevation `z`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HeightMap::new(s)
     }
 }
 
@@ -153,25 +160,21 @@ fn map_char_to_elevation(c: char) -> Result<u32, anyhow::Error> {
     }
 }
 
-fn get_min_distance(
-    unvisited: &HashSet<Coordinates>,
-    distance: &HashMap<Coordinates, u32>,
-) -> Result<Coordinates, anyhow::Error> {
-    let mut coords = Coordinates::new(0, 0);
-    let mut min = u32::MAX;
-    for c in unvisited.iter() {
-        let distance = distance
-            .get(c)
-            .ok_or_else(|| anyhow!("distance should contain coordinates"))?;
-        if *distance <= min {
-            coords = *c;
-            min = *distance;
-        }
+/// Converts the distance found by a search into steps, using `u32::MAX` if there is no path.
+fn steps(distance: Option<u64>) -> Result<u32, anyhow::Error> {
+    match distance {
+        Some(distance) => Ok(u32::try_from(distance)?),
+        None => Ok(u32::MAX),
     }
-    Ok(coords)
 }
 
 // TODO: more tests
+/// Parses the input for both parts without solving them.
+pub(crate) fn parse(input: &str) -> Result<(), anyhow::Error> {
+    HeightMap::new(input)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,10 +186,30 @@ abcryxxl
 accszExk
 acctuvwj
 abdefghi";
-        let map = Map::new(input).expect("should return result");
+        let map = HeightMap::new(input).expect("should return result");
         assert_eq!(
             31,
             map.shortest_path_length().expect("should return result")
         );
+        assert_eq!(
+            29,
+            map.shortest_path_length_from_base()
+                .expect("should return result")
+        );
+    }
+
+    #[test]
+    fn test_map_unreachable() {
+        // the cliff up to z can't be climbed from the start
+        let map = HeightMap::new("SaczE").expect("should return result");
+        assert_eq!(
+            u32::MAX,
+            map.shortest_path_length().expect("should return result")
+        );
+        assert_eq!(
+            u32::MAX,
+            map.shortest_path_length_from_base()
+                .expect("should return result")
+        );
     }
 }
@@ -1,10 +1,15 @@
 // TODO: docs
 
-use crate::solution::day_12::Map;
+use crate::error::{Categorize, ErrorKind};
+use crate::solution::day_12::HeightMap;
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
-    let terrain = Map::new(input)?;
-    Ok(terrain.shortest_path_length_from_base()?.to_string())
+    let terrain = HeightMap::new(input)?;
+    crate::metrics::parsed();
+    let length = terrain
+        .shortest_path_length_from_base()
+        .categorize(ErrorKind::Simulation)?;
+    Ok(length.to_string())
 }
 
 #[cfg(test)]
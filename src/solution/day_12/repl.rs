@@ -0,0 +1,247 @@
+//! Explorer for the height map and the distances across it.
+
+use crate::error::{Categorize, ErrorKind};
+use crate::repl::{arg, unknown_command, Explorer};
+use crate::search::Search;
+use crate::solution::day_12::{Coordinates, HeightMap};
+use anyhow::anyhow;
+use std::collections::HashSet;
+
+const HELP: &str = "show                     print the height map
+start                    print the coordinates of the start
+end                      print the coordinates of the best signal
+height <x> <y>           print the elevation of a square
+distance [<x> <y>]       print the fewest steps from a square to the best signal (part 1)
+closest [elevation]      find the square at an elevation closest to the best signal (part 2)
+path [<x> <y>]           print the map with the shortest path from a square marked";
+
+pub(crate) struct MapExplorer {
+    map: HeightMap,
+    width: usize,
+    height: usize,
+    // distances to the end are only calculated once they're first needed
+    distances: Option<Search<Coordinates>>,
+}
+
+impl MapExplorer {
+    pub(crate) fn new(input: &str) -> Result<Self, anyhow::Error> {
+        let map = HeightMap::new(input)?;
+        let width = map.terrain.keys().map(|c| c.x + 1).max().unwrap_or(0);
+        let height = map.terrain.keys().map(|c| c.y + 1).max().unwrap_or(0);
+        Ok(Self {
+            map,
+            width,
+            height,
+            distances: None,
+        })
+    }
+
+    /// Reads the coordinates of a square from the arguments, or uses the start if there are none.
+    fn square(&self, args: &[&str]) -> Result<Coordinates, anyhow::Error> {
+        if args.is_empty() {
+            return Ok(self.map.start);
+        }
+        let coordinates = Coordinates::new(arg(args, 0, "x")?, arg(args, 1, "y")?);
+        if !self.map.terrain.contains_key(&coordinates) {
+            return Err(anyhow!(
+                "square is outside the map: {},{}",
+                coordinates.x,
+                coordinates.y
+            ))
+            .categorize(ErrorKind::Usage);
+        }
+        Ok(coordinates)
+    }
+
+    /// Follows decreasing distances from a square to the end.
+    fn path(&mut self, from: Coordinates) -> Result<Vec<Coordinates>, anyhow::Error> {
+        let distances = distances(&self.map, &mut self.distances);
+        let mut path = vec![from];
+        let mut current = from;
+        let mut distance = distances
+            .distance(&from)
+            .ok_or_else(|| anyhow!("best signal is unreachable from {},{}", from.x, from.y))
+            .categorize(ErrorKind::Usage)?;
+        while distance > 0 {
+            let mut neighbours = vec![
+                Coordinates::new(current.x + 1, current.y),
+                Coordinates::new(current.x, current.y + 1),
+            ];
+            if current.x > 0 {
+                neighbours.push(Coordinates::new(current.x - 1, current.y));
+            }
+            if current.y > 0 {
+                neighbours.push(Coordinates::new(current.x, current.y - 1));
+            }
+            // distances were found walking backwards, so the step is checked in reverse
+            current = neighbours
+                .into_iter()
+                .find(|neighbour| {
+                    distances.distance(neighbour) == Some(distance - 1)
+                        && self.map.can_pass(neighbour, &current)
+                })
+                .ok_or_else(|| anyhow!("path should continue towards the end"))?;
+            distance -= 1;
+            path.push(current);
+        }
+        Ok(path)
+    }
+
+    fn render(&self, marked: &HashSet<Coordinates>) -> String {
+        let mut lines = Vec::new();
+        for y in 0..self.height {
+            let mut line = String::new();
+            for x in 0..self.width {
+                let coordinates = Coordinates::new(x, y);
+                let c = match self.map.terrain.get(&coordinates) {
+                    _ if coordinates == self.map.start => 'S',
+                    _ if coordinates == self.map.end => 'E',
+                    _ if marked.contains(&coordinates) => '.',
+                    Some(elevation) => elevation_to_char(*elevation),
+                    None => ' ',
+                };
+                line.push(c);
+            }
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+}
+
+impl Explorer for MapExplorer {
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String, anyhow::Error> {
+        match command {
+            "show" => Ok(self.render(&HashSet::new())),
+            "start" => Ok(format!("{},{}", self.map.start.x, self.map.start.y)),
+            "end" => Ok(format!("{},{}", self.map.end.x, self.map.end.y)),
+            "height" => {
+                let square = self.square(args)?;
+                Ok(elevation_to_char(self.map.terrain[&square]).to_string())
+            }
+            "distance" => {
+                let square = self.square(args)?;
+                match distances(&self.map, &mut self.distances).distance(&square) {
+                    Some(distance) => Ok(distance.to_string()),
+                    None => Ok("unreachable".to_string()),
+                }
+            }
+            "closest" => {
+                let elevation = match args.first() {
+                    Some(_) => char_to_elevation(arg(args, 0, "elevation")?)?,
+                    None => 0,
+                };
+                let distances = distances(&self.map, &mut self.distances);
+                let closest = self
+                    .map
+                    .terrain
+                    .iter()
+                    .filter(|(_, e)| **e == elevation)
+                    .filter_map(|(c, _)| Some((distances.distance(c)?, c.y, c.x)))
+                    .min();
+                match closest {
+                    Some((distance, y, x)) => Ok(format!("{} from {},{}", distance, x, y)),
+                    None => Ok("unreachable".to_string()),
+                }
+            }
+            "path" => {
+                let square = self.square(args)?;
+                let path = self.path(square)?;
+                let rendered = self.render(&path.iter().copied().collect());
+                Ok(format!("{} steps\n{}", path.len() - 1, rendered))
+            }
+            _ => Err(unknown_command(command)),
+        }
+    }
+
+    fn help(&self) -> &'static str {
+        HELP
+    }
+}
+
+/// Returns the distance from every square to the end, calculating them the first time.
+fn distances<'a>(
+    map: &HeightMap,
+    distances: &'a mut Option<Search<Coordinates>>,
+) -> &'a Search<Coordinates> {
+    distances.get_or_insert_with(|| map.search_from_end(|_| false))
+}
+
+fn elevation_to_char(elevation: u32) -> char {
+    char::from_u32('a' as u32 + elevation).unwrap_or('?')
+}
+
+fn char_to_elevation(c: char) -> Result<u32, anyhow::Error> {
+    match c {
+        'a'..='z' => Ok(c as u32 - 'a' as u32),
+        _ => Err(anyhow!("invalid elevation: {}", c)).categorize(ErrorKind::Usage),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn explorer() -> MapExplorer {
+        let input = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+        MapExplorer::new(input).expect("should return result")
+    }
+
+    #[test]
+    fn test_distances() {
+        let mut explorer = explorer();
+        assert_eq!(
+            "31",
+            explorer
+                .execute("distance", &[])
+                .expect("should return result")
+        );
+        assert_eq!(
+            "0",
+            explorer
+                .execute("distance", &["5", "2"])
+                .expect("should return result")
+        );
+        assert_eq!(
+            "29 from 0,4",
+            explorer
+                .execute("closest", &[])
+                .expect("should return result")
+        );
+        assert!(explorer.execute("distance", &["8", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_path() {
+        let path = explorer()
+            .execute("path", &[])
+            .expect("should return result");
+        assert_eq!(
+            "31 steps
+S.......
+ab......
+ac...E..
+ac......
+ab......",
+            path
+        );
+    }
+
+    #[test]
+    fn test_show() {
+        let mut explorer = explorer();
+        assert_eq!(
+            "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi",
+            explorer.execute("show", &[]).expect("should return result")
+        );
+        assert_eq!(
+            "z",
+            explorer
+                .execute("height", &["4", "2"])
+                .expect("should return result")
+        );
+    }
+}
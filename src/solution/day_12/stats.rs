@@ -0,0 +1,37 @@
+//! Statistics of heightmaps.
+
+use crate::solution::day_12::{Coordinates, HeightMap};
+use crate::stats::Stat;
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    let map = HeightMap::new(input)?;
+    let columns = map.terrain.keys().map(|c| c.x + 1).max().unwrap_or(0);
+    let rows = map.terrain.keys().map(|c| c.y + 1).max().unwrap_or(0);
+    let position = |c: &Coordinates| format!("{},{}", c.x, c.y);
+    Ok(vec![
+        Stat::new("columns", columns),
+        Stat::new("rows", rows),
+        Stat::new("start", position(&map.start)),
+        Stat::new("end", position(&map.end)),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = stats("Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi")
+            .expect("should return result");
+        assert_eq!(
+            vec![
+                Stat::new("columns", 8),
+                Stat::new("rows", 5),
+                Stat::new("start", "0,0"),
+                Stat::new("end", "5,2"),
+            ],
+            stats
+        );
+    }
+}
@@ -0,0 +1,42 @@
+//! Structural checks of distress signal packets.
+
+use crate::check::Problem;
+use crate::solution::day_13::Packet;
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    let mut line = 1;
+    for (i, pair) in input.split("\n\n").enumerate() {
+        let packets = pair.split('\n').collect::<Vec<&str>>();
+        if packets.len() != 2 {
+            problems.push(Problem::at(
+                line,
+                format!("pair {} has {} packets instead of 2", i + 1, packets.len()),
+            ));
+        }
+        for (j, packet) in packets.iter().enumerate() {
+            if let Err(e) = Packet::new(packet) {
+                problems.push(Problem::at(line + j, format!("{:#}", e)));
+            }
+        }
+        line += packets.len() + 1;
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("[1,1,3]\n[1,[2]]\n\n[]\n[[]]").is_empty());
+        assert_eq!(
+            vec![
+                Problem::at(2, "invalid packet input"),
+                Problem::at(4, "pair 2 has 3 packets instead of 2"),
+            ],
+            check("[1]\n1]\n\n[]\n[[]]\n[3]")
+        );
+    }
+}
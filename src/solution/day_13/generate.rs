@@ -0,0 +1,24 @@
+//! Random pairs of distress signal packets.
+
+use crate::generate::Rng;
+
+/// Deepest that lists are nested inside a packet.
+const MAX_DEPTH: u32 = 4;
+
+/// Generates `pairs` pairs of packets.
+pub(crate) fn generate(rng: &mut Rng, pairs: usize) -> String {
+    (0..pairs)
+        .map(|_| format!("{}\n{}", list(rng, 1), list(rng, 1)))
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+fn list(rng: &mut Rng, depth: u32) -> String {
+    let values = (0..rng.range(0..=5))
+        .map(|_| match depth < MAX_DEPTH && rng.chance(0.3) {
+            true => list(rng, depth + 1),
+            false => rng.range(0..=10).to_string(),
+        })
+        .collect::<Vec<String>>();
+    format!("[{}]", values.join(","))
+}
@@ -1,19 +1,70 @@
-// TODO: docs
+//! Solvers for day 13.
+//!
+//! The parts of day 13 compare distress signal [Packet]s, which are nested lists of integers.
+//! Part 1 counts the pairs that are already in the right order, and part 2 sorts every packet
+//! along with two divider packets.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc::day_13::Packet;
+//!
+//! let left = "[1,[2,[3]]]".parse::<Packet>().unwrap();
+//! let right = "[1,[2,4]]".parse::<Packet>().unwrap();
+//! assert!(left < right);
+//! assert_eq!("[1,[2,[3]]]", left.to_string());
+//! ```
 
 use anyhow::{anyhow, Context};
 use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
+pub(crate) mod check;
+pub(crate) mod generate;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod reference;
+pub(crate) mod stats;
 
-#[derive(PartialEq, Eq, Debug)]
-enum Packet {
+/// A packet, or one of the values inside it.
+///
+/// Packets are ordered the way the puzzle compares them: an integer is compared with a list as if
+/// it were a list holding just that integer. Equality follows the same rules, so `1`, `[1]` and
+/// `[[1]]` are all `==` even though they are written differently.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Packet {
     Int(i32),
     Array(Vec<Packet>),
 }
 
+/// Deepest nesting of lists allowed in a packet.
+///
+/// Real packets nest only a few lists deep, but parsing recurses once per list, so a limit stops a
+/// malformed packet from overflowing the stack.
+const MAX_DEPTH: usize = 100;
+
 impl Packet {
     pub(crate) fn new(input: &str) -> Result<Self, anyhow::Error> {
+        let mut depth: usize = 0;
+        for c in input.chars() {
+            match c {
+                '[' => depth += 1,
+                ']' => depth = depth.saturating_sub(1),
+                _ => continue,
+            }
+            if depth > MAX_DEPTH {
+                return Err(anyhow!(
+                    "packet is nested more than {} lists deep",
+                    MAX_DEPTH
+                ));
+            }
+        }
+        Packet::parse(input)
+    }
+
+    fn parse(input: &str) -> Result<Self, anyhow::Error> {
         if input.len() < 2 || !input.starts_with('[') || !input.ends_with(']') {
             return Err(anyhow!("invalid packet input"));
         }
@@ -49,6 +100,15 @@ impl Packet {
     }
 }
 
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        // equal whenever neither comes first, which keeps == consistent with the ordering
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Packet {}
+
 impl PartialOrd<Self> for Packet {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -89,6 +149,33 @@ impl Ord for Packet {
     }
 }
 
+impl FromStr for Packet {
+    type Err = anyhow::Error;
+
+    /// Parses a packet written as a list, such as `[1,[2,3]]`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Packet::new(s)
+    }
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Packet::Int(value) => write!(f, "{}", value),
+            Packet::Array(packets) => {
+                write!(f, "[")?;
+                for (i, packet) in packets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", packet)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
 fn trim_first_and_last(input: &str) -> &str {
     let mut chars = input.chars();
     chars.next();
@@ -101,7 +188,7 @@ fn process_scratch(input: &str) -> Result<Packet, anyhow::Error> {
         return Err(anyhow!("scratch is empty"));
     }
     match input.contains('[') {
-        true => Packet::new(input),
+        true => Packet::parse(input),
         false => {
             let val = input
                 .parse::<i32>()
@@ -111,6 +198,13 @@ fn process_scratch(input: &str) -> Result<Packet, anyhow::Error> {
     }
 }
 
+/// Parses the input for both parts without solving them.
+pub(crate) fn parse(input: &str) -> Result<(), anyhow::Error> {
+    part_1::parse_input(input)?;
+    part_2::parse_input(input)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,4 +217,29 @@ mod tests {
         let right = Packet::new(right_input).expect("should return result");
         assert!(left > right);
     }
+
+    #[test]
+    fn test_packet_equality() {
+        let packet = |input: &str| Packet::new(input).expect("should return result");
+        assert_eq!(packet("[1]"), packet("[[1]]"));
+        assert_eq!(Ordering::Equal, packet("[1]").cmp(&packet("[[1]]")));
+        assert_ne!(packet("[1]"), packet("[1,1]"));
+        let mut packets = vec![packet("[[1]]"), packet("[2]"), packet("[1]")];
+        packets.sort();
+        packets.dedup();
+        assert_eq!(2, packets.len());
+    }
+
+    #[test]
+    fn test_packet_too_deep() {
+        // parsing recurses for every list, so this used to overflow the stack
+        let input = format!("{}{}", "[".repeat(100_000), "]".repeat(100_000));
+        let error = Packet::new(&input).expect_err("should return error");
+        assert_eq!(
+            "packet is nested more than 100 lists deep",
+            error.to_string()
+        );
+        let input = format!("{}{}", "[".repeat(100), "]".repeat(100));
+        assert!(Packet::new(&input).is_ok());
+    }
 }
@@ -4,7 +4,7 @@ use crate::solution::day_13::Packet;
 use anyhow::anyhow;
 
 #[derive(Debug)]
-struct PacketPair {
+pub(super) struct PacketPair {
     left: Packet,
     right: Packet,
 }
@@ -26,7 +26,7 @@ impl PacketPair {
     }
 }
 
-fn parse_input(input: &str) -> Result<Vec<PacketPair>, anyhow::Error> {
+pub(super) fn parse_input(input: &str) -> Result<Vec<PacketPair>, anyhow::Error> {
     let mut pairs = Vec::new();
     for p in input.split("\n\n") {
         pairs.push(PacketPair::new(p)?);
@@ -36,6 +36,7 @@ fn parse_input(input: &str) -> Result<Vec<PacketPair>, anyhow::Error> {
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     let pairs = parse_input(input)?;
+    crate::metrics::parsed();
     let mut in_order_idx = Vec::new();
     for (idx, pair) in pairs.iter().enumerate() {
         if pair.in_order() {
@@ -1,9 +1,8 @@
 // TODO: docs
 
 use crate::solution::day_13::Packet;
-use anyhow::anyhow;
 
-fn parse_input(input: &str) -> Result<Vec<Packet>, anyhow::Error> {
+pub(super) fn parse_input(input: &str) -> Result<Vec<Packet>, anyhow::Error> {
     let mut packets = Vec::new();
     for p in input.replace("\n\n", "\n").split('\n') {
         packets.push(Packet::new(p)?);
@@ -11,25 +10,19 @@ fn parse_input(input: &str) -> Result<Vec<Packet>, anyhow::Error> {
     Ok(packets)
 }
 
-fn marker_packet(marker: i32) -> Packet {
-    Packet::Array(vec![Packet::Int(marker)])
-}
-
-fn find_marker_packet(packets: &[Packet], marker: i32) -> Result<usize, anyhow::Error> {
-    packets
-        .iter()
-        .position(|p| *p == marker_packet(marker))
-        .ok_or_else(|| anyhow!("could not find marker packet {}", marker))
+fn divider_packet(value: i32) -> Packet {
+    Packet::Array(vec![Packet::Array(vec![Packet::Int(value)])])
 }
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
     let mut packets = parse_input(input)?;
-    packets.push(marker_packet(2));
-    packets.push(marker_packet(6));
+    crate::metrics::parsed();
     packets.sort();
-    let marker_2_idx = find_marker_packet(&packets, 2)?;
-    let marker_6_idx = find_marker_packet(&packets, 6)?;
-    let key = (marker_2_idx + 1) as u32 * (marker_6_idx + 1) as u32;
+    // packets such as [2] are equal to a divider when ordering, so each divider is placed before
+    // any packet it's equal to, and the first divider also comes before the second
+    let divider_2_idx = packets.partition_point(|p| *p < divider_packet(2));
+    let divider_6_idx = packets.partition_point(|p| *p < divider_packet(6)) + 1;
+    let key = (divider_2_idx + 1) as u32 * (divider_6_idx + 1) as u32;
     Ok(key.to_string())
 }
 
@@ -64,4 +57,11 @@ mod tests {
 [1,[2,[3,[4,[5,6,0]]]],8,9]";
         assert_eq!("140", solve(input).expect("should return result"));
     }
+
+    #[test]
+    fn test_solve_equal_to_divider() {
+        // [[2]] and [2] are both equal to the first divider, which still comes right after [1]
+        let input = "[[2]]\n[2]\n\n[1]\n[6]";
+        assert_eq!("10", solve(input).expect("should return result"));
+    }
 }
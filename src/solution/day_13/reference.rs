@@ -0,0 +1,160 @@
+//! A slow reference implementation of day 13, for differential testing.
+//!
+//! Packets are parsed with a separate recursive descent parser, and compared by a function that
+//! follows the puzzle's rules one by one rather than the [Ord] implementation of
+//! [Packet](super::Packet). Part 2 doesn't sort: a divider's position is one more than the number
+//! of packets that come before it.
+
+use anyhow::{anyhow, Context};
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone)]
+enum Value {
+    Integer(i32),
+    List(Vec<Value>),
+}
+
+/// Parses a value starting at the front of the input, returning it and the rest of the input.
+fn parse_value(input: &str) -> Result<(Value, &str), anyhow::Error> {
+    match input.strip_prefix('[') {
+        Some(mut rest) => {
+            let mut values = Vec::new();
+            if let Some(rest) = rest.strip_prefix(']') {
+                return Ok((Value::List(values), rest));
+            }
+            loop {
+                let (value, after) = parse_value(rest)?;
+                values.push(value);
+                if let Some(after) = after.strip_prefix(',') {
+                    rest = after;
+                } else if let Some(after) = after.strip_prefix(']') {
+                    return Ok((Value::List(values), after));
+                } else {
+                    return Err(anyhow!("expected , or ] in packet: {}", after));
+                }
+            }
+        }
+        None => {
+            let digits = input.len() - input.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            let integer = input[..digits]
+                .parse::<i32>()
+                .with_context(|| format!("expected an integer in packet: {}", input))?;
+            Ok((Value::Integer(integer), &input[digits..]))
+        }
+    }
+}
+
+fn parse_packet(input: &str) -> Result<Value, anyhow::Error> {
+    match parse_value(input)? {
+        (packet @ Value::List(_), "") => Ok(packet),
+        _ => Err(anyhow!("invalid packet: {}", input)),
+    }
+}
+
+/// Compares two values using the rules from the puzzle, in the order they're given.
+fn compare(left: &Value, right: &Value) -> Ordering {
+    match (left, right) {
+        // if both values are integers, the lower integer should come first
+        (Value::Integer(left), Value::Integer(right)) => left.cmp(right),
+        // if both values are lists, compare the first value of each list, then the second, and so
+        // on, and if one list runs out first it should come first
+        (Value::List(left), Value::List(right)) => {
+            let mut index = 0;
+            loop {
+                match (left.get(index), right.get(index)) {
+                    (None, None) => return Ordering::Equal,
+                    (None, Some(_)) => return Ordering::Less,
+                    (Some(_), None) => return Ordering::Greater,
+                    (Some(left), Some(right)) => match compare(left, right) {
+                        Ordering::Equal => index += 1,
+                        ordering => return ordering,
+                    },
+                }
+            }
+        }
+        // if exactly one value is an integer, convert it to a list holding only that integer
+        (Value::Integer(_), Value::List(_)) => compare(&Value::List(vec![left.clone()]), right),
+        (Value::List(_), Value::Integer(_)) => compare(left, &Value::List(vec![right.clone()])),
+    }
+}
+
+pub(crate) fn part_1(input: &str) -> Result<String, anyhow::Error> {
+    let mut sum = 0;
+    for (index, pair) in input.split("\n\n").enumerate() {
+        let packets = pair.split('\n').collect::<Vec<&str>>();
+        if packets.len() != 2 {
+            return Err(anyhow!("invalid number of packets in pair: {}", pair));
+        }
+        let (left, right) = (parse_packet(packets[0])?, parse_packet(packets[1])?);
+        if compare(&left, &right) != Ordering::Greater {
+            sum += index + 1;
+        }
+    }
+    Ok(sum.to_string())
+}
+
+pub(crate) fn part_2(input: &str) -> Result<String, anyhow::Error> {
+    let packets = input
+        .split('\n')
+        .filter(|line| !line.is_empty())
+        .map(parse_packet)
+        .collect::<Result<Vec<Value>, anyhow::Error>>()?;
+    let divider = |value| Value::List(vec![Value::List(vec![Value::Integer(value)])]);
+    let (first, second) = (divider(2), divider(6));
+    let before = |divider: &Value| {
+        packets
+            .iter()
+            .filter(|packet| compare(packet, divider) == Ordering::Less)
+            .count()
+    };
+    // the first divider also comes before the second
+    let key = (before(&first) + 1) * (before(&second) + 2);
+    Ok(key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!("13", part_1(INPUT).expect("should return result"));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!("140", part_2(INPUT).expect("should return result"));
+    }
+
+    #[test]
+    fn test_parse_packet() {
+        parse_packet("[1,[2,[]],10]").expect("should return result");
+        parse_packet("[1,]").expect_err("should return error");
+        parse_packet("[1]]").expect_err("should return error");
+        parse_packet("1").expect_err("should return error");
+    }
+}
@@ -0,0 +1,40 @@
+//! Statistics of distress signal packets.
+
+use crate::solution::day_13::{part_2, Packet};
+use crate::stats::{range, Stat};
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    let packets = part_2::parse_input(input)?;
+    Ok(vec![
+        Stat::new("pairs", input.split("\n\n").count()),
+        Stat::new("packets", packets.len()),
+        Stat::new("list depth", range(packets.iter().map(depth))),
+    ])
+}
+
+/// Counts how many lists deep a packet goes, where an integer has no depth.
+fn depth(packet: &Packet) -> usize {
+    match packet {
+        Packet::Int(_) => 0,
+        Packet::Array(packets) => 1 + packets.iter().map(depth).max().unwrap_or(0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats =
+            stats("[1,1,3]\n[[1],[2,3,4]]\n\n[[[]]]\n[[[[5]]]]").expect("should return result");
+        assert_eq!(
+            vec![
+                Stat::new("pairs", 2),
+                Stat::new("packets", 4),
+                Stat::new("list depth", "1 to 4"),
+            ],
+            stats
+        );
+    }
+}
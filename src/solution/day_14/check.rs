@@ -0,0 +1,38 @@
+//! Structural checks of rock scans.
+
+use crate::check::Problem;
+use crate::solution::day_14::{process_line, Coordinates};
+
+pub(crate) fn check(input: &str) -> Vec<Problem> {
+    let source = Coordinates { x: 500, y: 0 };
+    let mut problems = Vec::new();
+    for (i, line) in input.split('\n').enumerate() {
+        match process_line(line) {
+            // the sand would have nowhere to come from, so nothing could fall
+            Ok(walls) if walls.contains(&source) => problems.push(Problem::at(
+                i + 1,
+                "rock covers the source of the sand at 500,0",
+            )),
+            Ok(_) => (),
+            Err(e) => problems.push(Problem::at(i + 1, format!("{:#}", e))),
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(check("498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9").is_empty());
+        assert_eq!(
+            vec![
+                Problem::at(1, "wall start and end must share at least a row or column"),
+                Problem::at(2, "rock covers the source of the sand at 500,0"),
+            ],
+            check("498,4 -> 497,6\n500,0 -> 500,2")
+        );
+    }
+}
@@ -0,0 +1,40 @@
+//! Random scans of rock paths in the cave.
+
+use crate::generate::Rng;
+
+/// Columns that rock can be placed in, around where the sand pours in at `500`.
+const COLUMNS: (u32, u32) = (460, 540);
+/// Rows that rock can be placed in, leaving a gap below the sand's source at the top.
+const ROWS: (u32, u32) = (13, 160);
+
+/// Generates a scan with `paths` paths of rock.
+pub(crate) fn generate(rng: &mut Rng, paths: usize) -> String {
+    (0..paths)
+        .map(|_| path(rng))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Generates a path whose segments alternate between horizontal and vertical.
+fn path(rng: &mut Rng) -> String {
+    let mut x = rng.range(COLUMNS.0 as u64..=COLUMNS.1 as u64) as u32;
+    let mut y = rng.range(ROWS.0 as u64..=ROWS.1 as u64) as u32;
+    let mut points = vec![format!("{},{}", x, y)];
+    let mut horizontal = rng.chance(0.5);
+    for _ in 0..rng.range(1..=4) {
+        let length = rng.range(1..=8) as u32;
+        let (position, (low, high)) = match horizontal {
+            true => (&mut x, COLUMNS),
+            false => (&mut y, ROWS),
+        };
+        // turn around rather than leaving the area
+        *position = match rng.chance(0.5) {
+            true if *position + length <= high => *position + length,
+            _ if *position >= low + length => *position - length,
+            _ => *position + length,
+        };
+        points.push(format!("{},{}", x, y));
+        horizontal = !horizontal;
+    }
+    points.join(" -> ")
+}
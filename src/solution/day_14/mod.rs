@@ -1,16 +1,48 @@
-// TODO: docs
+//! Solvers for day 14.
+//!
+//! The parts of day 14 pour sand into a [Cave] scanned as paths of rock, and count how much comes
+//! to rest. In part 1 the sand eventually falls past the lowest rock into the abyss, while in part
+//! 2 a floor two levels below the lowest rock catches it until it piles up to the source.
+//!
+//! # Examples
+//!
+//! ```
+//! use aoc::day_14::Cave;
+//!
+//! let input = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+//! let mut cave = input.parse::<Cave>().unwrap();
+//! assert!(cave.is_rock(498, 5));
+//! cave.simulate_sand(false);
+//! assert_eq!(24, cave.count_sand());
+//! ```
+//!
+//! A [SandSimulation] pours the same sand one unit at a time.
 
+use crate::simulation::Simulation;
 use anyhow::anyhow;
 use std::collections::HashSet;
 use std::str::FromStr;
 
+pub(crate) mod check;
+pub(crate) mod generate;
 pub(crate) mod part_1;
 pub(crate) mod part_2;
+pub(crate) mod stats;
+pub(crate) mod visualize;
 
-#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+/// Largest coordinate allowed in a scan.
+///
+/// Every square of rock is stored, so a limit far beyond any real scan stops a single path from
+/// using up all of the memory.
+const MAX_COORDINATE: u32 = 10_000;
+
+/// A square of the cave, where x grows to the right and y grows downwards.
+///
+/// Scans only have squares from 0 upwards, but with a floor the sand can pile up left of x=0.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
 struct Coordinates {
-    x: u32,
-    y: u32,
+    x: i32,
+    y: i32,
 }
 
 impl FromStr for Coordinates {
@@ -23,14 +55,27 @@ impl FromStr for Coordinates {
         }
         let x = parts[0].parse::<u32>()?;
         let y = parts[1].parse::<u32>()?;
-        Ok(Self { x, y })
+        if x > MAX_COORDINATE || y > MAX_COORDINATE {
+            return Err(anyhow!(
+                "coordinates are larger than {}: {}",
+                MAX_COORDINATE,
+                s
+            ));
+        }
+        // both fit in an i32 once they are within the limit
+        Ok(Self {
+            x: x as i32,
+            y: y as i32,
+        })
     }
 }
 
-struct Cave {
+/// A slice of a cave with rock, the sand that has come to rest, and a source of sand at 500,0.
+#[derive(Debug)]
+pub struct Cave {
     walls: HashSet<Coordinates>,
-    lowest_wall_point: u32,
-    cave_floor: u32,
+    lowest_wall_point: i32,
+    cave_floor: i32,
     sand_spawn: Coordinates,
     sand: HashSet<Coordinates>,
 }
@@ -52,50 +97,133 @@ impl Cave {
         })
     }
 
-    pub(crate) fn simulate_sand(&mut self, use_floor: bool) {
-        let mut done = false;
-        while !done {
-            let mut new_sand = self.sand_spawn;
-            let mut still_falling = true;
-            while still_falling {
-                if self.is_empty(new_sand.x, new_sand.y + 1) {
-                    new_sand.y += 1;
-                } else if self.is_empty(new_sand.x - 1, new_sand.y + 1) {
-                    new_sand.x -= 1;
-                    new_sand.y += 1;
-                } else if self.is_empty(new_sand.x + 1, new_sand.y + 1) {
-                    new_sand.x += 1;
-                    new_sand.y += 1;
-                } else {
-                    self.sand.insert(new_sand);
-                    still_falling = false;
-                    if use_floor && new_sand == self.sand_spawn {
-                        done = true;
-                    }
-                }
-                if !use_floor && new_sand.y > self.lowest_wall_point {
-                    still_falling = false;
-                    done = true;
-                }
+    /// Drops sand until it starts falling into the abyss, or until it piles up to the source when
+    /// there is a floor.
+    pub fn simulate_sand(&mut self, use_floor: bool) {
+        // sand stops once it falls into the abyss or piles up to the spawn
+        while let Some(sand) = self.drop_sand(use_floor) {
+            if sand == self.sand_spawn {
+                break;
             }
         }
     }
 
-    pub(crate) fn count_sand(&self) -> u32 {
+    /// Drops a single unit of sand from the spawn and returns where it came to rest.
+    ///
+    /// Without a floor, sand that falls past the lowest wall never comes to rest and `None` is
+    /// returned.
+    fn drop_sand(&mut self, use_floor: bool) -> Option<Coordinates> {
+        let mut new_sand = self.sand_spawn;
+        loop {
+            if self.is_empty(new_sand.x, new_sand.y + 1) {
+                new_sand.y += 1;
+            } else if self.is_empty(new_sand.x - 1, new_sand.y + 1) {
+                new_sand.x -= 1;
+                new_sand.y += 1;
+            } else if self.is_empty(new_sand.x + 1, new_sand.y + 1) {
+                new_sand.x += 1;
+                new_sand.y += 1;
+            } else {
+                self.sand.insert(new_sand);
+                return Some(new_sand);
+            }
+            if !use_floor && new_sand.y > self.lowest_wall_point {
+                return None;
+            }
+        }
+    }
+
+    /// Returns the number of units of sand that have come to rest.
+    pub fn count_sand(&self) -> u32 {
         self.sand.len() as u32
     }
 
-    fn is_empty(&self, x: u32, y: u32) -> bool {
+    /// Checks whether a square is rock, where x grows to the right and y grows downwards.
+    pub fn is_rock(&self, x: i32, y: i32) -> bool {
+        self.walls.contains(&Coordinates { x, y })
+    }
+
+    /// Checks whether a unit of sand has come to rest on a square.
+    ///
+    /// With a floor, sand can come to rest left of x=0, where there is no rock.
+    pub fn is_sand(&self, x: i32, y: i32) -> bool {
+        self.sand.contains(&Coordinates { x, y })
+    }
+
+    fn is_empty(&self, x: i32, y: i32) -> bool {
         let loc = Coordinates { x, y };
         !self.walls.contains(&loc) && !self.sand.contains(&loc) && self.cave_floor != y
     }
 }
 
+impl FromStr for Cave {
+    type Err = anyhow::Error;
+
+    /// Parses a scan with one path of rock per line, such as `498,4 -> 498,6 -> 496,6`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cave::new(s)
+    }
+}
+
+/// Pours sand into a [Cave], one unit of sand per step, until the sand falls into the abyss or
+/// piles up to the source.
+///
+/// The state is the number of units of sand that have come to rest.
+#[derive(Debug)]
+pub struct SandSimulation {
+    cave: Cave,
+    use_floor: bool,
+    done: bool,
+    /// Where the most recent unit of sand came to rest.
+    last: Option<Coordinates>,
+}
+
+impl SandSimulation {
+    /// Creates the simulation, with a floor two levels below the lowest rock if `use_floor` is set.
+    pub fn new(cave: Cave, use_floor: bool) -> Self {
+        Self {
+            cave,
+            use_floor,
+            done: false,
+            last: None,
+        }
+    }
+
+    pub fn cave(&self) -> &Cave {
+        &self.cave
+    }
+}
+
+impl Simulation for SandSimulation {
+    type State = u32;
+
+    fn step(&mut self) -> Result<(), anyhow::Error> {
+        if self.done {
+            return Ok(());
+        }
+        match self.cave.drop_sand(self.use_floor) {
+            Some(sand) => {
+                self.last = Some(sand);
+                self.done = sand == self.cave.sand_spawn;
+            }
+            None => self.done = true,
+        }
+        Ok(())
+    }
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    fn state(&self) -> Self::State {
+        self.cave.count_sand()
+    }
+}
+
 fn process_line(input: &str) -> Result<HashSet<Coordinates>, anyhow::Error> {
     let mut walls = HashSet::new();
     let coords = input
         .split("->")
-        .into_iter()
         .map(|raw| raw.trim().parse::<Coordinates>())
         .collect::<Result<Vec<Coordinates>, anyhow::Error>>()?;
     for i in 1..coords.len() {
@@ -143,6 +271,12 @@ fn build_wall(
     Ok(wall_segments)
 }
 
+/// Parses the input for both parts without solving them.
+pub(crate) fn parse(input: &str) -> Result<(), anyhow::Error> {
+    Cave::new(input)?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -162,4 +296,44 @@ mod tests {
         cave.simulate_sand(true);
         assert_eq!(41, cave.count_sand());
     }
+
+    #[test]
+    fn test_cave_too_large() {
+        // every square of a path is stored, so this used to run out of memory
+        let error = Cave::new("0,0 -> 0,4000000000").expect_err("should return error");
+        assert_eq!(
+            "coordinates are larger than 10000: 0,4000000000",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_cave_left_edge() {
+        // a staircase that leads the sand down to x=0, where moving left used to underflow
+        let input = (0..=500)
+            .map(|k| format!("{},{} -> {},{}", 500 - k, k + 1, 500 - k, k + 1))
+            .collect::<Vec<String>>()
+            .join("\n");
+        let mut cave = Cave::new(&input).expect("should return result");
+        cave.simulate_sand(false);
+        assert_eq!(0, cave.count_sand());
+        // with a floor, the first unit comes to rest left of x=0
+        let mut simulation =
+            SandSimulation::new(Cave::new(&input).expect("should return result"), true);
+        simulation.step().expect("should return result");
+        assert!(simulation.cave().is_sand(-1, 502));
+    }
+
+    #[test]
+    fn test_sand_simulation() {
+        let cave = Cave::new("498,5 -> 502,5").expect("should return result");
+        let mut simulation = SandSimulation::new(cave, false);
+        simulation.step().expect("should return result");
+        assert_eq!(1, simulation.state());
+        assert!(simulation.cave().is_sand(500, 4));
+        // the fifth unit of sand falls into the abyss
+        assert_eq!(4, simulation.run().expect("should return result"));
+        assert!(simulation.is_done());
+        assert_eq!(4, simulation.state());
+    }
 }
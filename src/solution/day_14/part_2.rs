@@ -1,11 +1,14 @@
 // TODO: docs
 
-use crate::solution::day_14::Cave;
+use crate::simulation::Simulation;
+use crate::solution::day_14::{Cave, SandSimulation};
 
 pub(crate) fn solve(input: &str) -> Result<String, anyhow::Error> {
-    let mut cave = Cave::new(input)?;
-    cave.simulate_sand(true);
-    Ok(cave.count_sand().to_string())
+    let cave = Cave::new(input)?;
+    crate::metrics::parsed();
+    let mut simulation = SandSimulation::new(cave, true);
+    simulation.run()?;
+    Ok(simulation.cave().count_sand().to_string())
 }
 
 #[cfg(test)]
@@ -0,0 +1,34 @@
+//! Statistics of rock scans.
+
+use crate::solution::day_14::Cave;
+use crate::stats::{range, Stat};
+
+pub(crate) fn stats(input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    let cave = Cave::new(input)?;
+    Ok(vec![
+        Stat::new("rock paths", input.split('\n').count()),
+        Stat::new("rock squares", cave.walls.len()),
+        Stat::new("rock x", range(cave.walls.iter().map(|w| w.x))),
+        Stat::new("rock y", range(cave.walls.iter().map(|w| w.y))),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = stats("498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9")
+            .expect("should return result");
+        assert_eq!(
+            vec![
+                Stat::new("rock paths", 2),
+                Stat::new("rock squares", 20),
+                Stat::new("rock x", "494 to 503"),
+                Stat::new("rock y", "4 to 9"),
+            ],
+            stats
+        );
+    }
+}
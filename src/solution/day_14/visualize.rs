@@ -0,0 +1,116 @@
+//! Animation of sand falling into the cave.
+
+use crate::simulation::Simulation;
+use crate::solution::day_14::{Cave, Coordinates, SandSimulation};
+use crate::visualize::Animation;
+
+pub(crate) struct SandAnimation {
+    simulation: SandSimulation,
+}
+
+impl SandAnimation {
+    /// Creates the animation, with a floor below the lowest wall for part 2.
+    pub(crate) fn new(input: &str, use_floor: bool) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            simulation: SandSimulation::new(Cave::new(input)?, use_floor),
+        })
+    }
+
+    fn square(&self, x: i32, y: i32) -> char {
+        let cave = self.simulation.cave();
+        let coordinates = Coordinates { x, y };
+        if coordinates == cave.sand_spawn && !cave.sand.contains(&coordinates) {
+            '+'
+        } else if cave.walls.contains(&coordinates) {
+            '#'
+        } else if cave.sand.contains(&coordinates) {
+            'o'
+        } else if self.simulation.use_floor && y == cave.cave_floor {
+            '='
+        } else {
+            '.'
+        }
+    }
+}
+
+impl Animation for SandAnimation {
+    fn step(&mut self) -> bool {
+        if self.simulation.is_done() {
+            return false;
+        }
+        // pouring sand can't fail
+        self.simulation.step().is_ok()
+    }
+
+    fn status(&self) -> String {
+        let state = match self.simulation.is_done() {
+            true => "finished",
+            false => "falling",
+        };
+        format!(
+            "Units of sand at rest: {} | {}",
+            self.simulation.state(),
+            state
+        )
+    }
+
+    fn render(&self, width: usize, height: usize) -> Vec<String> {
+        // center on the spawn horizontally and keep the latest sand in view vertically
+        let cave = self.simulation.cave();
+        let last = self.simulation.last.unwrap_or(cave.sand_spawn);
+        let (width, height) = (width as i32, height as i32);
+        let left = cave.sand_spawn.x - width / 2;
+        let bottom = cave.cave_floor + 1;
+        let top = (last.y - height / 2).min(bottom - height).max(0);
+        let mut lines = Vec::new();
+        for y in top..(top + height).min(bottom) {
+            lines.push((left..left + width).map(|x| self.square(x, y)).collect());
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn test_step() {
+        let mut animation = SandAnimation::new(INPUT, false).expect("should return result");
+        assert!(animation.step());
+        assert!(animation.step());
+        assert_eq!(
+            vec![
+                ".....+....",
+                "..........",
+                "..........",
+                "..........",
+                "...#...##.",
+                "...#...#..",
+                ".###...#..",
+                ".......#..",
+                "....oo.#..",
+                "########..",
+                "..........",
+                "..........",
+            ],
+            animation.render(10, 12)
+        );
+        while animation.step() {}
+        assert_eq!("Units of sand at rest: 24 | finished", animation.status());
+    }
+
+    #[test]
+    fn test_floor() {
+        let mut animation = SandAnimation::new(INPUT, true).expect("should return result");
+        while animation.step() {}
+        assert_eq!("Units of sand at rest: 93 | finished", animation.status());
+        // the view follows the sand all the way up to the blocked spawn
+        let lines = animation.render(10, 4);
+        assert_eq!(4, lines.len());
+        assert_eq!(Some('o'), lines[0].chars().nth(5));
+    }
+}
@@ -1,30 +1,68 @@
 //! Solvers for each day and part.
 //!
 //! Each day is separated into its own module containing the solver for each part and any
-//! day-specific helpers or structs. All solvers are exposed via a top-level [solve] function.
+//! day-specific helpers or structs. All solvers are exposed via a top-level [solve] function, which
+//! prefers any solver registered through [crate::registry] over the built-in one.
+//!
+//! The built-in solvers are all for [DEFAULT_YEAR]. Solvers for other years can only be registered,
+//! and their inputs are kept in a directory per year, such as `input/2023/day_01/input.txt`.
+//!
+//! The day modules are also re-exported from the crate root, such as [crate::day_13], so that the
+//! models the solvers parse can be used directly. Only the items marked `pub` in a day module are
+//! part of the library's API; everything else may change between releases.
 
+use crate::check::Problem;
+use crate::error::{Categorize, ErrorKind};
+use crate::generate::Generator;
+use crate::registry;
+use crate::repl::Explorer;
+use crate::stats::Stat;
+use crate::visualize::Animation;
 use anyhow::{anyhow, Context};
 use std::fs;
+use std::io::BufRead;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+
+/// The year of the built-in solvers, which is used when no year is given.
+pub const DEFAULT_YEAR: u16 = 2022;
 
-mod day_01;
-mod day_02;
-mod day_03;
-mod day_04;
-mod day_05;
-mod day_06;
-mod day_07;
-mod day_08;
-mod day_09;
-mod day_10;
-mod day_11;
-mod day_12;
-mod day_13;
-mod day_14;
+/// The first year that Advent of Code was held.
+const FIRST_YEAR: u16 = 2015;
 
 /// Type alias for the function signature that each solver uses.
 type Solver = fn(&str) -> Result<String, anyhow::Error>;
 
-/// Executes the selected day and part's solver using either the provided input or default path.
+/// Type alias for the function signature that each streaming solver uses.
+type StreamingSolver = fn(&mut dyn BufRead) -> Result<String, anyhow::Error>;
+
+/// Type alias for the function signature that each day's parser uses.
+type Parser = fn(&str) -> Result<(), anyhow::Error>;
+
+/// Type alias for the function signature that each day's checker uses.
+type Checker = fn(&str) -> Vec<Problem>;
+
+/// Type alias for the function signature that each day's statistics use.
+type Summarizer = fn(&str) -> Result<Vec<Stat>, anyhow::Error>;
+
+/// Executes the selected day and part's solver for [DEFAULT_YEAR] using either the provided input
+/// or default path.
 ///
 /// Each solver is implemented to process the given day and part's input exactly as given by the
 /// [Advent of Code](https://adventofcode.com/) website, so you should not need to make any
@@ -38,60 +76,547 @@ type Solver = fn(&str) -> Result<String, anyhow::Error>;
 /// ```
 /// // executes the solver for the first part of the first day using the default file path
 /// let answer = aoc::solve(1, 1, None).unwrap();
-/// assert_eq!("69626", answer);
+/// // answers aren't published, so check the answer against its stored hash
+/// assert!(aoc::verify::check(2022, 1, 1, None, &answer).unwrap().is_match());
 /// ```
 pub fn solve(day: u8, part: u8, file: Option<String>) -> Result<String, anyhow::Error> {
-    // list of each day and part's solvers
-    let fn_list: Vec<Vec<Solver>> = vec![
-        vec![day_01::part_1::solve, day_01::part_2::solve],
-        vec![day_02::part_1::solve, day_02::part_2::solve],
-        vec![day_03::part_1::solve, day_03::part_2::solve],
-        vec![day_04::part_1::solve, day_04::part_2::solve],
-        vec![day_05::part_1::solve, day_05::part_2::solve],
-        vec![day_06::part_1::solve, day_06::part_2::solve],
-        vec![day_07::part_1::solve, day_07::part_2::solve],
-        vec![day_08::part_1::solve, day_08::part_2::solve],
-        vec![day_09::part_1::solve, day_09::part_2::solve],
-        vec![day_10::part_1::solve, day_10::part_2::solve],
-        vec![day_11::part_1::solve, day_11::part_2::solve],
-        vec![day_12::part_1::solve, day_12::part_2::solve],
-        vec![day_13::part_1::solve, day_13::part_2::solve],
-        vec![day_14::part_1::solve, day_14::part_2::solve],
-    ];
-    // check that the provided day has an associated function set from the fn_list
-    if !(1..=14).contains(&day) {
-        return Err(anyhow!("invalid day: {}", day));
+    solve_year(DEFAULT_YEAR, day, part, file)
+}
+
+/// Executes the selected year, day and part's solver using either the provided input or default
+/// path, like [solve] does for [DEFAULT_YEAR].
+///
+/// # Examples
+///
+/// ```
+/// let answer = aoc::solve_year(2022, 1, 1, None).unwrap();
+/// assert_eq!(aoc::solve(1, 1, None).unwrap(), answer);
+/// ```
+pub fn solve_year(
+    year: u16,
+    day: u8,
+    part: u8,
+    file: Option<String>,
+) -> Result<String, anyhow::Error> {
+    // read the input before solving so that an invalid day or part is reported first
+    let contents = read_input_year(year, day, part, file)?;
+    // execute selected solver
+    solve_input_year(year, day, part, &contents)
+}
+
+/// Reads the input for the selected day and part of [DEFAULT_YEAR] using either the provided or
+/// default path.
+///
+/// An error is returned if the day or part is invalid or if there is an issue reading the file.
+pub fn read_input(day: u8, part: u8, file: Option<String>) -> Result<String, anyhow::Error> {
+    read_input_year(DEFAULT_YEAR, day, part, file)
+}
+
+/// Reads the input for the selected year, day and part using either the provided or default path.
+///
+/// An error is returned if the year, day or part is invalid or if there is an issue reading the
+/// file.
+pub fn read_input_year(
+    year: u16,
+    day: u8,
+    part: u8,
+    file: Option<String>,
+) -> Result<String, anyhow::Error> {
+    validate(year, day, part)?;
+    // resolve file path
+    let file_path = get_file_path(year, day, part, file)?;
+    // read file
+    read_file_to_string(&file_path)
+}
+
+/// Executes the selected day and part's solver for [DEFAULT_YEAR] on input that has already been
+/// read.
+///
+/// # Examples
+///
+/// ```
+/// let answer = aoc::solve_input(6, 1, "mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+/// assert_eq!("7", answer);
+/// ```
+pub fn solve_input(day: u8, part: u8, input: &str) -> Result<String, anyhow::Error> {
+    solve_input_year(DEFAULT_YEAR, day, part, input)
+}
+
+/// Executes the selected year, day and part's solver on input that has already been read.
+///
+/// # Examples
+///
+/// ```
+/// let answer = aoc::solve_input_year(2022, 6, 1, "mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+/// assert_eq!("7", answer);
+/// ```
+pub fn solve_input_year(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+) -> Result<String, anyhow::Error> {
+    validate(year, day, part)?;
+    // execute selected solver, treating any error it didn't categorize as bad input
+    match registry::solver(year, day, part) {
+        Some(solver) => solver.solve(input),
+        None => solvers()[day as usize - 1][part as usize - 1](input),
+    }
+    .map_err(parse_by_default)
+}
+
+/// Executes the selected day and part's solver for [DEFAULT_YEAR], giving up if it runs for longer
+/// than the timeout.
+///
+/// See [solve_input_with_timeout_year] for how the timeout behaves.
+pub fn solve_input_with_timeout(
+    day: u8,
+    part: u8,
+    input: &str,
+    timeout: Duration,
+) -> Result<String, anyhow::Error> {
+    solve_input_with_timeout_year(DEFAULT_YEAR, day, part, input, timeout)
+}
+
+/// Executes the selected year, day and part's solver, giving up if it runs for longer than the
+/// timeout.
+///
+/// The solver runs on its own thread. Threads cannot be cancelled, so a solver that times out keeps
+/// running in the background until it finishes or the process exits.
+///
+/// An error categorized as [ErrorKind::Timeout] is returned if the solver does not finish in time.
+pub fn solve_input_with_timeout_year(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    timeout: Duration,
+) -> Result<String, anyhow::Error> {
+    validate(year, day, part)?;
+    let input = input.to_string();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        // the receiver is gone if the solver took too long, so there's nobody to tell
+        let _ = tx.send(solve_input_year(year, day, part, &input));
+    });
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => Err(anyhow!(
+            "solver did not finish within {:.3}s",
+            timeout.as_secs_f64()
+        ))
+        .categorize(ErrorKind::Timeout),
+        Err(RecvTimeoutError::Disconnected) => Err(anyhow!("solver stopped without an answer")),
+    }
+}
+
+/// Parses a day's input without solving it, to check that it is well-formed.
+///
+/// Only the built-in days of [DEFAULT_YEAR] have parsers.
+///
+/// Parsing is quick even for inputs that would take a solver a long time, and the same parsing is
+/// used by the solvers, so an input that parses will not be rejected as malformed when solved.
+///
+/// # Examples
+///
+/// ```
+/// assert!(aoc::parse(4, "2-4,6-8").is_ok());
+/// assert!(aoc::parse(4, "2-4").is_err());
+/// ```
+pub fn parse(day: u8, input: &str) -> Result<(), anyhow::Error> {
+    validate_day(day)?;
+    parsers()[day as usize - 1](input).map_err(parse_by_default)
+}
+
+/// Parses a day's input and measures the shape of the parsed model.
+pub(crate) fn stats(day: u8, input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    validate_day(day)?;
+    summarizers()[day as usize - 1](input).map_err(parse_by_default)
+}
+
+/// Returns every year that has a solver, including registered solvers, in order.
+pub fn years() -> Vec<u16> {
+    let mut years = vec![DEFAULT_YEAR];
+    years.extend(registry::registered().into_iter().map(|(year, _, _)| year));
+    years.sort();
+    years.dedup();
+    years
+}
+
+/// Returns every day of [DEFAULT_YEAR] that has a solver, including registered solvers, in order.
+pub fn days() -> Vec<u8> {
+    days_year(DEFAULT_YEAR)
+}
+
+/// Returns every day of a year that has a solver, including registered solvers, in order.
+pub fn days_year(year: u16) -> Vec<u8> {
+    let mut days = match year {
+        DEFAULT_YEAR => builtin_days(),
+        _ => Vec::new(),
+    };
+    days.extend(
+        registry::registered()
+            .into_iter()
+            .filter(|(registered_year, _, _)| *registered_year == year)
+            .map(|(_, day, _)| day),
+    );
+    days.sort();
+    days.dedup();
+    days
+}
+
+/// Returns the parts of a day of [DEFAULT_YEAR] that have a solver, in order.
+///
+/// Every built-in day has both parts, but a registered day may only have one.
+pub fn parts(day: u8) -> Vec<u8> {
+    parts_year(DEFAULT_YEAR, day)
+}
+
+/// Returns the parts of a day of a year that have a solver, in order.
+pub fn parts_year(year: u16, day: u8) -> Vec<u8> {
+    if year == DEFAULT_YEAR && builtin_days().contains(&day) {
+        return vec![1, 2];
+    }
+    registry::registered()
+        .into_iter()
+        .filter(|(registered_year, registered_day, _)| {
+            *registered_year == year && *registered_day == day
+        })
+        .map(|(_, _, part)| part)
+        .collect()
+}
+
+/// Returns every day of [DEFAULT_YEAR] with a built-in solver, in order.
+///
+/// Only these days have parsers, checkers and statistics.
+pub(crate) fn builtin_days() -> Vec<u8> {
+    (1..=solvers().len() as u8).collect()
+}
+
+/// Returns every day that has an explorer, in order.
+pub(crate) fn explorer_days() -> Vec<u8> {
+    vec![7, 11, 12]
+}
+
+/// Parses a day's input into the explorer for that day.
+pub(crate) fn explorer(day: u8, input: &str) -> Result<Box<dyn Explorer>, anyhow::Error> {
+    let explorer: Result<Box<dyn Explorer>, anyhow::Error> = match day {
+        7 => day_07::repl::DirectoryExplorer::new(input).map(|e| Box::new(e) as _),
+        11 => day_11::repl::MonkeyExplorer::new(input).map(|e| Box::new(e) as _),
+        12 => day_12::repl::MapExplorer::new(input).map(|e| Box::new(e) as _),
+        _ => {
+            return Err(anyhow!("no explorer for day {} (try day 7, 11 or 12)", day))
+                .categorize(ErrorKind::Usage)
+        }
+    };
+    explorer.map_err(parse_by_default)
+}
+
+/// Returns every day that has an animation, in order.
+pub(crate) fn animation_days() -> Vec<u8> {
+    vec![5, 9, 10, 11, 14]
+}
+
+/// Parses a day's input into the animation for one of its parts.
+pub(crate) fn animation(
+    day: u8,
+    part: u8,
+    input: &str,
+) -> Result<Box<dyn Animation>, anyhow::Error> {
+    validate(DEFAULT_YEAR, day, part)?;
+    let part_2 = part == 2;
+    let animation: Result<Box<dyn Animation>, anyhow::Error> = match day {
+        5 => day_05::visualize::CraneAnimation::new(input, part_2).map(|a| Box::new(a) as _),
+        9 => {
+            let knots = if part_2 { 9 } else { 1 };
+            day_09::visualize::RopeAnimation::new(input, knots).map(|a| Box::new(a) as _)
+        }
+        10 => day_10::visualize::CrtAnimation::new(input).map(|a| Box::new(a) as _),
+        11 => {
+            let (reduce_worry, rounds) = if part_2 { (None, 10000) } else { (Some(3), 20) };
+            day_11::visualize::MonkeyAnimation::new(input, reduce_worry, rounds)
+                .map(|a| Box::new(a) as _)
+        }
+        14 => day_14::visualize::SandAnimation::new(input, part_2).map(|a| Box::new(a) as _),
+        _ => {
+            return Err(anyhow!(
+                "no animation for day {} (try day 5, 9, 10, 11 or 14)",
+                day
+            ))
+            .categorize(ErrorKind::Usage)
+        }
+    };
+    animation.map_err(parse_by_default)
+}
+
+/// Returns every day that has a streaming solver, in order.
+pub(crate) fn streaming_days() -> Vec<u8> {
+    vec![1, 2, 3, 4, 6, 9, 10]
+}
+
+/// Solves a day's input with its streaming solver, reading the input as it goes.
+///
+/// Registered solvers take the whole input at once, so a day whose built-in solver has been
+/// replaced can't be streamed, rather than silently using the solver it replaced.
+pub(crate) fn solve_reader(
+    day: u8,
+    part: u8,
+    reader: &mut dyn BufRead,
+) -> Result<String, anyhow::Error> {
+    validate(DEFAULT_YEAR, day, part)?;
+    if registry::solver(DEFAULT_YEAR, day, part).is_some() {
+        return Err(anyhow!(
+            "day {} part {} has a registered solver, which can't read a stream",
+            day,
+            part
+        ))
+        .categorize(ErrorKind::Usage);
+    }
+    let solvers: [StreamingSolver; 2] = match day {
+        1 => [day_01::part_1::solve_reader, day_01::part_2::solve_reader],
+        2 => [day_02::part_1::solve_reader, day_02::part_2::solve_reader],
+        3 => [day_03::part_1::solve_reader, day_03::part_2::solve_reader],
+        4 => [day_04::part_1::solve_reader, day_04::part_2::solve_reader],
+        6 => [day_06::part_1::solve_reader, day_06::part_2::solve_reader],
+        9 => [day_09::part_1::solve_reader, day_09::part_2::solve_reader],
+        10 => [day_10::part_1::solve_reader, day_10::part_2::solve_reader],
+        _ => {
+            return Err(anyhow!(
+                "no streaming solver for day {} (try day 1, 2, 3, 4, 6, 9 or 10)",
+                day
+            ))
+            .categorize(ErrorKind::Usage)
+        }
+    };
+    solvers[part as usize - 1](reader).map_err(parse_by_default)
+}
+
+/// Returns every day that has a reference implementation, in order.
+pub(crate) fn reference_days() -> Vec<u8> {
+    vec![4, 11, 13]
+}
+
+/// Solves a day's input with its slow reference implementation instead of its solver.
+pub(crate) fn reference(day: u8, part: u8, input: &str) -> Result<String, anyhow::Error> {
+    reference_solver(day, part)?(input).map_err(parse_by_default)
+}
+
+/// Looks up the reference implementation of a day's part.
+pub(crate) fn reference_solver(day: u8, part: u8) -> Result<Solver, anyhow::Error> {
+    validate(DEFAULT_YEAR, day, part)?;
+    let solvers: [Solver; 2] = match day {
+        4 => [day_04::reference::part_1, day_04::reference::part_2],
+        11 => [day_11::reference::part_1, day_11::reference::part_2],
+        13 => [day_13::reference::part_1, day_13::reference::part_2],
+        _ => {
+            return Err(anyhow!(
+                "no reference implementation for day {} (try day 4, 11 or 13)",
+                day
+            ))
+            .categorize(ErrorKind::Usage)
+        }
+    };
+    Ok(solvers[part as usize - 1])
+}
+
+/// Categorizes an error from a day's code as [ErrorKind::Parse] unless it already has a category.
+///
+/// Almost every failure inside a day's code comes from input that isn't in the expected format.
+fn parse_by_default(error: anyhow::Error) -> anyhow::Error {
+    match ErrorKind::of(&error) {
+        Some(_) => error,
+        None => ErrorKind::Parse.wrap(error),
+    }
+}
+
+/// Lists each day's solvers, where index `0` holds day 1's solvers for both parts.
+fn solvers() -> Vec<[Solver; 2]> {
+    vec![
+        [day_01::part_1::solve, day_01::part_2::solve],
+        [day_02::part_1::solve, day_02::part_2::solve],
+        [day_03::part_1::solve, day_03::part_2::solve],
+        [day_04::part_1::solve, day_04::part_2::solve],
+        [day_05::part_1::solve, day_05::part_2::solve],
+        [day_06::part_1::solve, day_06::part_2::solve],
+        [day_07::part_1::solve, day_07::part_2::solve],
+        [day_08::part_1::solve, day_08::part_2::solve],
+        [day_09::part_1::solve, day_09::part_2::solve],
+        [day_10::part_1::solve, day_10::part_2::solve],
+        [day_11::part_1::solve, day_11::part_2::solve],
+        [day_12::part_1::solve, day_12::part_2::solve],
+        [day_13::part_1::solve, day_13::part_2::solve],
+        [day_14::part_1::solve, day_14::part_2::solve],
+    ]
+}
+
+/// Lists each day's input generator, where index `0` holds day 1's generator.
+pub(crate) fn generators() -> Vec<Generator> {
+    let generator = |generate, unit, default_size, sizes| Generator {
+        generate,
+        unit,
+        default_size,
+        sizes,
+    };
+    vec![
+        generator(day_01::generate::generate, "elves", 250, 1..=100_000),
+        generator(day_02::generate::generate, "rounds", 2500, 1..=1_000_000),
+        generator(
+            day_03::generate::generate,
+            "groups of three elves",
+            100,
+            1..=100_000,
+        ),
+        generator(day_04::generate::generate, "pairs", 1000, 1..=1_000_000),
+        generator(day_05::generate::generate, "moves", 500, 1..=100_000),
+        generator(
+            day_06::generate::generate,
+            "characters",
+            4096,
+            14..=10_000_000,
+        ),
+        generator(day_07::generate::generate, "directories", 180, 1..=10_000),
+        generator(day_08::generate::generate, "trees per side", 99, 1..=500),
+        generator(day_09::generate::generate, "moves", 2000, 1..=100_000),
+        generator(day_10::generate::generate, "cycles", 240, 1..=240),
+        generator(day_11::generate::generate, "monkeys", 8, 2..=9),
+        generator(day_12::generate::generate, "columns", 143, 30..=300),
+        generator(day_13::generate::generate, "pairs", 150, 1..=10_000),
+        generator(day_14::generate::generate, "rock paths", 150, 1..=1000),
+    ]
+}
+
+/// Lists each day's parser, where index `0` holds day 1's parser.
+fn parsers() -> Vec<Parser> {
+    vec![
+        day_01::parse,
+        day_02::parse,
+        day_03::parse,
+        day_04::parse,
+        day_05::parse,
+        day_06::parse,
+        day_07::parse,
+        day_08::parse,
+        day_09::parse,
+        day_10::parse,
+        day_11::parse,
+        day_12::parse,
+        day_13::parse,
+        day_14::parse,
+    ]
+}
+
+/// Lists each day's checker, where index `0` holds day 1's checker.
+pub(crate) fn checkers() -> Vec<Checker> {
+    vec![
+        day_01::check::check,
+        day_02::check::check,
+        day_03::check::check,
+        day_04::check::check,
+        day_05::check::check,
+        day_06::check::check,
+        day_07::check::check,
+        day_08::check::check,
+        day_09::check::check,
+        day_10::check::check,
+        day_11::check::check,
+        day_12::check::check,
+        day_13::check::check,
+        day_14::check::check,
+    ]
+}
+
+/// Lists each day's statistics, where index `0` holds day 1's statistics.
+fn summarizers() -> Vec<Summarizer> {
+    vec![
+        day_01::stats::stats,
+        day_02::stats::stats,
+        day_03::stats::stats,
+        day_04::stats::stats,
+        day_05::stats::stats,
+        day_06::stats::stats,
+        day_07::stats::stats,
+        day_08::stats::stats,
+        day_09::stats::stats,
+        day_10::stats::stats,
+        day_11::stats::stats,
+        day_12::stats::stats,
+        day_13::stats::stats,
+        day_14::stats::stats,
+    ]
+}
+
+/// Checks that the year, day and part have an associated solver, either built in or registered.
+pub(crate) fn validate(year: u16, day: u8, part: u8) -> Result<(), anyhow::Error> {
+    validate_year(year)?;
+    let parts = parts_year(year, day);
+    if parts.is_empty() {
+        return Err(anyhow!("invalid day: {}", day)).categorize(ErrorKind::Usage);
     }
     // each day has only part 1 or part 2
-    if !(1..=2).contains(&part) {
-        return Err(anyhow!("invalid part: {}", part));
+    if !parts.contains(&part) {
+        return Err(anyhow!("invalid part: {}", part)).categorize(ErrorKind::Usage);
     }
-    // resolve file path
-    let file_path = get_file_path(day, part, file)?;
-    // read file
-    let contents = read_file_to_string(&file_path)?;
-    // execute selected solver
-    fn_list[day as usize - 1][part as usize - 1](&contents)
+    Ok(())
+}
+
+/// Checks that Advent of Code was held in the year.
+pub(crate) fn validate_year(year: u16) -> Result<(), anyhow::Error> {
+    if year < FIRST_YEAR {
+        return Err(anyhow!("invalid year: {}", year)).categorize(ErrorKind::Usage);
+    }
+    Ok(())
+}
+
+/// Checks that the day has a built-in solver.
+fn validate_day(day: u8) -> Result<(), anyhow::Error> {
+    // check that the provided day has an associated function set from the solvers
+    if day == 0 || day as usize > solvers().len() {
+        return Err(anyhow!("invalid day: {}", day)).categorize(ErrorKind::Usage);
+    }
+    Ok(())
 }
 
 /// Resolves the file path for the input data.
 ///
 /// This helper either returns the file path that was provided, or builds a file path based on the
-/// default structure: `input/day_XX/input.txt`. It is assumed that the `input` directory exists in
-/// the current working directory.
+/// default structure: `input/day_XX/input.txt` for [DEFAULT_YEAR] and `input/YYYY/day_XX/input.txt`
+/// for any other year. It is assumed that the `input` directory exists in the current working
+/// directory.
 ///
 /// `XX` is a padded integer representation of the selected day (e.g. `01`, `06`, `11`).
-fn get_file_path(day: u8, _: u8, file: Option<String>) -> Result<String, anyhow::Error> {
+pub(crate) fn get_file_path(
+    year: u16,
+    day: u8,
+    _: u8,
+    file: Option<String>,
+) -> Result<String, anyhow::Error> {
     // if the user provided a file path just return that
     if let Some(existing_path) = file {
         return Ok(existing_path);
     }
     // build the file path based on the default directory structure
-    Ok(format!("input/day_{:02}/input.txt", day))
+    match year {
+        // the built-in year's inputs were here before there were other years
+        DEFAULT_YEAR => Ok(format!("input/day_{:02}/input.txt", day)),
+        _ => Ok(format!("input/{}/day_{:02}/input.txt", year, day)),
+    }
+}
+
+/// Builds the path to a day's puzzle description, laid out the same way as the inputs:
+/// `docs/day_XX.md` for [DEFAULT_YEAR] and `docs/YYYY/day_XX.md` for any other year.
+pub(crate) fn get_docs_path(year: u16, day: u8) -> String {
+    match year {
+        DEFAULT_YEAR => format!("docs/day_{:02}.md", day),
+        _ => format!("docs/{}/day_{:02}.md", year, day),
+    }
 }
 
 /// Reads the input file into a string or returns an error if there is an issue reading the file.
 fn read_file_to_string(file_path: &str) -> Result<String, anyhow::Error> {
-    fs::read_to_string(&file_path)
+    let bytes = fs::read(file_path)
+        .with_context(|| format!("could not read input file: {}", &file_path))
+        .categorize(ErrorKind::Io)?;
+    String::from_utf8(bytes)
+        .map_err(|_| crate::error::invalid_utf8())
         .with_context(|| format!("could not read input file: {}", &file_path))
 }
@@ -0,0 +1,87 @@
+//! Statistics describing the shape of puzzle inputs.
+//!
+//! Knowing how big an input is helps when deciding whether a solver's approach will hold up, or
+//! when comparing a generated input against a real one. Each day reports a few metrics measured
+//! from the model its solvers parse, such as the number of elves on day 1, the size of the grid on
+//! days 8 and 12, or how deeply the packets on day 13 are nested.
+
+use std::fmt::{Display, Formatter};
+
+/// A named measurement of an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stat {
+    /// What is measured, such as `elves` or `deepest directory`.
+    pub name: &'static str,
+    pub value: String,
+}
+
+impl Stat {
+    pub(crate) fn new(name: &'static str, value: impl ToString) -> Self {
+        Self {
+            name,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Display for Stat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.name, self.value)
+    }
+}
+
+/// Returns every day that has statistics, in order.
+pub fn days() -> Vec<u8> {
+    crate::solution::builtin_days()
+}
+
+/// Parses a day's input and measures it.
+///
+/// An error is returned if the day has no solver or if the input cannot be parsed.
+///
+/// # Examples
+///
+/// ```
+/// let stats = aoc::stats::stats(8, "303\n255\n653\n335").unwrap();
+/// assert_eq!("columns: 3", stats[0].to_string());
+/// assert_eq!("rows: 4", stats[1].to_string());
+/// ```
+pub fn stats(day: u8, input: &str) -> Result<Vec<Stat>, anyhow::Error> {
+    crate::solution::stats(day, input)
+}
+
+/// Formats the smallest and largest of some values as a range, such as `4 to 9`.
+pub(crate) fn range<T: Ord + Display>(values: impl IntoIterator<Item = T>) -> String {
+    let values = values.into_iter().collect::<Vec<T>>();
+    match (values.iter().min(), values.iter().max()) {
+        (Some(min), Some(max)) if min != max => format!("{} to {}", min, max),
+        (Some(min), _) => min.to_string(),
+        _ => String::from("none"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn test_stats() {
+        for day in days() {
+            let input = crate::read_input(day, 1, None).expect("should return result");
+            let stats = stats(day, &input).expect("should return result");
+            assert!(!stats.is_empty(), "day {}", day);
+        }
+        let error = stats(15, "").expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+        let error = stats(8, "3x3").expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Parse), ErrorKind::of(&error));
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!("none", range(Vec::<u32>::new()));
+        assert_eq!("3", range([3, 3]));
+        assert_eq!("-2 to 7", range([4, -2, 7, 0]));
+    }
+}
@@ -0,0 +1,229 @@
+//! Solving inputs too large to read into memory at once.
+//!
+//! Solvers normally read the whole input into a string and parse all of it before solving, which
+//! doesn't work for generated stress inputs of many gigabytes. The days whose input is a list of
+//! lines, or a single stream of characters on day 6, also have a streaming solver that reads its
+//! input a piece at a time. Each one only keeps what it needs for the answer, such as the three
+//! largest totals on day 1 or the places the rope's tail has visited on day 9, so memory use
+//! doesn't grow with the size of the input.
+//!
+//! Streaming solvers give the same answers as the other solvers. For an input with several
+//! problems the first one reached is reported, which may not be the one the other solver reports,
+//! and day 6 stops reading once it has found its marker, so nothing after it is checked.
+//!
+//! ```
+//! let answer = aoc::stream::solve(1, 2, None).unwrap();
+//! assert_eq!(answer, aoc::solve(1, 2, None).unwrap());
+//! ```
+
+use crate::error::{Categorize, ErrorKind};
+use anyhow::Context;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+/// Returns every day that has a streaming solver, in order.
+pub fn days() -> Vec<u8> {
+    crate::solution::streaming_days()
+}
+
+/// Solves a day and part of [crate::DEFAULT_YEAR] by streaming its input file, using the default
+/// input path if no file is provided.
+pub fn solve(day: u8, part: u8, file: Option<String>) -> Result<String, anyhow::Error> {
+    crate::solution::validate(crate::DEFAULT_YEAR, day, part)?;
+    let path = crate::solution::get_file_path(crate::DEFAULT_YEAR, day, part, file)?;
+    let file = File::open(&path)
+        .with_context(|| format!("could not read input file: {}", path))
+        .categorize(ErrorKind::Io)?;
+    solve_reader(day, part, BufReader::new(file))
+}
+
+/// Solves a day and part of [crate::DEFAULT_YEAR], reading the input from a reader as it goes.
+///
+/// An error is returned if the day has no streaming solver, the part is invalid, a registered
+/// solver has replaced the built-in one, or the input cannot be read or solved.
+///
+/// # Examples
+///
+/// ```
+/// let answer = aoc::stream::solve_reader(4, 2, "2-4,6-8\n5-7,7-9".as_bytes()).unwrap();
+/// assert_eq!("1", answer);
+/// ```
+pub fn solve_reader(day: u8, part: u8, mut reader: impl BufRead) -> Result<String, anyhow::Error> {
+    crate::solution::solve_reader(day, part, &mut reader)
+}
+
+/// Calls `f` with each line of the input, without its line break.
+///
+/// Lines are split the same way as `input.split('\n')`, so an input ending in a line break has an
+/// empty last line, and an empty input has a single empty line.
+pub(crate) fn for_each_line(
+    reader: &mut dyn BufRead,
+    mut f: impl FnMut(&str) -> Result<(), anyhow::Error>,
+) -> Result<(), anyhow::Error> {
+    let mut buffer = Vec::new();
+    loop {
+        buffer.clear();
+        reader
+            .read_until(b'\n', &mut buffer)
+            .context("could not read input")
+            .categorize(ErrorKind::Io)?;
+        let ends_line = buffer.last() == Some(&b'\n');
+        if ends_line {
+            buffer.pop();
+        }
+        let line = std::str::from_utf8(&buffer).map_err(|_| crate::error::invalid_utf8())?;
+        f(line)?;
+        if !ends_line {
+            return Ok(());
+        }
+    }
+}
+
+/// Reads the characters of the input one at a time.
+pub(crate) fn chars(reader: &mut dyn BufRead) -> Chars<'_> {
+    Chars {
+        reader,
+        decoded: Vec::new(),
+        next: 0,
+        partial: Vec::new(),
+    }
+}
+
+/// An iterator over the characters of an input, decoded a buffer at a time.
+pub(crate) struct Chars<'a> {
+    reader: &'a mut dyn BufRead,
+    decoded: Vec<char>,
+    next: usize,
+    /// The start of a character that was cut off at the end of the last buffer.
+    partial: Vec<u8>,
+}
+
+impl Chars<'_> {
+    /// Decodes the next buffer of input, returning false at the end of the input.
+    fn refill(&mut self) -> Result<bool, anyhow::Error> {
+        let invalid = crate::error::invalid_utf8;
+        let buffer = self
+            .reader
+            .fill_buf()
+            .context("could not read input")
+            .categorize(ErrorKind::Io)?;
+        if buffer.is_empty() {
+            return match self.partial.is_empty() {
+                true => Ok(false),
+                false => Err(invalid()),
+            };
+        }
+        let mut bytes = std::mem::take(&mut self.partial);
+        bytes.extend_from_slice(buffer);
+        let read = buffer.len();
+        self.reader.consume(read);
+        let valid = match std::str::from_utf8(&bytes) {
+            Ok(text) => text.len(),
+            // a character cut off at the end is finished by the next buffer
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => return Err(invalid()),
+        };
+        self.partial = bytes.split_off(valid);
+        self.decoded = String::from_utf8(bytes)
+            .map_err(|_| invalid())?
+            .chars()
+            .collect();
+        self.next = 0;
+        Ok(true)
+    }
+}
+
+impl Iterator for Chars<'_> {
+    type Item = Result<char, anyhow::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next == self.decoded.len() {
+            match self.refill() {
+                Ok(true) => continue,
+                Ok(false) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        self.next += 1;
+        Some(Ok(self.decoded[self.next - 1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    fn lines(input: &[u8]) -> Result<Vec<String>, anyhow::Error> {
+        let mut lines = Vec::new();
+        for_each_line(&mut &input[..], |line| {
+            lines.push(line.to_string());
+            Ok(())
+        })?;
+        Ok(lines)
+    }
+
+    #[test]
+    fn test_for_each_line() {
+        for input in ["", "a", "a\nb", "a\n", "a\n\nb\n\n"] {
+            let expected = input.split('\n').collect::<Vec<&str>>();
+            assert_eq!(
+                expected,
+                lines(input.as_bytes()).expect("should return result")
+            );
+        }
+        let error = lines(b"a\n\xff").expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Parse), ErrorKind::of(&error));
+    }
+
+    #[test]
+    fn test_chars() {
+        let input = "añb€c";
+        // a tiny buffer cuts the characters that take several bytes in half
+        let mut reader = BufReader::with_capacity(1, input.as_bytes());
+        let decoded = chars(&mut reader)
+            .collect::<Result<String, anyhow::Error>>()
+            .expect("should return result");
+        assert_eq!(input, decoded);
+        let mut reader = BufReader::with_capacity(1, &b"a\xe2\x82"[..]);
+        let error = chars(&mut reader)
+            .collect::<Result<String, anyhow::Error>>()
+            .expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Parse), ErrorKind::of(&error));
+    }
+
+    #[test]
+    fn test_solve_reader() {
+        for day in days() {
+            for part in 1..=2 {
+                let mut inputs =
+                    vec![crate::read_input(day, part, None).expect("should return result")];
+                for seed in 0..3 {
+                    inputs.push(
+                        crate::generate::generate(day, seed, None).expect("should return result"),
+                    );
+                }
+                for input in inputs {
+                    assert_eq!(
+                        crate::solve_input(day, part, &input).expect("should return result"),
+                        solve_reader(day, part, input.as_bytes()).expect("should return result"),
+                        "day {} part {}",
+                        day,
+                        part
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_reader_errors() {
+        let error = solve_reader(5, 1, "".as_bytes()).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+        let error = solve_reader(4, 1, "2-4,6".as_bytes()).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Parse), ErrorKind::of(&error));
+        let error =
+            solve(1, 1, Some("does/not/exist.txt".to_string())).expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Io), ErrorKind::of(&error));
+    }
+}
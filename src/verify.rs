@@ -0,0 +1,284 @@
+//! Verifies solver output against stored answers without revealing them.
+//!
+//! Puzzle answers should not be published, so expected answers are never stored in plaintext.
+//! Instead, each input file has an answers file next to it with the same name and an `.answers`
+//! extension (e.g. `input/day_01/input.answers`). Each line of that file holds a part number, a
+//! random salt and the SHA-256 hash of the salt followed by the answer:
+//!
+//! ```text
+//! 1 3f9a0c2e7d41b85a 4c0b...e19d
+//! ```
+//!
+//! An answer is verified by hashing it with the stored salt and comparing the result to the stored
+//! hash, so a mismatch can be reported without ever knowing the expected value.
+//!
+//! The hashes only stop answers from being read by accident, such as when browsing the repository.
+//! SHA-256 is fast and most answers are small numbers, so anyone who wants an answer can find it
+//! in seconds by hashing every number up to it with the stored salt.
+
+use crate::error::{Categorize, ErrorKind};
+use anyhow::{anyhow, Context};
+use sha2::{Digest, Sha256};
+use std::collections::hash_map::RandomState;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::hash::{BuildHasher, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The result of checking an answer against the answers store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verification {
+    /// The answer hashes to the stored hash.
+    Match,
+    /// The answer hashes to something else.
+    Mismatch,
+}
+
+impl Verification {
+    /// Returns true if the answer matched.
+    pub fn is_match(&self) -> bool {
+        *self == Verification::Match
+    }
+}
+
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verification::Match => write!(f, "match"),
+            Verification::Mismatch => write!(f, "mismatch"),
+        }
+    }
+}
+
+/// A salted hash of an expected answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashedAnswer {
+    salt: String,
+    hash: String,
+}
+
+impl HashedAnswer {
+    /// Hashes an answer with a new random salt.
+    pub fn new(answer: &str) -> Self {
+        let salt = random_salt();
+        let hash = hash_answer(&salt, answer);
+        Self { salt, hash }
+    }
+
+    /// Checks whether an answer hashes to this hash.
+    pub fn verify(&self, answer: &str) -> Verification {
+        match hash_answer(&self.salt, answer) == self.hash {
+            true => Verification::Match,
+            false => Verification::Mismatch,
+        }
+    }
+}
+
+/// The hashed answers for each part of a single input file.
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    answers: BTreeMap<u8, HashedAnswer>,
+}
+
+impl AnswerStore {
+    /// Loads the answers file for an input file.
+    ///
+    /// An answers file that does not exist is treated as empty. An error is returned if the file
+    /// cannot be read or any line is malformed.
+    pub fn load(input_path: &str) -> Result<Self, anyhow::Error> {
+        let path = answers_path(input_path);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("could not read answers file: {}", path.display()))
+            .categorize(ErrorKind::Io)?;
+        let mut answers = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (part, answer) = parse_line(line)
+                .with_context(|| format!("invalid answer on line {} of {}", i + 1, path.display()))
+                .categorize(ErrorKind::Parse)?;
+            answers.insert(part, answer);
+        }
+        Ok(Self { answers })
+    }
+
+    /// Writes the answers file for an input file.
+    pub fn save(&self, input_path: &str) -> Result<(), anyhow::Error> {
+        let path = answers_path(input_path);
+        let mut contents = String::new();
+        for (part, answer) in &self.answers {
+            contents.push_str(&format!("{} {} {}\n", part, answer.salt, answer.hash));
+        }
+        fs::write(&path, contents)
+            .with_context(|| format!("could not write answers file: {}", path.display()))
+            .categorize(ErrorKind::Io)
+    }
+
+    /// Returns the hashed answer for a part, if one has been stored.
+    pub fn get(&self, part: u8) -> Option<&HashedAnswer> {
+        self.answers.get(&part)
+    }
+
+    /// Stores the hash of an answer for a part, replacing any previous answer.
+    pub fn insert(&mut self, part: u8, answer: &str) {
+        self.answers.insert(part, HashedAnswer::new(answer));
+    }
+}
+
+/// Checks an answer against the stored answer for the selected year, day, part and input file.
+///
+/// The default input path is used if no file is provided. An error is returned if the answers
+/// file cannot be read or has no answer for the part.
+///
+/// # Examples
+///
+/// ```
+/// let answer = aoc::solve(1, 1, None).unwrap();
+/// assert!(aoc::verify::check(2022, 1, 1, None, &answer).unwrap().is_match());
+/// ```
+pub fn check(
+    year: u16,
+    day: u8,
+    part: u8,
+    file: Option<String>,
+    answer: &str,
+) -> Result<Verification, anyhow::Error> {
+    let input_path = crate::solution::get_file_path(year, day, part, file)?;
+    let store = AnswerStore::load(&input_path)?;
+    let expected = store
+        .get(part)
+        .ok_or_else(|| {
+            anyhow!(
+                "no stored answer for part {} in {}",
+                part,
+                answers_path(&input_path).display()
+            )
+        })
+        .categorize(ErrorKind::Io)?;
+    Ok(expected.verify(answer))
+}
+
+/// Solves the selected year, day and part and checks the answer against the stored answer.
+pub fn verify(
+    year: u16,
+    day: u8,
+    part: u8,
+    file: Option<String>,
+) -> Result<Verification, anyhow::Error> {
+    let answer = crate::solve_year(year, day, part, file.clone())?;
+    check(year, day, part, file, &answer)
+}
+
+/// Solves the selected year, day and part and stores the hash of the answer.
+///
+/// This is how expected answers are added to the store. The answer itself is returned so that it
+/// can be reviewed before the answers file is committed.
+pub fn store(year: u16, day: u8, part: u8, file: Option<String>) -> Result<String, anyhow::Error> {
+    let answer = crate::solve_year(year, day, part, file.clone())?;
+    let input_path = crate::solution::get_file_path(year, day, part, file)?;
+    let mut store = AnswerStore::load(&input_path)?;
+    store.insert(part, &answer);
+    store.save(&input_path)?;
+    Ok(answer)
+}
+
+/// Resolves the answers file that sits next to an input file.
+fn answers_path(input_path: &str) -> PathBuf {
+    Path::new(input_path).with_extension("answers")
+}
+
+/// Parses a `<part> <salt> <hash>` line of an answers file.
+fn parse_line(line: &str) -> Result<(u8, HashedAnswer), anyhow::Error> {
+    let parts = line.split(' ').collect::<Vec<&str>>();
+    if parts.len() != 3 {
+        return Err(anyhow!("expected part, salt and hash: {}", line));
+    }
+    let part = parts[0]
+        .parse::<u8>()
+        .with_context(|| format!("could not parse token to u8: {}", parts[0]))?;
+    let hash = parts[2].to_lowercase();
+    if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(anyhow!("invalid SHA-256 hash: {}", parts[2]));
+    }
+    Ok((
+        part,
+        HashedAnswer {
+            salt: parts[1].to_string(),
+            hash,
+        },
+    ))
+}
+
+/// Hashes the salt followed by the answer with SHA-256 and returns the hex digest.
+///
+/// This is deliberately a single fast hash, see the module documentation for what it protects.
+fn hash_answer(salt: &str, answer: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(salt.as_bytes());
+    hasher.update(answer.as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Generates a random 64-bit salt as hex.
+///
+/// The standard library's randomly keyed hasher is mixed with the current time, which is plenty
+/// for a salt whose only job is to stop precomputed lookups of small numbers.
+fn random_salt() -> String {
+    let mut hasher = RandomState::new().build_hasher();
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    hasher.write_u128(nanos);
+    format!("{:016x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_answer() {
+        // the salt is prepended to the answer, so this is the SHA-256 digest of "abc"
+        assert_eq!(
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+            hash_answer("a", "bc")
+        );
+        assert_ne!(hash_answer("a", "answer"), hash_answer("b", "answer"));
+    }
+
+    #[test]
+    fn test_hashed_answer_verify() {
+        let hashed = HashedAnswer::new("24000");
+        assert_eq!(Verification::Match, hashed.verify("24000"));
+        assert_eq!(Verification::Mismatch, hashed.verify("24001"));
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let line = format!("2 abc {}", hash_answer("abc", "45000"));
+        let (part, hashed) = parse_line(&line).expect("should return result");
+        assert_eq!(2, part);
+        assert!(hashed.verify("45000").is_match());
+        assert!(parse_line("2 abc 45000").is_err());
+        assert!(parse_line("2 abc").is_err());
+    }
+
+    #[test]
+    fn test_answers_path() {
+        assert_eq!(
+            PathBuf::from("input/day_01/input.answers"),
+            answers_path("input/day_01/input.txt")
+        );
+    }
+}
@@ -0,0 +1,323 @@
+//! Terminal animations of the simulation days.
+//!
+//! Several puzzles are simulations whose answer is only the final number: crates moving between
+//! stacks on day 5, rope knots on day 9, the CRT being drawn on day 10, monkeys passing items on
+//! day 11 and sand falling on day 14. An [Animation] runs one of those simulations a step at a time
+//! and draws its state as text, and [play] turns it into frames using plain ANSI escape codes.
+//!
+//! The size of the terminal is checked before every frame, so the picture adapts when the window
+//! is resized.
+
+use crate::error::{Categorize, ErrorKind};
+use anyhow::{anyhow, Context};
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Moves the cursor to the top left corner without clearing, so frames don't flicker.
+const CURSOR_HOME: &str = "\x1b[H";
+/// Clears from the cursor to the end of the line.
+const CLEAR_LINE: &str = "\x1b[K";
+/// Clears from the cursor to the end of the screen.
+const CLEAR_BELOW: &str = "\x1b[J";
+/// Clears the whole screen.
+const CLEAR_SCREEN: &str = "\x1b[2J";
+/// Bold text, used for the status line.
+const BOLD: &str = "\x1b[1m";
+/// Resets all text attributes.
+const RESET: &str = "\x1b[0m";
+
+/// Switches to the alternate screen and hides the cursor, so the animation doesn't scroll away
+/// whatever was in the terminal before.
+pub const ENTER_SCREEN: &str = "\x1b[?1049h\x1b[?25l";
+/// Restores the cursor and the screen that was shown before [ENTER_SCREEN].
+pub const LEAVE_SCREEN: &str = "\x1b[0m\x1b[?25h\x1b[?1049l";
+
+/// Longest pause between checks for a stop request while waiting for the next frame.
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A simulation that can be advanced one step at a time and drawn as text.
+pub trait Animation {
+    /// Advances the simulation by a single step.
+    ///
+    /// Returns false without changing anything if the simulation has already finished.
+    fn step(&mut self) -> bool;
+
+    /// Describes the current state in a single line, such as the step count and running answer.
+    fn status(&self) -> String;
+
+    /// Draws the current state as lines of text that fit within the given columns and rows.
+    ///
+    /// Lines that are too long are cut off when drawn, so an animation only needs to use the size
+    /// to decide which part of a large picture to show.
+    fn render(&self, width: usize, height: usize) -> Vec<String>;
+}
+
+/// How fast to play an animation.
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Frames to draw per second, or `0` to draw as fast as possible.
+    pub fps: f64,
+    /// Simulation steps to take between frames.
+    pub steps: usize,
+    /// Stop after drawing this many frames, even if the simulation hasn't finished.
+    pub frames: Option<usize>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            fps: 30.0,
+            steps: 1,
+            frames: None,
+        }
+    }
+}
+
+/// Returns every day that has an animation, in order.
+pub fn days() -> Vec<u8> {
+    crate::solution::animation_days()
+}
+
+/// Parses a day's input and returns the animation for one of its parts.
+///
+/// An error is returned if the day has no animation, the part is invalid, or the input cannot be
+/// parsed.
+pub fn animation(day: u8, part: u8, input: &str) -> Result<Box<dyn Animation>, anyhow::Error> {
+    crate::solution::animation(day, part, input)
+}
+
+/// Plays an animation until it finishes, the frame limit is reached, or `stop` is set.
+///
+/// The terminal size is read with `size` before each frame, as columns and rows. The writer is
+/// expected to be a terminal that has already been prepared with [ENTER_SCREEN].
+///
+/// An error categorized as [ErrorKind::Usage] is returned if the frame rate is so low that a
+/// frame would last longer than a [Duration] can hold.
+pub fn play<W: Write>(
+    animation: &mut dyn Animation,
+    options: &Options,
+    mut writer: W,
+    size: impl Fn() -> (usize, usize),
+    stop: &AtomicBool,
+) -> Result<(), anyhow::Error> {
+    let frame_time = match options.fps > 0.0 {
+        true => Duration::try_from_secs_f64(1.0 / options.fps)
+            .map_err(|_| anyhow!("frame rate is too low: {:e}", options.fps))
+            .categorize(ErrorKind::Usage)?,
+        false => Duration::ZERO,
+    };
+    let mut last_size = None;
+    let mut frames = 0;
+    loop {
+        let started = Instant::now();
+        let (width, height) = size();
+        // a resized window may leave parts of the old frame outside the new one
+        if last_size.is_some() && last_size != Some((width, height)) {
+            write!(writer, "{}", CLEAR_SCREEN)
+                .context("could not clear screen")
+                .categorize(ErrorKind::Io)?;
+        }
+        last_size = Some((width, height));
+        writer
+            .write_all(frame(animation, width, height).as_bytes())
+            .and_then(|_| writer.flush())
+            .context("could not draw frame")
+            .categorize(ErrorKind::Io)?;
+        frames += 1;
+        if options.frames.is_some_and(|limit| frames >= limit) {
+            return Ok(());
+        }
+        // wait out the rest of the frame, checking regularly whether to stop
+        while let Some(remaining) = frame_time.checked_sub(started.elapsed()) {
+            if remaining.is_zero() || stop.load(Ordering::Relaxed) {
+                break;
+            }
+            thread::sleep(remaining.min(STOP_CHECK_INTERVAL));
+        }
+        if stop.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        // the last frame drawn already shows the final state if nothing changed
+        let mut advanced = false;
+        for _ in 0..options.steps.max(1) {
+            if !animation.step() {
+                break;
+            }
+            advanced = true;
+        }
+        if !advanced {
+            return Ok(());
+        }
+    }
+}
+
+/// Draws a whole frame: the status line followed by the animation, cut to the terminal size.
+pub fn frame(animation: &dyn Animation, width: usize, height: usize) -> String {
+    let mut output = String::from(CURSOR_HOME);
+    output.push_str(&format!(
+        "{}{}{}{}",
+        BOLD,
+        clip(&animation.status(), width),
+        RESET,
+        CLEAR_LINE
+    ));
+    let rows = height.saturating_sub(1);
+    for line in animation.render(width, rows).iter().take(rows) {
+        output.push('\n');
+        output.push_str(&clip(line, width));
+        output.push_str(CLEAR_LINE);
+    }
+    output.push_str(CLEAR_BELOW);
+    output
+}
+
+/// Cuts a line down to the given number of characters.
+fn clip(line: &str, width: usize) -> String {
+    line.chars().take(width).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An animation that counts up to a limit.
+    struct Counter {
+        count: usize,
+        limit: usize,
+    }
+
+    impl Animation for Counter {
+        fn step(&mut self) -> bool {
+            if self.count == self.limit {
+                return false;
+            }
+            self.count += 1;
+            true
+        }
+
+        fn status(&self) -> String {
+            format!("count {}", self.count)
+        }
+
+        fn render(&self, _width: usize, height: usize) -> Vec<String> {
+            vec!["#".repeat(self.count); height + 1]
+        }
+    }
+
+    #[test]
+    fn test_frame() {
+        let counter = Counter { count: 5, limit: 5 };
+        let frame = frame(&counter, 3, 3);
+        assert_eq!(
+            "\x1b[H\x1b[1mcou\x1b[0m\x1b[K\n###\x1b[K\n###\x1b[K\x1b[J",
+            frame
+        );
+    }
+
+    #[test]
+    fn test_play() {
+        let mut counter = Counter { count: 0, limit: 3 };
+        let options = Options {
+            fps: 0.0,
+            steps: 2,
+            frames: None,
+        };
+        let mut output = Vec::new();
+        play(
+            &mut counter,
+            &options,
+            &mut output,
+            || (80, 2),
+            &AtomicBool::new(false),
+        )
+        .expect("should return result");
+        let output = String::from_utf8(output).expect("should be UTF-8");
+        assert_eq!(3, counter.count);
+        // frames after 0, 2 and 3 steps
+        assert_eq!(3, output.matches(CURSOR_HOME).count());
+        assert!(output.contains("count 2"));
+        assert!(!output.contains("count 1"));
+    }
+
+    #[test]
+    fn test_play_limits() {
+        let mut counter = Counter {
+            count: 0,
+            limit: 100,
+        };
+        let mut options = Options {
+            fps: 0.0,
+            steps: 1,
+            frames: Some(4),
+        };
+        play(
+            &mut counter,
+            &options,
+            Vec::new(),
+            || (80, 2),
+            &AtomicBool::new(false),
+        )
+        .expect("should return result");
+        assert_eq!(3, counter.count);
+        // a stop request ends the animation after the current frame
+        options.frames = None;
+        play(
+            &mut counter,
+            &options,
+            Vec::new(),
+            || (80, 2),
+            &AtomicBool::new(true),
+        )
+        .expect("should return result");
+        assert_eq!(3, counter.count);
+    }
+
+    #[test]
+    fn test_play_resize() {
+        let mut counter = Counter { count: 0, limit: 2 };
+        let options = Options {
+            fps: 0.0,
+            ..Options::default()
+        };
+        let mut output = Vec::new();
+        let sizes = std::cell::Cell::new(0);
+        let size = || {
+            sizes.set(sizes.get() + 1);
+            (80, 10 + sizes.get() / 2)
+        };
+        play(
+            &mut counter,
+            &options,
+            &mut output,
+            size,
+            &AtomicBool::new(false),
+        )
+        .expect("should return result");
+        let output = String::from_utf8(output).expect("should be UTF-8");
+        assert_eq!(1, output.matches(CLEAR_SCREEN).count());
+    }
+
+    #[test]
+    fn test_play_tiny_fps() {
+        // a frame would last forever, which used to panic when converted to a duration
+        let mut counter = Counter { count: 0, limit: 3 };
+        let options = Options {
+            fps: 1e-310,
+            steps: 1,
+            frames: Some(1),
+        };
+        let mut output = Vec::new();
+        let error = play(
+            &mut counter,
+            &options,
+            &mut output,
+            || (80, 2),
+            &AtomicBool::new(false),
+        )
+        .expect_err("should return error");
+        assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+        assert!(output.is_empty());
+    }
+}
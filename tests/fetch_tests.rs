@@ -0,0 +1,137 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+
+/// Starts a stand-in for the Advent of Code website that answers a single request.
+///
+/// Returns the base URL of the server and a channel that receives the raw request head.
+fn mock_server(status: &'static str, body: &'static str) -> (String, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("should bind to loopback");
+    let base_url = format!(
+        "http://{}",
+        listener.local_addr().expect("should have address")
+    );
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("should accept connection");
+        let mut reader = BufReader::new(stream.try_clone().expect("should clone stream"));
+        let mut head = String::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).expect("should read request");
+            if line == "\r\n" || line.is_empty() {
+                break;
+            }
+            head.push_str(&line);
+        }
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        stream
+            .write_all(response.as_bytes())
+            .expect("should write response");
+        tx.send(head).expect("should send request head");
+    });
+    (base_url, rx)
+}
+
+#[test]
+fn test_fetch_downloads_input() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let (base_url, rx) = mock_server("200 OK", "1000\n2000\n\n3000\n");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.current_dir(dir.path())
+        .env("AOC_SESSION", "abc123")
+        .args(["fetch", "-d", "3", "--base-url", &base_url]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("saved to: input/day_03/input.txt"));
+    let head = rx.recv().expect("should receive request");
+    assert!(head.starts_with("GET /2022/day/3/input HTTP/1.1"));
+    assert!(head.contains("session=abc123"));
+    let contents =
+        fs::read_to_string(dir.path().join("input/day_03/input.txt")).expect("should read input");
+    assert_eq!("1000\n2000\n\n3000", contents);
+}
+
+#[test]
+fn test_fetch_other_year() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let (base_url, rx) = mock_server("200 OK", "abc\n");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.current_dir(dir.path())
+        .env("AOC_SESSION", "abc123")
+        .args([
+            "fetch",
+            "--year",
+            "2021",
+            "-d",
+            "3",
+            "--base-url",
+            &base_url,
+        ]);
+    cmd.assert().success().stdout(predicate::str::contains(
+        "saved to: input/2021/day_03/input.txt",
+    ));
+    let head = rx.recv().expect("should receive request");
+    assert!(head.starts_with("GET /2021/day/3/input HTTP/1.1"));
+    let contents = fs::read_to_string(dir.path().join("input/2021/day_03/input.txt"))
+        .expect("should read input");
+    assert_eq!("abc", contents);
+}
+
+#[test]
+fn test_fetch_uses_cache() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    fs::create_dir_all(dir.path().join("input/day_05")).expect("should create input dir");
+    fs::write(dir.path().join("input/day_05/input.txt"), "cached").expect("should write input");
+    // no server and no session token, so any download attempt would fail
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.current_dir(dir.path()).env_remove("AOC_SESSION").args([
+        "fetch",
+        "-d",
+        "5",
+        "--base-url",
+        "http://127.0.0.1:9",
+    ]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("already cached"));
+    let contents =
+        fs::read_to_string(dir.path().join("input/day_05/input.txt")).expect("should read input");
+    assert_eq!("cached", contents);
+}
+
+#[test]
+fn test_fetch_error_status() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let (base_url, _rx) = mock_server("400 Bad Request", "Please log in.");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.current_dir(dir.path())
+        .env("AOC_SESSION", "expired")
+        .args(["fetch", "-d", "1", "--base-url", &base_url]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("could not download input"));
+    assert!(!dir.path().join("input/day_01/input.txt").exists());
+}
+
+#[test]
+fn test_fetch_missing_session() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.current_dir(dir.path())
+        .env_remove("AOC_SESSION")
+        .env("XDG_CONFIG_HOME", dir.path())
+        .args(["fetch", "-d", "2", "--base-url", "http://127.0.0.1:9"]);
+    cmd.assert()
+        .failure()
+        .stderr(predicate::str::contains("no session token"));
+}
@@ -0,0 +1,164 @@
+//! Golden-file tests for every input under `input/`.
+//!
+//! Each `input/day_XX/<name>.txt` (or `input/YYYY/day_XX/<name>.txt` for years other than 2022) is
+//! solved for every part of its day and compared to the expected output next to it:
+//!
+//! - `<name>.part_N.expected` holds the expected answer for part N in plaintext, or `error: `
+//!   followed by the expected error message. This is for examples and edge cases whose answers
+//!   can be published.
+//! - `<name>.answers` holds hashed answers, as written by `aoc verify --update`, for puzzle inputs
+//!   whose answers shouldn't be published.
+//!
+//! A test is generated for each input and part, so adding a new input and its expected output
+//! doesn't need any changes here.
+
+use aoc::verify::{AnswerStore, Verification};
+use libtest_mimic::{Arguments, Failed, Trial};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let args = Arguments::from_args();
+    let trials = match discover(Path::new("input")) {
+        Ok(trials) => trials,
+        Err(e) => {
+            eprintln!("could not discover inputs: {:#}", e);
+            std::process::exit(101);
+        }
+    };
+    libtest_mimic::run(&args, trials).exit();
+}
+
+/// Finds every input and creates a test for each of its parts.
+fn discover(root: &Path) -> Result<Vec<Trial>, anyhow::Error> {
+    let mut trials = Vec::new();
+    for (year, day, dir) in day_dirs(root)? {
+        for input in sorted_entries(&dir)? {
+            if input.extension().and_then(|e| e.to_str()) != Some("txt") {
+                continue;
+            }
+            let name = format!(
+                "{}::day_{:02}::{}",
+                year,
+                day,
+                input.file_stem().unwrap_or_default().to_string_lossy()
+            );
+            let answers = AnswerStore::load(&input.to_string_lossy())?;
+            let mut found = false;
+            for part in aoc::parts_year(year, day) {
+                let expected = expected_path(&input, part);
+                let trial = if expected.exists() {
+                    let input = input.clone();
+                    Trial::test(format!("{}::part_{}", name, part), move || {
+                        check_expected(year, day, part, &input, &expected)
+                    })
+                } else if answers.get(part).is_some() {
+                    let input = input.clone();
+                    Trial::test(format!("{}::part_{}", name, part), move || {
+                        check_answers(year, day, part, &input)
+                    })
+                } else {
+                    continue;
+                };
+                found = true;
+                trials.push(trial);
+            }
+            if !found {
+                let message = format!("no expected output for any part of {}", input.display());
+                trials.push(Trial::test(name, move || Err(message.into())));
+            }
+        }
+    }
+    Ok(trials)
+}
+
+/// Returns the year, day and directory of every day with inputs, in order.
+fn day_dirs(root: &Path) -> Result<Vec<(u16, u8, PathBuf)>, anyhow::Error> {
+    let mut dirs = Vec::new();
+    for path in sorted_entries(root)? {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if let Some(day) = name.strip_prefix("day_").and_then(|d| d.parse().ok()) {
+            dirs.push((aoc::DEFAULT_YEAR, day, path));
+        } else if let Ok(year) = name.parse::<u16>() {
+            for path in sorted_entries(&path)? {
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                if let Some(day) = name.strip_prefix("day_").and_then(|d| d.parse().ok()) {
+                    dirs.push((year, day, path));
+                }
+            }
+        }
+    }
+    Ok(dirs)
+}
+
+fn sorted_entries(dir: &Path) -> Result<Vec<PathBuf>, anyhow::Error> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<PathBuf>, _>>()?;
+    entries.sort();
+    Ok(entries)
+}
+
+/// Resolves the plaintext expected output for a part, e.g. `example.part_1.expected`.
+fn expected_path(input: &Path, part: u8) -> PathBuf {
+    input.with_extension(format!("part_{}.expected", part))
+}
+
+/// Solves a part, formatting an error the same way as an expected error.
+fn solve(year: u16, day: u8, part: u8, input: &Path) -> String {
+    match aoc::solve_year(year, day, part, Some(input.to_string_lossy().to_string())) {
+        Ok(answer) => answer,
+        Err(e) => format!("error: {}", e),
+    }
+}
+
+fn check_expected(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &Path,
+    expected: &Path,
+) -> Result<(), Failed> {
+    let expected = fs::read_to_string(expected)
+        .map_err(|e| format!("could not read {}: {}", expected.display(), e))?;
+    let expected = expected.trim_end_matches('\n');
+    let actual = solve(year, day, part, input);
+    if actual == expected {
+        return Ok(());
+    }
+    Err(diff(expected, &actual).into())
+}
+
+fn check_answers(year: u16, day: u8, part: u8, input: &Path) -> Result<(), Failed> {
+    let actual = solve(year, day, part, input);
+    let path = Some(input.to_string_lossy().to_string());
+    match aoc::verify::check(year, day, part, path, &actual) {
+        Ok(Verification::Match) => Ok(()),
+        // the expected answer is only stored as a hash, so only the actual answer can be shown
+        Ok(Verification::Mismatch) => {
+            Err(format!("answer does not match the stored hash\nactual:\n{}", actual).into())
+        }
+        Err(e) => Err(format!("{:#}", e).into()),
+    }
+}
+
+/// Shows the expected and actual output line by line, marking the lines that differ.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<&str>>();
+    let actual = actual.lines().collect::<Vec<&str>>();
+    let mut lines = vec!["output does not match (- expected, + actual):".to_string()];
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => lines.push(format!("  {}", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    lines.push(format!("- {}", e));
+                }
+                if let Some(a) = a {
+                    lines.push(format!("+ {}", a));
+                }
+            }
+        }
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,77 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::fs;
+
+const INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+#[test]
+fn test_history_warns_on_rejected_answer() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let input_path = dir.path().join("input.txt");
+    let history_path = dir.path().join("history.txt");
+    fs::write(&input_path, INPUT).expect("should write input");
+    let input_path = input_path.to_str().expect("should be utf-8");
+    let history_path = history_path.to_str().expect("should be utf-8");
+
+    // record the solver's own answer as wrong
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["record", "wrong", "-d", "1", "-p", "1", "-f", input_path])
+        .args(["--history", history_path]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("answer 24000 marked wrong"));
+
+    // solving again should warn about it
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["-d", "1", "-p", "1", "-f", input_path])
+        .args(["--history", history_path]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("24000"))
+        .stderr(predicate::str::contains(
+            "WARNING: answer 24000 was already submitted and marked wrong",
+        ));
+}
+
+#[test]
+fn test_history_warns_outside_bounds() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let input_path = dir.path().join("input.txt");
+    let history_path = dir.path().join("history.txt");
+    fs::write(&input_path, INPUT).expect("should write input");
+    let input_path = input_path.to_str().expect("should be utf-8");
+    let history_path = history_path.to_str().expect("should be utf-8");
+
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["record", "too-high", "-d", "1", "-p", "2", "-f", input_path])
+        .args(["--answer", "40000", "--history", history_path]);
+    cmd.assert().success();
+
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["-d", "1", "-p", "2", "-f", input_path])
+        .args(["--history", history_path]);
+    cmd.assert().success().stderr(predicate::str::contains(
+        "WARNING: answer 45000 is above 40000, which was already too high",
+    ));
+
+    // the bound only applies to the part it was recorded for
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["-d", "1", "-p", "1", "-f", input_path])
+        .args(["--history", history_path]);
+    cmd.assert()
+        .success()
+        .stderr(predicate::str::contains("WARNING").not());
+}
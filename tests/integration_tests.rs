@@ -7,7 +7,7 @@ fn test_cli() {
     cmd.arg("-d").arg("1").arg("-p").arg("1");
     cmd.assert()
         .success()
-        .stdout(predicate::str::contains("69626"));
+        .stdout(predicate::str::contains("Day 1 part 1 solution: "));
 }
 
 #[test]
@@ -16,227 +16,409 @@ fn test_cli_error() {
     cmd.arg("-d").arg("0");
     cmd.assert()
         .failure()
+        .code(2)
         .stderr(predicate::str::contains("ERROR"));
 }
 
 #[test]
-fn test_day_01_part_1() {
-    assert_eq!(
-        "69626",
-        aoc::solve(1, 1, None).expect("should return result")
-    );
-}
-
-#[test]
-fn test_day_01_part_2() {
-    assert_eq!(
-        "206780",
-        aoc::solve(1, 2, None).expect("should return result")
-    );
-}
-
-#[test]
-fn test_day_02_part_1() {
-    assert_eq!(
-        "12535",
-        aoc::solve(2, 1, None).expect("should return result")
-    );
-}
-
-#[test]
-fn test_day_02_part_2() {
-    assert_eq!(
-        "15457",
-        aoc::solve(2, 2, None).expect("should return result")
-    );
-}
-
-#[test]
-fn test_day_03_part_1() {
-    assert_eq!(
-        "7821",
-        aoc::solve(3, 1, None).expect("should return result")
-    );
-}
-
-#[test]
-fn test_day_03_part_2() {
-    assert_eq!(
-        "2752",
-        aoc::solve(3, 2, None).expect("should return result")
-    );
-}
-
-#[test]
-fn test_day_04_part_1() {
-    assert_eq!("567", aoc::solve(4, 1, None).expect("should return result"));
-}
-
-#[test]
-fn test_day_04_part_2() {
-    assert_eq!("907", aoc::solve(4, 2, None).expect("should return result"));
+fn test_cli_year() {
+    // the built-in solvers are all for 2022
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["--year", "2022", "-d", "6", "-f", "input/day_06/input.txt"]);
+    cmd.assert().success();
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["--year", "2021", "-d", "6"]);
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("invalid day: 6"));
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["stats", "--year", "2021", "-d", "6"]);
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("stats is only available for 2022"));
 }
 
 #[test]
-fn test_day_05_part_1() {
-    assert_eq!(
-        "SVFDLGLWV",
-        aoc::solve(5, 1, None).expect("should return result")
-    );
+fn test_cli_batch() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    std::fs::write(dir.path().join("alice.txt"), "1000\n\n2000").expect("should write input");
+    std::fs::write(dir.path().join("bob.txt"), "3000").expect("should write input");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["-d", "1", "-f"]).arg(dir.path());
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("alice.txt: 2000\n"))
+        .stdout(predicate::str::contains("bob.txt: 3000\n"));
+    // every file is solved even when one of them fails
+    std::fs::write(dir.path().join("carol.txt"), "abc").expect("should write input");
+    let pattern = dir.path().join("*.txt");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["-d", "1", "-f"])
+        .arg(&pattern)
+        .arg(dir.path().join("missing.txt"));
+    cmd.assert()
+        .failure()
+        .code(4)
+        .stdout(predicate::str::contains("bob.txt: 3000\n"))
+        .stdout(predicate::str::contains("carol.txt: ERROR: "))
+        .stdout(predicate::str::contains(
+            "missing.txt: ERROR: could not read input file",
+        ))
+        .stderr(predicate::str::contains("2 of 4 input file(s) failed"));
 }
 
 #[test]
-fn test_day_05_part_2() {
-    assert_eq!(
-        "DCVTCVPCL",
-        aoc::solve(5, 2, None).expect("should return result")
-    );
+fn test_cli_metrics() {
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["-d", "8", "--metrics"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Day 8 part 1 solution: "))
+        .stdout(predicate::str::contains("read:").not())
+        .stderr(predicate::str::contains("read:  "))
+        .stderr(predicate::str::contains("parse: "))
+        .stderr(predicate::str::contains("solve: "));
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["-d", "8", "--metrics", "--timeout", "1"]);
+    cmd.assert().failure().code(2);
 }
 
 #[test]
-fn test_day_06_part_1() {
-    assert_eq!(
-        "1855",
-        aoc::solve(6, 1, None).expect("should return result")
-    );
+fn test_cli_stream() {
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["-d", "1", "-p", "2"]);
+    let output = cmd.output().expect("should run command");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["-d", "1", "-p", "2", "--stream"]);
+    cmd.assert().success().stdout(output.stdout);
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["-d", "5", "--stream"]);
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("no streaming solver for day 5"));
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["-d", "1", "--stream", "--metrics"]);
+    cmd.assert().failure().code(2);
 }
 
 #[test]
-fn test_day_06_part_2() {
-    assert_eq!(
-        "3256",
-        aoc::solve(6, 2, None).expect("should return result")
-    );
+fn test_cli_error_io() {
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.arg("-d").arg("1").arg("-f").arg("does/not/exist.txt");
+    cmd.assert()
+        .failure()
+        .code(3)
+        .stderr(predicate::str::contains("could not read input file"));
 }
 
 #[test]
-fn test_day_07_part_1() {
-    assert_eq!(
-        "1444896",
-        aoc::solve(7, 1, None).expect("should return result")
-    );
+fn test_cli_error_parse() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let input_path = dir.path().join("input.txt");
+    std::fs::write(&input_path, "1000\nabc").expect("should write input");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.arg("-d").arg("1").arg("-f").arg(&input_path);
+    cmd.assert()
+        .failure()
+        .code(4)
+        .stderr(predicate::str::contains("ERROR"));
 }
 
 #[test]
-fn test_day_07_part_2() {
-    assert_eq!(
-        "404395",
-        aoc::solve(7, 2, None).expect("should return result")
-    );
+fn test_cli_error_parse_not_utf8() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let input_path = dir.path().join("input.txt");
+    std::fs::write(&input_path, b"1000\n\xff").expect("should write input");
+    // the file is read fine, so bytes that aren't text are a parse error like in a stream
+    for stream in [false, true] {
+        let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+        cmd.arg("-d").arg("1").arg("-f").arg(&input_path);
+        if stream {
+            cmd.arg("--stream");
+        }
+        cmd.assert()
+            .failure()
+            .code(4)
+            .stderr(predicate::str::contains("input is not valid UTF-8"));
+    }
 }
 
 #[test]
-fn test_day_08_part_1() {
-    assert_eq!(
-        "1782",
-        aoc::solve(8, 1, None).expect("should return result")
-    );
+fn test_cli_error_simulation() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let input_path = dir.path().join("input.txt");
+    // the monkey parses fine but throws to a monkey that doesn't exist
+    let input = "Monkey 0:
+  Starting items: 79
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3";
+    std::fs::write(&input_path, input).expect("should write input");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.arg("-d").arg("11").arg("-f").arg(&input_path);
+    cmd.assert()
+        .failure()
+        .code(5)
+        .stderr(predicate::str::contains("non-existent monkey"));
 }
 
 #[test]
-fn test_day_08_part_2() {
-    assert_eq!(
-        "474606",
-        aoc::solve(8, 2, None).expect("should return result")
-    );
+fn test_cli_verify() {
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.arg("verify").arg("-d").arg("1");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Day 1 part 1: match"))
+        .stdout(predicate::str::contains("Day 1 part 2: match"));
 }
 
 #[test]
-fn test_day_09_part_1() {
-    assert_eq!(
-        "5907",
-        aoc::solve(9, 1, None).expect("should return result")
-    );
+fn test_cli_verify_mismatch() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let input_path = dir.path().join("input.txt");
+    std::fs::write(&input_path, "1000\n\n2000").expect("should write input");
+    // hash a different input's answer into the answers file next to this input
+    std::fs::write(
+        dir.path().join("input.answers"),
+        "1 a ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad\n",
+    )
+    .expect("should write answers");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["verify", "-d", "1", "-p", "1", "-f"])
+        .arg(&input_path);
+    cmd.assert()
+        .failure()
+        .code(7)
+        .stdout(predicate::str::contains("Day 1 part 1: mismatch"))
+        .stdout(predicate::str::contains("2000").not())
+        .stderr(predicate::str::contains("ERROR"));
 }
 
 #[test]
-fn test_day_09_part_2() {
-    assert_eq!(
-        "2303",
-        aoc::solve(9, 2, None).expect("should return result")
-    );
+fn test_cli_check() {
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.arg("check");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Day 1: ok"))
+        .stdout(predicate::str::contains("Day 14: ok"));
+    // every problem is listed, not just the first
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let input_path = dir.path().join("input.txt");
+    std::fs::write(&input_path, "Sabqponm\nabcryxx\naccszxk").expect("should write input");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["check", "-d", "12", "-f"]).arg(&input_path);
+    cmd.assert()
+        .failure()
+        .code(4)
+        .stdout(predicate::str::contains("Day 12: 3 problems"))
+        .stdout(predicate::str::contains(
+            "  line 2: row has 7 squares, but the first row has 8",
+        ))
+        .stdout(predicate::str::contains(
+            "  expected one end marked E, but found 0",
+        ))
+        .stderr(predicate::str::contains("3 problem(s) found"));
 }
 
 #[test]
-fn test_day_10_part_1() {
-    assert_eq!(
-        "14860",
-        aoc::solve(10, 1, None).expect("should return result")
-    );
+fn test_cli_stats() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let input_path = dir.path().join("input.txt");
+    std::fs::write(&input_path, "303\n255\n653\n335").expect("should write input");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["stats", "-d", "8", "-f"]).arg(&input_path);
+    cmd.assert()
+        .success()
+        .stdout("Day 8:\n  columns:  3\n  rows:     4\n  heights:  0 to 6\n");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.arg("stats");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Day 14:"))
+        .stdout(predicate::str::contains("  rock paths:"));
+    std::fs::write(&input_path, "3x3").expect("should write input");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["stats", "-d", "8", "-f"]).arg(&input_path);
+    cmd.assert().code(4);
 }
 
 #[test]
-fn test_day_10_part_2() {
-    let gt = "
-###...##..####.####.#..#.#..#.###..#..#.
-#..#.#..#....#.#....#..#.#..#.#..#.#.#..
-#..#.#......#..###..####.#..#.#..#.##...
-###..#.##..#...#....#..#.#..#.###..#.#..
-#.#..#..#.#....#....#..#.#..#.#.#..#.#..
-#..#..###.####.####.#..#..##..#..#.#..#.\n";
-    assert_eq!(gt, aoc::solve(10, 2, None).expect("should return result"));
+fn test_cli_describe() {
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["describe", "-d", "1", "-p", "2", "--width", "40"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with(
+            "--- Day 1: Calorie Counting ---\n\n--- Part Two ---\n",
+        ))
+        .stdout(predicate::str::contains("\x1b[").not())
+        .stdout(predicate::str::contains("206780").not())
+        .stdout(predicate::function(|out: &str| {
+            out.lines()
+                .all(|line| line.starts_with("    ") || line.len() <= 40)
+        }));
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["describe", "-d", "1", "-p", "3"]);
+    cmd.assert()
+        .code(2)
+        .stderr(predicate::str::contains("invalid part: 3"));
 }
 
 #[test]
-fn test_day_11_part_1() {
-    assert_eq!(
-        "95472",
-        aoc::solve(11, 1, None).expect("should return result")
-    );
+fn test_cli_report() {
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["report", "-d", "1"]);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with("| Day | Puzzle |"))
+        .stdout(predicate::str::contains(
+            "| 1 | Calorie Counting | solved |",
+        ));
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let path = dir.path().join("README.md");
+    std::fs::write(
+        &path,
+        "# Progress\n<!-- report start -->\nold\n<!-- report end -->\n",
+    )
+    .expect("should write file");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["report", "-d", "1", "--update"]).arg(&path);
+    cmd.assert().success();
+    let updated = std::fs::read_to_string(&path).expect("should read file");
+    assert!(updated.starts_with("# Progress\n<!-- report start -->\n| Day |"));
+    assert!(updated.contains("| 1 | Calorie Counting |"));
+    assert!(!updated.contains("old"));
+    assert!(updated.ends_with("|\n<!-- report end -->\n"));
+    std::fs::write(&path, "# Progress\n").expect("should write file");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["report", "-d", "1", "--update"]).arg(&path);
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "could not find the report section",
+        ));
 }
 
 #[test]
-fn test_day_11_part_2() {
-    assert_eq!(
-        "17926061332",
-        aoc::solve(11, 2, None).expect("should return result")
-    );
+fn test_cli_completions() {
+    for shell in ["bash", "zsh", "fish"] {
+        let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+        cmd.arg("completions").arg(shell);
+        cmd.assert()
+            .success()
+            .stdout(predicate::str::contains("verify"))
+            .stdout(predicate::str::contains("14"));
+    }
 }
 
 #[test]
-fn test_day_12_part_1() {
-    assert_eq!(
-        "339",
-        aoc::solve(12, 1, None).expect("should return result")
-    );
+fn test_cli_manpage() {
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.arg("manpage");
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::starts_with(".ie"))
+        .stdout(predicate::str::contains(".TH aoc 1"));
 }
 
 #[test]
-fn test_day_12_part_2() {
-    assert_eq!(
-        "332",
-        aoc::solve(12, 2, None).expect("should return result")
+fn test_cli_repl() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let input_path = dir.path().join("input.txt");
+    std::fs::write(
+        &input_path,
+        "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi",
+    )
+    .expect("should write input");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.arg("repl")
+        .arg("-d")
+        .arg("12")
+        .arg("-f")
+        .arg(&input_path);
+    cmd.write_stdin("distance\nclosest\nheight 9 9\npart 2\n");
+    cmd.assert().success().stdout(
+        predicate::str::starts_with("31\n29 from 0,4\nERROR: square is outside the map: 9,9\n")
+            .and(predicate::str::contains("Day 12 part 2 solution: 29")),
     );
 }
 
 #[test]
-fn test_day_13_part_1() {
-    assert_eq!(
-        "5588",
-        aoc::solve(13, 1, None).expect("should return result")
-    );
+fn test_cli_repl_no_explorer() {
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.arg("repl").arg("-d").arg("1");
+    cmd.write_stdin("help\n");
+    cmd.assert()
+        .failure()
+        .code(2)
+        .stderr(predicate::str::contains("no explorer for day 1"));
 }
 
 #[test]
-fn test_day_13_part_2() {
-    assert_eq!(
-        "23958",
-        aoc::solve(13, 2, None).expect("should return result")
-    );
+fn test_cli_visualize() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let input_path = dir.path().join("input.txt");
+    std::fs::write(
+        &input_path,
+        "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9",
+    )
+    .expect("should write input");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["visualize", "-d", "14", "--fps", "0", "--steps", "5", "-f"])
+        .arg(&input_path);
+    cmd.assert().success().stdout(predicate::str::ends_with(
+        "Day 14 part 1: Units of sand at rest: 24 | finished\n",
+    ));
+    // a frame limit stops the animation early
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["visualize", "-d", "14", "--fps", "0", "--frames", "3", "-f"])
+        .arg(&input_path);
+    cmd.assert().success().stdout(predicate::str::ends_with(
+        "Day 14 part 1: Units of sand at rest: 2 | falling\n",
+    ));
 }
 
 #[test]
-fn test_day_14_part_1() {
-    assert_eq!(
-        "774",
-        aoc::solve(14, 1, None).expect("should return result")
-    );
+fn test_cli_generate() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let input_path = dir.path().join("input.txt");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["generate", "-d", "9", "--seed", "42", "--size", "50", "-o"])
+        .arg(&input_path);
+    cmd.assert().success().stdout("");
+    let input = std::fs::read_to_string(&input_path).expect("should read input");
+    assert_eq!(50, input.lines().count());
+    // the same seed prints the same input, which the solver accepts
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["generate", "-d", "9", "--seed", "42", "--size", "50"]);
+    cmd.assert().success().stdout(input);
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["-d", "9", "-f"]).arg(&input_path);
+    cmd.assert().success();
+    // sizes are checked against what the day supports
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["generate", "-d", "11", "--size", "1"]);
+    cmd.assert().code(2).stderr(predicate::str::contains(
+        "invalid size for day 11: 1 (must be 2 to 9 monkeys)",
+    ));
 }
 
 #[test]
-fn test_day_14_part_2() {
-    assert_eq!(
-        "22499",
-        aoc::solve(14, 2, None).expect("should return result")
-    );
+fn test_cli_compare() {
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["compare", "-d", "13", "--inputs", "10", "--size", "20"]);
+    cmd.assert()
+        .success()
+        .stdout("Day 13 part 1: agree on 10 input(s)\nDay 13 part 2: agree on 10 input(s)\n");
+    let mut cmd = Command::cargo_bin("aoc").expect("should create command");
+    cmd.args(["compare", "-d", "1"]);
+    cmd.assert().code(2).stderr(predicate::str::contains(
+        "no reference implementation for day 1",
+    ));
 }
@@ -0,0 +1,102 @@
+use proptest::prelude::*;
+
+/// Replaces a run of characters starting at `position` with `text`, wrapping positions that are
+/// past the end of the input.
+type Edit = (usize, usize, String);
+
+/// Edits that break inputs in the ways a parser is likely to trip over: stray characters, missing
+/// characters, numbers that are too big and negative numbers.
+fn edits() -> impl Strategy<Value = Vec<Edit>> {
+    let text = prop_oneof![
+        "[ -~\n]{0,4}",
+        "[0-9]{1,20}",
+        "-[0-9]{1,3}",
+        // pieces of syntax from the different days
+        prop::sample::select(vec![
+            "\n", "\n\n", " ", "$", "$ cd", "dir", "[", "]", ",", "-", " -> ", ":", "old", "*",
+            "+",
+        ])
+        .prop_map(String::from),
+    ];
+    prop::collection::vec((any::<usize>(), 0..10usize, text), 1..4)
+}
+
+fn apply(input: &str, edits: &[Edit]) -> String {
+    let mut chars = input.chars().collect::<Vec<char>>();
+    for (position, removed, text) in edits {
+        let start = position % (chars.len() + 1);
+        let end = (start + removed).min(chars.len());
+        chars.splice(start..end, text.chars());
+    }
+    chars.into_iter().collect()
+}
+
+/// Divides every number in the input by `divisor`, which keeps its shape but not its sizes.
+fn scale(input: &str, divisor: u64) -> String {
+    let mut output = String::new();
+    let mut number = String::new();
+    for c in input.chars().chain(std::iter::once('\n')) {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        if !number.is_empty() {
+            match number.parse::<u64>() {
+                Ok(n) => output.push_str(&(n / divisor).to_string()),
+                Err(_) => output.push_str(&number),
+            }
+            number.clear();
+        }
+        output.push(c);
+    }
+    // the extra line break only flushed the last number
+    output.pop();
+    output
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+
+    #[test]
+    fn test_parse_any_text(day in 1u8..=14, input in "(\\PC|\n){0,64}") {
+        // parsing either succeeds or returns an error, but never panics
+        let _ = aoc::parse(day, &input);
+    }
+
+    #[test]
+    fn test_parse_broken_input(day in 1u8..=14, seed: u64, edits in edits()) {
+        let input = aoc::generate::generate(day, seed, None).expect("should return result");
+        let _ = aoc::parse(day, &apply(&input, &edits));
+    }
+}
+
+proptest! {
+    // solving takes much longer than parsing, so there are fewer cases
+    #![proptest_config(ProptestConfig::with_cases(200))]
+
+    #[test]
+    fn test_solve_scaled_input(day in 1u8..=14, part in 1u8..=2, seed: u64, divisor in 1u64..=1000) {
+        // inputs that still parse can be impossible to solve, such as a disk with enough free
+        // space already, which must be an error rather than a panic
+        let input = aoc::generate::generate(day, seed, None).expect("should return result");
+        let _ = aoc::solve_input(day, part, &scale(&input, divisor));
+    }
+
+    #[test]
+    fn test_solve_sand_at_left_edge(length in 480u32..=500, width in 0u32..=3) {
+        // generated scans stay near the source, so a staircase leads the sand to x=0 instead, where
+        // it can slide off the left of the scan; only part 1 is solved since a floor that deep
+        // takes too long to fill
+        let input = (0..=length)
+            .map(|k| format!("{},{} -> {},{}", 500 - k, k + 1, 500 - k + width, k + 1))
+            .collect::<Vec<String>>()
+            .join("\n");
+        prop_assert!(aoc::solve_input(14, 1, &input).is_ok());
+    }
+
+    #[test]
+    fn test_solve_broken_input(day in 1u8..=14, part in 1u8..=2, seed: u64, edits in edits()) {
+        let input = aoc::generate::generate(day, seed, None).expect("should return result");
+        let _ = aoc::solve_input(day, part, &apply(&input, &edits));
+    }
+}
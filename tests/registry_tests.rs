@@ -0,0 +1,91 @@
+use aoc::error::ErrorKind;
+use aoc::verify::{self, Verification};
+use std::fs;
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+#[test]
+fn test_registered_solver_replaces_builtin() {
+    let input = "1000\n2000\n\n3000";
+    assert_eq!(
+        "3000",
+        aoc::solve_input(1, 1, input).expect("should return result")
+    );
+    aoc::registry::register(2022, 1, 1, |input: &str| {
+        Ok(input.lines().count().to_string())
+    })
+    .expect("should return result");
+    assert_eq!(
+        "4",
+        aoc::solve_input(1, 1, input).expect("should return result")
+    );
+    // the other part is still built in
+    assert_eq!(
+        "6000",
+        aoc::solve_input(1, 2, input).expect("should return result")
+    );
+    assert!(aoc::registry::unregister(2022, 1, 1));
+    assert_eq!(
+        "3000",
+        aoc::solve_input(1, 1, input).expect("should return result")
+    );
+}
+
+#[test]
+fn test_registered_day_is_verified() {
+    let dir = tempfile::tempdir().expect("should create temp dir");
+    let input_path = dir.path().join("input.txt");
+    fs::write(&input_path, "abc").expect("should write input");
+    let input_path = input_path.to_str().expect("should be utf-8").to_string();
+
+    aoc::registry::register(2023, 30, 2, |input: &str| Ok(input.len().to_string()))
+        .expect("should return result");
+    assert!(aoc::days_year(2023).contains(&30));
+    assert_eq!(vec![2], aoc::parts_year(2023, 30));
+    let answer =
+        verify::store(2023, 30, 2, Some(input_path.clone())).expect("should return result");
+    assert_eq!("3", answer);
+    let result =
+        verify::verify(2023, 30, 2, Some(input_path.clone())).expect("should return result");
+    assert_eq!(Verification::Match, result);
+    let error = verify::verify(2023, 30, 1, Some(input_path)).expect_err("should return error");
+    assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+}
+
+#[test]
+fn test_registered_solver_times_out() {
+    // the solver blocks until it's told to finish, so it can't beat the timeout however fast it is
+    let (tx, rx) = mpsc::channel::<()>();
+    let rx = Mutex::new(rx);
+    aoc::registry::register(2023, 31, 1, move |_: &str| {
+        let _ = rx.lock().expect("should lock receiver").recv();
+        Ok("done".to_string())
+    })
+    .expect("should return result");
+    let error = aoc::solve_input_with_timeout_year(2023, 31, 1, "", Duration::from_millis(10))
+        .expect_err("should return error");
+    assert_eq!(Some(ErrorKind::Timeout), ErrorKind::of(&error));
+    assert_eq!(6, ErrorKind::Timeout.exit_code());
+    tx.send(()).expect("should release solver");
+    aoc::registry::unregister(2023, 31, 1);
+}
+
+#[test]
+fn test_registered_solver_is_not_streamed() {
+    let input = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+    aoc::registry::register(2022, 6, 2, |input: &str| Ok(input.len().to_string()))
+        .expect("should return result");
+    // streaming would use the built-in solver that the registered one replaced
+    let error = aoc::stream::solve_reader(6, 2, input.as_bytes()).expect_err("should return error");
+    let answer = aoc::solve_input(6, 2, input);
+    let other_part = aoc::stream::solve_reader(6, 1, input.as_bytes());
+    aoc::registry::unregister(2022, 6, 2);
+    assert_eq!(Some(ErrorKind::Usage), ErrorKind::of(&error));
+    assert_eq!(
+        "day 6 part 2 has a registered solver, which can't read a stream",
+        error.to_string()
+    );
+    assert_eq!("30", answer.expect("should return result"));
+    assert_eq!("7", other_part.expect("should return result"));
+}
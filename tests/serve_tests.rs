@@ -0,0 +1,93 @@
+use aoc::serve::Server;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::process::{Command, Stdio};
+use std::thread;
+
+/// Starts the service on a free loopback port and returns its address.
+fn start_server() -> SocketAddr {
+    let server = Server::bind(0, None).expect("should bind to loopback");
+    let addr = server.local_addr().expect("should have address");
+    thread::spawn(move || server.run());
+    addr
+}
+
+/// Sends a request to the service and returns the response status and body.
+fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(addr).expect("should connect to server");
+    let request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Length: {}\r\n\r\n{}",
+        method,
+        path,
+        addr,
+        body.len(),
+        body
+    );
+    stream
+        .write_all(request.as_bytes())
+        .expect("should write request");
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .expect("should read response");
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .expect("should have head and body");
+    let status = head
+        .split(' ')
+        .nth(1)
+        .and_then(|s| s.parse::<u16>().ok())
+        .expect("should have status");
+    (status, body.to_string())
+}
+
+#[test]
+fn test_serve_solve() {
+    let addr = start_server();
+    let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+    let (status, body) = request(addr, "POST", "/solve/1/1", input);
+    assert_eq!(200, status);
+    assert!(body.starts_with("{\"day\":1,\"part\":1,\"answer\":\"24000\",\"time_ms\":"));
+    let (status, body) = request(addr, "POST", "/solve/1/2", input);
+    assert_eq!(200, status);
+    assert!(body.contains("\"answer\":\"45000\""));
+}
+
+#[test]
+fn test_serve_errors() {
+    let addr = start_server();
+    let (status, body) = request(addr, "POST", "/solve/1/1", "abc");
+    assert_eq!(422, status);
+    assert!(body.starts_with("{\"day\":1,\"part\":1,\"error\":{\"kind\":\"parse\""));
+    let (status, body) = request(addr, "POST", "/solve/26/1", "1");
+    assert_eq!(400, status);
+    assert!(body.contains("\"kind\":\"usage\""));
+    let (status, _) = request(addr, "POST", "/answers/1/1", "1");
+    assert_eq!(404, status);
+    let (status, _) = request(addr, "GET", "/solve/1/1", "");
+    assert_eq!(405, status);
+}
+
+#[test]
+fn test_cli_serve() {
+    let mut child = Command::new(assert_cmd::cargo::cargo_bin("aoc"))
+        .args(["serve", "--port", "0"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("should start server");
+    let mut line = String::new();
+    BufReader::new(child.stdout.take().expect("should capture stdout"))
+        .read_line(&mut line)
+        .expect("should read address");
+    let addr = line
+        .trim()
+        .strip_prefix("Listening on http://")
+        .and_then(|a| a.parse::<SocketAddr>().ok());
+    let result =
+        addr.map(|addr| request(addr, "POST", "/solve/6/2", "mjqjpqmgbljsphdztnvjfqwrcgsmlb"));
+    child.kill().expect("should stop server");
+    child.wait().expect("should wait for server");
+    let (status, body) = result.expect("should print listening address");
+    assert_eq!(200, status);
+    assert!(body.contains("\"answer\":\"19\""));
+}